@@ -57,122 +57,61 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
       ]
     },
     {
-      "name": "buyWrappedSolV0",
+      "name": "initializeReserveStakingV0",
       "accounts": [
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wrappedSolMint",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "mintAuthority",
+          "name": "upgradeAuthority",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "solStorage",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "source",
-          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "destination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "programData",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "BuyWrappedSolV0Args"
-          }
-        }
-      ]
-    },
-    {
-      "name": "sellWrappedSolV0",
-      "accounts": [
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wrappedSolMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "solStorage",
+          "name": "reserveStaking",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "source",
-          "isMut": true,
+          "name": "stakePool",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "stakePoolProgram",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "destination",
-          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "poolMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "solStorage",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SellWrappedSolV0Args"
-          }
-        }
-      ]
-    },
-    {
-      "name": "createCurveV0",
-      "accounts": [
+        },
         {
-          "name": "payer",
+          "name": "poolTokens",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "curve",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -190,102 +129,37 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
         {
           "name": "args",
           "type": {
-            "defined": "CreateCurveV0Args"
+            "defined": "InitializeReserveStakingV0Args"
           }
         }
       ]
     },
     {
-      "name": "initializeTokenBondingV0",
+      "name": "updateReserveStakingV0",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "curve",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenBonding",
+          "name": "reserveStaking",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "baseMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "targetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "baseStorage",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "buyBaseRoyalties",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "buyTargetRoyalties",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellBaseRoyalties",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellTargetRoyalties",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "InitializeTokenBondingV0Args"
+            "defined": "UpdateReserveStakingV0Args"
           }
         }
       ]
     },
     {
-      "name": "closeTokenBondingV0",
+      "name": "initializeReserveStakeV0",
       "accounts": [
-        {
-          "name": "refund",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "tokenBonding",
           "isMut": true,
@@ -297,17 +171,12 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
           "isSigner": true
         },
         {
-          "name": "targetMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "baseStorage",
-          "isMut": true,
+          "name": "state",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "reserveStaking",
           "isMut": false,
           "isSigner": false
         }
@@ -315,98 +184,100 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
       "args": []
     },
     {
-      "name": "transferReservesV0",
+      "name": "rebalanceReserveStakingV0",
       "accounts": [
         {
           "name": "common",
           "accounts": [
             {
-              "name": "tokenBonding",
+              "name": "state",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "reserveStaking",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "reserveAuthority",
-              "isMut": false,
-              "isSigner": true
+              "name": "wrappedSolMint",
+              "isMut": true,
+              "isSigner": false
             },
             {
-              "name": "baseMint",
-              "isMut": false,
+              "name": "solStorage",
+              "isMut": true,
               "isSigner": false
             },
             {
-              "name": "baseStorage",
+              "name": "poolTokens",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "tokenProgram",
+              "name": "stakePool",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "stakePoolWithdrawAuthority",
               "isMut": false,
               "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "destination",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "TransferReservesV0Args"
-          }
-        }
-      ]
-    },
-    {
-      "name": "transferReservesNativeV0",
-      "accounts": [
-        {
-          "name": "common",
-          "accounts": [
+            },
             {
-              "name": "tokenBonding",
+              "name": "reserveStake",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "reserveAuthority",
+              "name": "managerFeeAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "stakePoolProgram",
               "isMut": false,
-              "isSigner": true
+              "isSigner": false
             },
             {
-              "name": "baseMint",
+              "name": "clock",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "baseStorage",
-              "isMut": true,
+              "name": "stakeHistory",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "stakeProgram",
+              "isMut": false,
               "isSigner": false
             },
             {
               "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
             }
           ]
         },
         {
-          "name": "destination",
+          "name": "tokenBonding",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "state",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "wrappedSolMint",
+          "name": "baseStorage",
           "isMut": true,
           "isSigner": false
         },
@@ -414,179 +285,68 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
           "name": "mintAuthority",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "solStorage",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "TransferReservesV0Args"
-          }
-        }
-      ]
-    },
-    {
-      "name": "updateReserveAuthorityV0",
-      "accounts": [
-        {
-          "name": "tokenBonding",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "reserveAuthority",
-          "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "UpdateReserveAuthorityV0Args"
-          }
-        }
-      ]
-    },
-    {
-      "name": "updateCurveV0",
-      "accounts": [
-        {
-          "name": "tokenBonding",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "curveAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "curve",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "UpdateCurveV0Args"
-          }
-        }
-      ]
-    },
-    {
-      "name": "updateTokenBondingV0",
-      "accounts": [
-        {
-          "name": "tokenBonding",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "generalAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "baseMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "targetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "buyBaseRoyalties",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "buyTargetRoyalties",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellBaseRoyalties",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellTargetRoyalties",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "UpdateTokenBondingV0Args"
-          }
-        }
-      ]
-    },
-    {
-      "name": "buyV1",
-      "accounts": [
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReserveYieldV0",
+      "accounts": [
         {
           "name": "common",
           "accounts": [
             {
-              "name": "tokenBonding",
+              "name": "state",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "reserveStaking",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "curve",
-              "isMut": false,
+              "name": "wrappedSolMint",
+              "isMut": true,
               "isSigner": false
             },
             {
-              "name": "baseMint",
-              "isMut": false,
+              "name": "solStorage",
+              "isMut": true,
               "isSigner": false
             },
             {
-              "name": "targetMint",
+              "name": "poolTokens",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "baseStorage",
+              "name": "stakePool",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "buyBaseRoyalties",
+              "name": "stakePoolWithdrawAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "reserveStake",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "destination",
+              "name": "managerFeeAccount",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "buyTargetRoyalties",
+              "name": "poolMint",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "tokenProgram",
+              "name": "stakePoolProgram",
               "isMut": false,
               "isSigner": false
             },
@@ -594,82 +354,105 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
               "name": "clock",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "stakeHistory",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "stakeProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
             }
           ]
         },
         {
-          "name": "state",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "source",
+          "name": "tokenBonding",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sourceAuthority",
-          "isMut": false,
-          "isSigner": true
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "BuyV0Args"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "buyNativeV0",
+      "name": "addReserveYieldV0",
       "accounts": [
         {
           "name": "common",
           "accounts": [
             {
-              "name": "tokenBonding",
+              "name": "state",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "reserveStaking",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "curve",
-              "isMut": false,
+              "name": "wrappedSolMint",
+              "isMut": true,
               "isSigner": false
             },
             {
-              "name": "baseMint",
-              "isMut": false,
+              "name": "solStorage",
+              "isMut": true,
               "isSigner": false
             },
             {
-              "name": "targetMint",
+              "name": "poolTokens",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "baseStorage",
+              "name": "stakePool",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "buyBaseRoyalties",
+              "name": "stakePoolWithdrawAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "reserveStake",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "destination",
+              "name": "managerFeeAccount",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "buyTargetRoyalties",
+              "name": "poolMint",
               "isMut": true,
               "isSigner": false
             },
             {
-              "name": "tokenProgram",
+              "name": "stakePoolProgram",
               "isMut": false,
               "isSigner": false
             },
@@ -677,14 +460,55 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
               "name": "clock",
               "isMut": false,
               "isSigner": false
+            },
+            {
+              "name": "stakeHistory",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "stakeProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
             }
           ]
         },
         {
-          "name": "source",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "buyWrappedSolV0",
+      "accounts": [
         {
           "name": "state",
           "isMut": false,
@@ -706,188 +530,1517 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "source",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
           "name": "args",
           "type": {
-            "defined": "BuyV0Args"
+            "defined": "BuyWrappedSolV0Args"
           }
         }
       ]
     },
     {
-      "name": "sellV1",
+      "name": "sellWrappedSolV0",
       "accounts": [
-        {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "tokenBonding",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "curve",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "baseMint",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "targetMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "baseStorage",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sellBaseRoyalties",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "source",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sourceAuthority",
-              "isMut": false,
-              "isSigner": true
-            },
-            {
-              "name": "sellTargetRoyalties",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
         {
           "name": "state",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "destination",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "SellV0Args"
+            "defined": "SellWrappedSolV0Args"
           }
         }
       ]
     },
     {
-      "name": "sellNativeV0",
+      "name": "createCurveV0",
       "accounts": [
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "tokenBonding",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "curve",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "baseMint",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "targetMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "baseStorage",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sellBaseRoyalties",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "source",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "sourceAuthority",
-              "isMut": false,
-              "isSigner": true
-            },
-            {
-              "name": "sellTargetRoyalties",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "clock",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "destination",
+          "name": "curve",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "state",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wrappedSolMint",
-          "isMut": true,
+          "name": "rent",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateCurveV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeTokenBondingV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "mintAuthority",
+          "name": "curve",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "solStorage",
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeTokenBondingV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeTokenBondingV1",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeTokenBondingV1Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeTokenBondingV0",
+      "accounts": [
+        {
+          "name": "refund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "generalAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "targetMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferReservesV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferReservesV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferReservesNativeV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferReservesV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferReservesV1",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferReservesV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferReservesNativeV1",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "reserveAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferReservesV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateReserveAuthorityV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "reserveAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateReserveAuthorityV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCurveV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "curveAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateCurveV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCurveV1",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "curveAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateCurveV1Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeCurveUpdateV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "curveAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ProposeCurveUpdateV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "applyCurveUpdateV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelCurveUpdateV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "curveAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "lockTokenBondingV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "generalAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LockTokenBondingV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "lockCurveV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "curveAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "windDownTokenBondingV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "generalAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializePriceOracleV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceOracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializePriceOracleV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "recordPriceObservationV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceOracle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "getTwapV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "priceOracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GetTwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTokenBondingV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "generalAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateTokenBondingV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTokenBondingV1",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "generalAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateTokenBondingV1Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyV1",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyNativeV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyV2",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyV1Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "buyNativeV1",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyV1Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellV1",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sellBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "sellTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellNativeV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sellBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "sellTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
           "isMut": true,
           "isSigner": false
         },
@@ -903,103 +2056,2073 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
           "type": {
             "defined": "SellV0Args"
           }
-        }
-      ]
-    }
-  ],
-  "accounts": [
+        }
+      ]
+    },
+    {
+      "name": "sellV2",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sellBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "sellTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sellNativeV1",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sellBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "sellTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "quoteBuyV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BuyV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "quoteSellV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellBaseRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellTargetRoyalties",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SellV2Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "quoteSpotPriceV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "quoteSolvencyV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "curve",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "baseStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapV0",
+      "accounts": [
+        {
+          "name": "sell",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sellBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "sellTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "buy",
+          "accounts": [
+            {
+              "name": "tokenBonding",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "curve",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "baseMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "baseStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyBaseRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "buyTargetRoyalties",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "routeSwapV0",
+      "accounts": [
+        {
+          "name": "state",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedSolMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mintAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RouteSwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeWalletPurchaseV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBonding",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "walletPurchase",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resizeTokenBondingV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeRoyaltyVestingV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "generalAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "targetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeRoyaltyVestingV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimVestedRoyaltiesV0",
+      "accounts": [
+        {
+          "name": "tokenBonding",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeRoyaltySplitV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "royaltySplit",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeRoyaltySplitV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRoyaltySplitV0",
+      "accounts": [
+        {
+          "name": "royaltySplit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ClaimRoyaltySplitV0Args"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "ProgramStateV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wrappedSolMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "solStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintAuthorityBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "solStorageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CurveV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "definition",
+            "type": {
+              "defined": "PiecewiseCurve"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenBondingV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "targetMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "generalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "reserveAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "curveAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "baseStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyBaseRoyalties",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyTargetRoyalties",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellBaseRoyalties",
+            "type": "publicKey"
+          },
+          {
+            "name": "sellTargetRoyalties",
+            "type": "publicKey"
+          },
+          {
+            "name": "buyBaseRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "buyTargetRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "sellBaseRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "sellTargetRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "curve",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "purchaseCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeBuyUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "buyFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "sellFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "index",
+            "type": "u16"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "baseStorageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "targetMintAuthorityBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "baseStorageAuthorityBumpSeed",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "reserveBalanceFromBonding",
+            "type": "u64"
+          },
+          {
+            "name": "supplyFromBonding",
+            "type": "u64"
+          },
+          {
+            "name": "ignoreExternalReserveChanges",
+            "type": "bool"
+          },
+          {
+            "name": "ignoreExternalSupplyChanges",
+            "type": "bool"
+          },
+          {
+            "name": "walletPurchaseCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "walletPurchaseWindowSecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "allowlistRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "allowlistEndUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "buyGate",
+            "type": {
+              "option": {
+                "defined": "BuyGateV0"
+              }
+            }
+          },
+          {
+            "name": "royaltyVesting",
+            "type": {
+              "option": {
+                "defined": "RoyaltyVestingV0"
+              }
+            }
+          },
+          {
+            "name": "priceImpactFees",
+            "type": {
+              "option": {
+                "defined": "PriceImpactFeesV0"
+              }
+            }
+          },
+          {
+            "name": "curveUpdateDelaySecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "pendingCurveUpdate",
+            "type": {
+              "option": {
+                "defined": "PendingCurveUpdateV0"
+              }
+            }
+          },
+          {
+            "name": "royaltiesLocked",
+            "type": "bool"
+          },
+          {
+            "name": "capsLocked",
+            "type": "bool"
+          },
+          {
+            "name": "curveLocked",
+            "type": "bool"
+          },
+          {
+            "name": "royaltyChangeLimits",
+            "type": {
+              "option": {
+                "defined": "RoyaltyChangeLimitsV0"
+              }
+            }
+          },
+          {
+            "name": "royaltyChangePeriod",
+            "type": {
+              "option": {
+                "defined": "RoyaltyChangePeriodV0"
+              }
+            }
+          },
+          {
+            "name": "windDown",
+            "type": "bool"
+          },
+          {
+            "name": "priceAccumulator",
+            "type": {
+              "option": {
+                "defined": "PriceAccumulatorV0"
+              }
+            }
+          },
+          {
+            "name": "reserveStake",
+            "type": {
+              "option": {
+                "defined": "ReserveStakeV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RoyaltySplitV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "escrow",
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipients",
+            "type": {
+              "vec": {
+                "defined": "RoyaltyRecipientV0"
+              }
+            }
+          },
+          {
+            "name": "totalClaimedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "totalNativeClaimedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "vaultBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceOracleV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenBonding",
+            "type": "publicKey"
+          },
+          {
+            "name": "observationIntervalSecs",
+            "type": "i64"
+          },
+          {
+            "name": "nextIndex",
+            "type": "u16"
+          },
+          {
+            "name": "observations",
+            "type": {
+              "vec": {
+                "defined": "PriceObservationV0"
+              }
+            }
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "WalletPurchaseV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenBonding",
+            "type": "publicKey"
+          },
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "purchasedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "windowStartUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReserveStakingV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "stakePoolProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "stakePool",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolTokens",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxStakedPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "poolTokensBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BuyWithBaseV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minimumTargetAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyTargetAmountV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maximumPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyWithBase",
+            "type": {
+              "option": {
+                "defined": "BuyWithBaseV0Args"
+              }
+            }
+          },
+          {
+            "name": "buyTargetAmount",
+            "type": {
+              "option": {
+                "defined": "BuyTargetAmountV0Args"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyV1Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyWithBase",
+            "type": {
+              "option": {
+                "defined": "BuyWithBaseV0Args"
+              }
+            }
+          },
+          {
+            "name": "buyTargetAmount",
+            "type": {
+              "option": {
+                "defined": "BuyTargetAmountV0Args"
+              }
+            }
+          },
+          {
+            "name": "allowlistProof",
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyWrappedSolV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClaimRoyaltySplitV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "native",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateCurveV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "definition",
+            "type": {
+              "defined": "PiecewiseCurve"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GetTwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSecs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializePriceOracleV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "observationIntervalSecs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RoyaltyRecipientArgsV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeRoyaltySplitV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipients",
+            "type": {
+              "vec": {
+                "defined": "RoyaltyRecipientArgsV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeRoyaltyVestingV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "cliffSecs",
+            "type": "i64"
+          },
+          {
+            "name": "durationSecs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeSolStorageV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintAuthorityBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "solStorageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeTokenBondingV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyBaseRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "buyTargetRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "sellBaseRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "sellTargetRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeBuyUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "mintCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "purchaseCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "generalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "reserveAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "curveAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "buyFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "index",
+            "type": "u16"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "sellFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "ignoreExternalReserveChanges",
+            "type": "bool"
+          },
+          {
+            "name": "ignoreExternalSupplyChanges",
+            "type": "bool"
+          },
+          {
+            "name": "initialReservesPad",
+            "type": "u64"
+          },
+          {
+            "name": "initialSupplyPad",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeTokenBondingV1Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buyBaseRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "buyTargetRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "sellBaseRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "sellTargetRoyaltyPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeBuyUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "mintCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "purchaseCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "generalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "reserveAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "curveAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "buyFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "index",
+            "type": "u16"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "sellFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "ignoreExternalReserveChanges",
+            "type": "bool"
+          },
+          {
+            "name": "ignoreExternalSupplyChanges",
+            "type": "bool"
+          },
+          {
+            "name": "initialReservesPad",
+            "type": "u64"
+          },
+          {
+            "name": "initialSupplyPad",
+            "type": "u64"
+          },
+          {
+            "name": "walletPurchaseCap",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "walletPurchaseWindowSecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "allowlistRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "allowlistEndUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "buyGate",
+            "type": {
+              "option": {
+                "defined": "BuyGateV0"
+              }
+            }
+          },
+          {
+            "name": "priceImpactFees",
+            "type": {
+              "option": {
+                "defined": "PriceImpactFeesV0"
+              }
+            }
+          },
+          {
+            "name": "curveUpdateDelaySecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "royaltyChangeLimits",
+            "type": {
+              "option": {
+                "defined": "RoyaltyChangeLimitsV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LockTokenBondingV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lockRoyalties",
+            "type": "bool"
+          },
+          {
+            "name": "lockCaps",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposeCurveUpdateV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "effectiveUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeReserveStakingV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxStakedPercentage",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateReserveStakingV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxStakedPercentage",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RouteHopV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "extraAccounts",
+            "type": "u8"
+          },
+          {
+            "name": "allowlistProof",
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RouteSwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inputMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "minimumOutputAmount",
+            "type": "u64"
+          },
+          {
+            "name": "hops",
+            "type": {
+              "vec": {
+                "defined": "RouteHopV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minimumPrice",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellForBaseV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maximumTargetAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SellV2Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minimumPrice",
+            "type": "u64"
+          },
+          {
+            "name": "sellForBase",
+            "type": {
+              "option": {
+                "defined": "SellForBaseV0Args"
+              }
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "ProgramStateV0",
+      "name": "SellWrappedSolV0Args",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "wrappedSolMint",
-            "type": "publicKey"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "solStorage",
-            "type": "publicKey"
-          },
+            "name": "all",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "mintAuthorityBumpSeed",
-            "type": "u8"
+            "name": "targetAmount",
+            "type": "u64"
           },
           {
-            "name": "solStorageBumpSeed",
-            "type": "u8"
+            "name": "minimumTargetAmount",
+            "type": "u64"
           },
           {
-            "name": "bumpSeed",
-            "type": "u8"
+            "name": "allowlistProof",
+            "type": {
+              "option": {
+                "vec": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "CurveV0",
+      "name": "TransferReservesV0Args",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "definition",
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateCurveV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "curveAuthority",
             "type": {
-              "defined": "PiecewiseCurve"
+              "option": "publicKey"
             }
           }
         ]
       }
     },
     {
-      "name": "TokenBondingV0",
+      "name": "UpdateCurveV1Args",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "baseMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "targetMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "generalAuthority",
+            "name": "curveAuthority",
             "type": {
               "option": "publicKey"
             }
           },
           {
-            "name": "reserveAuthority",
+            "name": "curveUpdateDelaySecs",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateReserveAuthorityV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newReserveAuthority",
             "type": {
               "option": "publicKey"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateTokenBondingV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "curveAuthority",
+            "name": "generalAuthority",
             "type": {
               "option": "publicKey"
             }
           },
           {
-            "name": "baseStorage",
-            "type": "publicKey"
+            "name": "buyBaseRoyaltyPercentage",
+            "type": "u32"
           },
           {
-            "name": "buyBaseRoyalties",
-            "type": "publicKey"
+            "name": "buyTargetRoyaltyPercentage",
+            "type": "u32"
           },
           {
-            "name": "buyTargetRoyalties",
-            "type": "publicKey"
+            "name": "sellBaseRoyaltyPercentage",
+            "type": "u32"
           },
           {
-            "name": "sellBaseRoyalties",
-            "type": "publicKey"
+            "name": "sellTargetRoyaltyPercentage",
+            "type": "u32"
           },
           {
-            "name": "sellTargetRoyalties",
-            "type": "publicKey"
+            "name": "buyFrozen",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateTokenBondingV1Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "generalAuthority",
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
             "name": "buyBaseRoyaltyPercentage",
@@ -1018,136 +4141,249 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
             "type": "u32"
           },
           {
-            "name": "curve",
-            "type": "publicKey"
+            "name": "buyFrozen",
+            "type": "bool"
           },
           {
-            "name": "mintCap",
+            "name": "allowlist",
             "type": {
-              "option": "u64"
+              "option": {
+                "defined": "AllowlistUpdateV0"
+              }
             }
           },
           {
-            "name": "purchaseCap",
+            "name": "buyGate",
             "type": {
-              "option": "u64"
+              "option": {
+                "defined": "BuyGateUpdateV0"
+              }
             }
           },
           {
-            "name": "goLiveUnixTime",
-            "type": "i64"
+            "name": "priceImpactFees",
+            "type": {
+              "option": {
+                "defined": "PriceImpactFeesUpdateV0"
+              }
+            }
           },
           {
-            "name": "freezeBuyUnixTime",
+            "name": "royaltyChangeLimits",
             "type": {
-              "option": "i64"
+              "option": {
+                "defined": "RoyaltyChangeLimitsV0"
+              }
             }
           },
           {
-            "name": "createdAtUnixTime",
-            "type": "i64"
-          },
+            "name": "caps",
+            "type": {
+              "option": {
+                "defined": "CapsUpdateV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllowlistUpdateV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "buyFrozen",
-            "type": "bool"
+            "name": "root",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           },
           {
-            "name": "sellFrozen",
-            "type": "bool"
-          },
+            "name": "endUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyGateUpdateV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "index",
-            "type": "u16"
-          },
+            "name": "buyGate",
+            "type": {
+              "option": {
+                "defined": "BuyGateV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceImpactFeesUpdateV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "bumpSeed",
-            "type": "u8"
+            "name": "priceImpactFees",
+            "type": {
+              "option": {
+                "defined": "PriceImpactFeesV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CapsUpdateV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintCap",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "baseStorageBumpSeed",
-            "type": "u8"
+            "name": "purchaseCap",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "targetMintAuthorityBumpSeed",
-            "type": "u8"
+            "name": "walletPurchaseCap",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "baseStorageAuthorityBumpSeed",
+            "name": "walletPurchaseWindowSecs",
             "type": {
-              "option": "u8"
+              "option": "i64"
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TwapV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "startUnixTime",
+            "type": "i64"
           },
           {
-            "name": "reserveBalanceFromBonding",
+            "name": "endUnixTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QuoteV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
             "type": "u64"
           },
           {
-            "name": "supplyFromBonding",
+            "name": "totalAmount",
             "type": "u64"
           },
           {
-            "name": "ignoreExternalReserveChanges",
-            "type": "bool"
+            "name": "baseRoyalties",
+            "type": "u64"
           },
           {
-            "name": "ignoreExternalSupplyChanges",
-            "type": "bool"
+            "name": "targetRoyalties",
+            "type": "u64"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "BuyWithBaseV0Args",
+      "name": "SpotPriceV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "baseAmount",
+            "name": "buyPrice",
             "type": "u64"
           },
           {
-            "name": "minimumTargetAmount",
+            "name": "sellPrice",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "BuyTargetAmountV0Args",
+      "name": "SolvencyV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "targetAmount",
+            "name": "reserves",
             "type": "u64"
           },
           {
-            "name": "maximumPrice",
+            "name": "requiredReserves",
+            "type": "u64"
+          },
+          {
+            "name": "solvencyRatioBps",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "BuyV0Args",
+      "name": "TimeCurveV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "buyWithBase",
+            "name": "offset",
+            "type": "i64"
+          },
+          {
+            "name": "curve",
+            "type": {
+              "defined": "PrimitiveCurve"
+            }
+          },
+          {
+            "name": "buyTransitionFees",
             "type": {
               "option": {
-                "defined": "BuyWithBaseV0Args"
+                "defined": "TransitionFeeV0"
               }
             }
           },
           {
-            "name": "buyTargetAmount",
+            "name": "sellTransitionFees",
             "type": {
               "option": {
-                "defined": "BuyTargetAmountV0Args"
+                "defined": "TransitionFeeV0"
               }
             }
           }
@@ -1155,299 +4391,286 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
       }
     },
     {
-      "name": "BuyWrappedSolV0Args",
+      "name": "SupplyCurveV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "offset",
+            "type": "u128"
+          },
+          {
+            "name": "curve",
+            "type": {
+              "defined": "PrimitiveCurve"
+            }
           }
         ]
       }
     },
     {
-      "name": "CreateCurveV0Args",
+      "name": "PricePointV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "definition",
-            "type": {
-              "defined": "PiecewiseCurve"
-            }
+            "name": "supply",
+            "type": "u128"
+          },
+          {
+            "name": "price",
+            "type": "u128"
           }
         ]
       }
     },
     {
-      "name": "InitializeSolStorageV0Args",
+      "name": "TransitionFeeV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mintAuthorityBumpSeed",
-            "type": "u8"
-          },
-          {
-            "name": "solStorageBumpSeed",
-            "type": "u8"
+            "name": "percentage",
+            "type": "u32"
           },
           {
-            "name": "bumpSeed",
-            "type": "u8"
+            "name": "interval",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "InitializeTokenBondingV0Args",
+      "name": "BuyGateV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "buyBaseRoyaltyPercentage",
-            "type": "u32"
-          },
-          {
-            "name": "buyTargetRoyaltyPercentage",
-            "type": "u32"
-          },
-          {
-            "name": "sellBaseRoyaltyPercentage",
-            "type": "u32"
-          },
-          {
-            "name": "sellTargetRoyaltyPercentage",
-            "type": "u32"
-          },
-          {
-            "name": "goLiveUnixTime",
-            "type": "i64"
-          },
-          {
-            "name": "freezeBuyUnixTime",
+            "name": "gateType",
             "type": {
-              "option": "i64"
+              "defined": "BuyGateType"
             }
           },
           {
-            "name": "mintCap",
-            "type": {
-              "option": "u64"
-            }
+            "name": "key",
+            "type": "publicKey"
           },
           {
-            "name": "purchaseCap",
-            "type": {
-              "option": "u64"
-            }
-          },
+            "name": "minimumAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RoyaltyVestingV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "generalAuthority",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "escrow",
+            "type": "publicKey"
           },
           {
-            "name": "reserveAuthority",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "destination",
+            "type": "publicKey"
           },
           {
-            "name": "curveAuthority",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "startUnixTime",
+            "type": "i64"
           },
           {
-            "name": "buyFrozen",
-            "type": "bool"
+            "name": "cliffSecs",
+            "type": "i64"
           },
           {
-            "name": "index",
-            "type": "u16"
+            "name": "durationSecs",
+            "type": "i64"
           },
           {
-            "name": "bumpSeed",
-            "type": "u8"
+            "name": "totalVestingAmount",
+            "type": "u64"
           },
           {
-            "name": "sellFrozen",
-            "type": "bool"
+            "name": "unvestedAmount",
+            "type": "u64"
           },
           {
-            "name": "ignoreExternalReserveChanges",
-            "type": "bool"
+            "name": "releaseUnixTime",
+            "type": "i64"
           },
           {
-            "name": "ignoreExternalSupplyChanges",
-            "type": "bool"
+            "name": "endUnixTime",
+            "type": "i64"
           },
           {
-            "name": "initialReservesPad",
+            "name": "claimedAmount",
             "type": "u64"
           },
           {
-            "name": "initialSupplyPad",
-            "type": "u64"
+            "name": "escrowBumpSeed",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "SellV0Args",
+      "name": "PriceImpactFeesV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "targetAmount",
-            "type": "u64"
+            "name": "minImpactPercentage",
+            "type": "u32"
           },
           {
-            "name": "minimumPrice",
-            "type": "u64"
+            "name": "maxImpactPercentage",
+            "type": "u32"
+          },
+          {
+            "name": "maxRoyaltyPercentage",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "SellWrappedSolV0Args",
+      "name": "RoyaltyChangeLimitsV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "maxChangePercentage",
+            "type": "u32"
           },
           {
-            "name": "all",
-            "type": "bool"
+            "name": "periodSecs",
+            "type": "i64"
+          },
+          {
+            "name": "maxRoyaltyPercentage",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "TransferReservesV0Args",
+      "name": "RoyaltyChangePeriodV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "startUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "startPercentages",
+            "type": {
+              "array": [
+                "u32",
+                6
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "UpdateCurveV0Args",
+      "name": "PendingCurveUpdateV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "curveAuthority",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "curve",
+            "type": "publicKey"
+          },
+          {
+            "name": "effectiveUnixTime",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "UpdateReserveAuthorityV0Args",
+      "name": "PriceAccumulatorV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "newReserveAuthority",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "lastPrice",
+            "type": "u64"
+          },
+          {
+            "name": "lastUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
           }
         ]
       }
     },
     {
-      "name": "UpdateTokenBondingV0Args",
+      "name": "ReserveStakeV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "generalAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "buyBaseRoyaltyPercentage",
-            "type": "u32"
-          },
-          {
-            "name": "buyTargetRoyaltyPercentage",
-            "type": "u32"
+            "name": "poolTokens",
+            "type": "u64"
           },
           {
-            "name": "sellBaseRoyaltyPercentage",
-            "type": "u32"
+            "name": "stakedLamports",
+            "type": "u64"
           },
           {
-            "name": "sellTargetRoyaltyPercentage",
-            "type": "u32"
+            "name": "totalYieldLamports",
+            "type": "u64"
           },
           {
-            "name": "buyFrozen",
-            "type": "bool"
+            "name": "totalLossLamports",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "TimeCurveV0",
+      "name": "RoyaltyRecipientV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "offset",
-            "type": "i64"
+            "name": "wallet",
+            "type": "publicKey"
           },
           {
-            "name": "curve",
-            "type": {
-              "defined": "PrimitiveCurve"
-            }
+            "name": "shareBps",
+            "type": "u16"
           },
           {
-            "name": "buyTransitionFees",
-            "type": {
-              "option": {
-                "defined": "TransitionFeeV0"
-              }
-            }
+            "name": "claimedAmount",
+            "type": "u64"
           },
           {
-            "name": "sellTransitionFees",
-            "type": {
-              "option": {
-                "defined": "TransitionFeeV0"
-              }
-            }
+            "name": "nativeClaimedAmount",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "TransitionFeeV0",
+      "name": "PriceObservationV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "percentage",
-            "type": "u32"
+            "name": "unixTime",
+            "type": "i64"
           },
-          {
-            "name": "interval",
-            "type": "u32"
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
           }
         ]
       }
@@ -1502,6 +4725,53 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
                 "type": "u128"
               }
             ]
+          },
+          {
+            "name": "LogarithmicCurveV0",
+            "fields": [
+              {
+                "name": "c",
+                "type": "u128"
+              },
+              {
+                "name": "g",
+                "type": "u128"
+              },
+              {
+                "name": "b",
+                "type": "u128"
+              }
+            ]
+          },
+          {
+            "name": "SigmoidCurveV0",
+            "fields": [
+              {
+                "name": "max_price",
+                "type": "u128"
+              },
+              {
+                "name": "k",
+                "type": "u128"
+              },
+              {
+                "name": "midpoint",
+                "type": "u128"
+              }
+            ]
+          },
+          {
+            "name": "PriceTableCurveV0",
+            "fields": [
+              {
+                "name": "points",
+                "type": {
+                  "vec": {
+                    "defined": "PricePointV0"
+                  }
+                }
+              }
+            ]
           }
         ]
       }
@@ -1523,11 +4793,274 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
                 }
               }
             ]
+          },
+          {
+            "name": "SupplyV0",
+            "fields": [
+              {
+                "name": "curves",
+                "type": {
+                  "vec": {
+                    "defined": "SupplyCurveV0"
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "BuyGateType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Token"
+          },
+          {
+            "name": "Collection"
           }
         ]
       }
     }
   ],
+  "events": [
+    {
+      "name": "BuyEventV0",
+      "fields": [
+        {
+          "name": "tokenBonding",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "targetAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "baseRoyalties",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "targetRoyalties",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "reserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "supply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unixTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SellEventV0",
+      "fields": [
+        {
+          "name": "tokenBonding",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "seller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "targetAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "reclaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "baseRoyalties",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "targetRoyalties",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "reserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "supply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unixTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TransferReservesEventV0",
+      "fields": [
+        {
+          "name": "tokenBonding",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reserveAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "destination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "reserves",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unixTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CurveUpdateEventV0",
+      "fields": [
+        {
+          "name": "tokenBonding",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousCurve",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "curve",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "curveAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "unixTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenBondingUpdateEventV0",
+      "fields": [
+        {
+          "name": "tokenBonding",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "generalAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "buyBaseRoyaltyPercentage",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "buyTargetRoyaltyPercentage",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "sellBaseRoyaltyPercentage",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "sellTargetRoyaltyPercentage",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "buyBaseRoyalties",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyTargetRoyalties",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sellBaseRoyalties",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sellTargetRoyalties",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "buyFrozen",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "unixTimestamp",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -1618,24 +5151,215 @@ export const SplTokenBondingIDLJson: Idl & { metadata?: { address: string } } =
       "code": 6017,
       "name": "InvalidPad",
       "msg": "Cannot pad token bonding without ignoring external reserve and supply changes. This is an advanced feature, incorrect use could lead to insufficient resreves to cover sells"
+    },
+    {
+      "code": 6018,
+      "name": "CurveAccountTooSmall",
+      "msg": "Curve account is too small to hold the curve definition"
+    },
+    {
+      "code": 6019,
+      "name": "SellAmountTooHigh",
+      "msg": "Selling for that base amount would sell more than the maximum target amount. Try increasing maximum_target_amount or slippage configuration"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidRoute",
+      "msg": "Route accounts do not form a valid path through the provided token bondings"
+    },
+    {
+      "code": 6021,
+      "name": "RouteOutputTooLow",
+      "msg": "Route output was lower than the minimum output amount. Try decreasing minimum_output_amount or increasing slippage configuration"
+    },
+    {
+      "code": 6022,
+      "name": "WalletPurchaseRequired",
+      "msg": "This token bonding has a per wallet purchase cap. Pass the buyer's wallet purchase account as the first remaining account"
+    },
+    {
+      "code": 6023,
+      "name": "OverWalletPurchaseCap",
+      "msg": "Cannot purchase that many tokens because of the per wallet purchase cap"
+    },
+    {
+      "code": 6024,
+      "name": "NotAllowlisted",
+      "msg": "Buying is limited to an allowlist right now. Provide a valid allowlist proof for the buyer's wallet"
+    },
+    {
+      "code": 6025,
+      "name": "BuyGateNotMet",
+      "msg": "Buyer does not hold enough of the token or NFT collection gating this bonding curve. Pass the holding accounts as remaining accounts"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMetadata",
+      "msg": "Metadata account is not the Metaplex metadata for the provided mint"
+    },
+    {
+      "code": 6027,
+      "name": "RoyaltyVestingAlreadySet",
+      "msg": "Royalty vesting has already been set up for this token bonding"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidVestingSchedule",
+      "msg": "Vesting cliff must be between zero and the vesting duration"
+    },
+    {
+      "code": 6029,
+      "name": "NoRoyaltyVesting",
+      "msg": "This token bonding does not vest royalties"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidPriceImpactFees",
+      "msg": "Price impact fees must have min impact below max impact"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidRoyaltySplit",
+      "msg": "Royalty splits need 1 to 16 distinct recipients with positive shares adding up to 10000 basis points"
+    },
+    {
+      "code": 6032,
+      "name": "UnknownRoyaltyRecipient",
+      "msg": "Account is not a recipient of this royalty split"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidCurveUpdateDelay",
+      "msg": "Curve update delay cannot be negative, and once set can only be raised"
+    },
+    {
+      "code": 6034,
+      "name": "CurveUpdateTimelocked",
+      "msg": "This curve update is timelocked. Propose it with propose_curve_update_v0 and apply it once the delay has passed"
+    },
+    {
+      "code": 6035,
+      "name": "NoPendingCurveUpdate",
+      "msg": "There is no pending curve update for this curve"
+    },
+    {
+      "code": 6036,
+      "name": "RoyaltiesLocked",
+      "msg": "Royalties on this token bonding are locked and can never change"
+    },
+    {
+      "code": 6037,
+      "name": "CurveLocked",
+      "msg": "The curve on this token bonding is locked and can never change"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidRoyaltyChangeLimits",
+      "msg": "Royalty change limits need a positive period, and once set can only be tightened"
+    },
+    {
+      "code": 6039,
+      "name": "RoyaltyChangeTooLarge",
+      "msg": "Royalties cannot rise that much this period or above the maximum royalty percentage"
+    },
+    {
+      "code": 6040,
+      "name": "WindingDown",
+      "msg": "This token bonding is winding down. Reserves are reserved for holders redeeming their tokens"
+    },
+    {
+      "code": 6041,
+      "name": "PriceOracleWindowTooLong",
+      "msg": "Price oracle has no observation old enough for this window"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPriceOracle",
+      "msg": "Price oracle does not belong to this token bonding"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidStakePool",
+      "msg": "Stake pool is not owned by the stake pool program, or does not match reserve staking"
+    },
+    {
+      "code": 6044,
+      "name": "StakePoolNotUpdated",
+      "msg": "Stake pool has not been updated this epoch"
+    },
+    {
+      "code": 6045,
+      "name": "NoReserveYield",
+      "msg": "Staked reserves have not earned any yield to claim"
+    },
+    {
+      "code": 6046,
+      "name": "ReservesInsolvent",
+      "msg": "Base storage would not cover buying back the outstanding supply on the curve"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidMaxStakedPercentage",
+      "msg": "Reserve staking can stake at most MAX_STAKED_PERCENTAGE of wrapped sol"
+    },
+    {
+      "code": 6048,
+      "name": "ReservesNotLiquid",
+      "msg": "Not enough unstaked reserves. Pass the reserve staking accounts so the sell can unstake, or run rebalance_reserve_staking_v0"
+    },
+    {
+      "code": 6049,
+      "name": "TransferReservesV1Only",
+      "msg": "Reserve transfers on bondings that ignore external changes or are winding down are only supported on v1 of the transfer reserves endpoints. Please upgrade your client"
+    },
+    {
+      "code": 6050,
+      "name": "CloseDestinationRequired",
+      "msg": "Closing a wound down bonding with reserves left needs a base mint token account of the general authority as the first remaining account"
+    },
+    {
+      "code": 6051,
+      "name": "ReserveStakeNotInitialized",
+      "msg": "Token bonding is not staking its reserves"
+    },
+    {
+      "code": 6052,
+      "name": "ReservesStaked",
+      "msg": "Token bonding still has reserves in the stake pool. Wind it down and run rebalance_reserve_staking_v0 to unstake them"
+    },
+    {
+      "code": 6053,
+      "name": "CapsLocked",
+      "msg": "Caps on this token bonding are locked and can never change"
     }
   ],
   "metadata": {
     "address": "TBondmkCYxaPCKG4CHYfVTcwQ8on31xnJrPzk8F8WsS"
   }
 };
-export type SplTokenBondingIDL = {"version":"3.9.2","name":"spl_token_bonding","instructions":[{"name":"initializeSolStorageV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"state","isMut":true,"isSigner":false},{"name":"solStorage","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":false,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeSolStorageV0Args"}}]},{"name":"buyWrappedSolV0","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"BuyWrappedSolV0Args"}}]},{"name":"sellWrappedSolV0","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"owner","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellWrappedSolV0Args"}}]},{"name":"createCurveV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"curve","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"CreateCurveV0Args"}}]},{"name":"initializeTokenBondingV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"curve","isMut":false,"isSigner":false},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":false,"isSigner":false},{"name":"buyBaseRoyalties","isMut":false,"isSigner":false},{"name":"buyTargetRoyalties","isMut":false,"isSigner":false},{"name":"sellBaseRoyalties","isMut":false,"isSigner":false},{"name":"sellTargetRoyalties","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeTokenBondingV0Args"}}]},{"name":"closeTokenBondingV0","accounts":[{"name":"refund","isMut":true,"isSigner":false},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"transferReservesV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferReservesV0Args"}}]},{"name":"transferReservesNativeV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferReservesV0Args"}}]},{"name":"updateReserveAuthorityV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true}],"args":[{"name":"args","type":{"defined":"UpdateReserveAuthorityV0Args"}}]},{"name":"updateCurveV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curveAuthority","isMut":false,"isSigner":true},{"name":"curve","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateCurveV0Args"}}]},{"name":"updateTokenBondingV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"buyBaseRoyalties","isMut":false,"isSigner":false},{"name":"buyTargetRoyalties","isMut":false,"isSigner":false},{"name":"sellBaseRoyalties","isMut":false,"isSigner":false},{"name":"sellTargetRoyalties","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateTokenBondingV0Args"}}]},{"name":"buyV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"buyBaseRoyalties","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"buyTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"state","isMut":false,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true}],"args":[{"name":"args","type":{"defined":"BuyV0Args"}}]},{"name":"buyNativeV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"buyBaseRoyalties","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"buyTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"source","isMut":true,"isSigner":true},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"BuyV0Args"}}]},{"name":"sellV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"sellBaseRoyalties","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"sellTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"state","isMut":false,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellV0Args"}}]},{"name":"sellNativeV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"sellBaseRoyalties","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"sellTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellV0Args"}}]}],"accounts":[{"name":"programStateV0","type":{"kind":"struct","fields":[{"name":"wrappedSolMint","type":"publicKey"},{"name":"solStorage","type":"publicKey"},{"name":"mintAuthorityBumpSeed","type":"u8"},{"name":"solStorageBumpSeed","type":"u8"},{"name":"bumpSeed","type":"u8"}]}},{"name":"curveV0","type":{"kind":"struct","fields":[{"name":"definition","type":{"defined":"PiecewiseCurve"}}]}},{"name":"tokenBondingV0","type":{"kind":"struct","fields":[{"name":"baseMint","type":"publicKey"},{"name":"targetMint","type":"publicKey"},{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"reserveAuthority","type":{"option":"publicKey"}},{"name":"curveAuthority","type":{"option":"publicKey"}},{"name":"baseStorage","type":"publicKey"},{"name":"buyBaseRoyalties","type":"publicKey"},{"name":"buyTargetRoyalties","type":"publicKey"},{"name":"sellBaseRoyalties","type":"publicKey"},{"name":"sellTargetRoyalties","type":"publicKey"},{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"curve","type":"publicKey"},{"name":"mintCap","type":{"option":"u64"}},{"name":"purchaseCap","type":{"option":"u64"}},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeBuyUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"buyFrozen","type":"bool"},{"name":"sellFrozen","type":"bool"},{"name":"index","type":"u16"},{"name":"bumpSeed","type":"u8"},{"name":"baseStorageBumpSeed","type":"u8"},{"name":"targetMintAuthorityBumpSeed","type":"u8"},{"name":"baseStorageAuthorityBumpSeed","type":{"option":"u8"}},{"name":"reserveBalanceFromBonding","type":"u64"},{"name":"supplyFromBonding","type":"u64"},{"name":"ignoreExternalReserveChanges","type":"bool"},{"name":"ignoreExternalSupplyChanges","type":"bool"}]}}],"types":[{"name":"BuyWithBaseV0Args","type":{"kind":"struct","fields":[{"name":"baseAmount","type":"u64"},{"name":"minimumTargetAmount","type":"u64"}]}},{"name":"BuyTargetAmountV0Args","type":{"kind":"struct","fields":[{"name":"targetAmount","type":"u64"},{"name":"maximumPrice","type":"u64"}]}},{"name":"BuyV0Args","type":{"kind":"struct","fields":[{"name":"buyWithBase","type":{"option":{"defined":"BuyWithBaseV0Args"}}},{"name":"buyTargetAmount","type":{"option":{"defined":"BuyTargetAmountV0Args"}}}]}},{"name":"BuyWrappedSolV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"CreateCurveV0Args","type":{"kind":"struct","fields":[{"name":"definition","type":{"defined":"PiecewiseCurve"}}]}},{"name":"InitializeSolStorageV0Args","type":{"kind":"struct","fields":[{"name":"mintAuthorityBumpSeed","type":"u8"},{"name":"solStorageBumpSeed","type":"u8"},{"name":"bumpSeed","type":"u8"}]}},{"name":"InitializeTokenBondingV0Args","type":{"kind":"struct","fields":[{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeBuyUnixTime","type":{"option":"i64"}},{"name":"mintCap","type":{"option":"u64"}},{"name":"purchaseCap","type":{"option":"u64"}},{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"reserveAuthority","type":{"option":"publicKey"}},{"name":"curveAuthority","type":{"option":"publicKey"}},{"name":"buyFrozen","type":"bool"},{"name":"index","type":"u16"},{"name":"bumpSeed","type":"u8"},{"name":"sellFrozen","type":"bool"},{"name":"ignoreExternalReserveChanges","type":"bool"},{"name":"ignoreExternalSupplyChanges","type":"bool"},{"name":"initialReservesPad","type":"u64"},{"name":"initialSupplyPad","type":"u64"}]}},{"name":"SellV0Args","type":{"kind":"struct","fields":[{"name":"targetAmount","type":"u64"},{"name":"minimumPrice","type":"u64"}]}},{"name":"SellWrappedSolV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"all","type":"bool"}]}},{"name":"TransferReservesV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"UpdateCurveV0Args","type":{"kind":"struct","fields":[{"name":"curveAuthority","type":{"option":"publicKey"}}]}},{"name":"UpdateReserveAuthorityV0Args","type":{"kind":"struct","fields":[{"name":"newReserveAuthority","type":{"option":"publicKey"}}]}},{"name":"UpdateTokenBondingV0Args","type":{"kind":"struct","fields":[{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"buyFrozen","type":"bool"}]}},{"name":"TimeCurveV0","type":{"kind":"struct","fields":[{"name":"offset","type":"i64"},{"name":"curve","type":{"defined":"PrimitiveCurve"}},{"name":"buyTransitionFees","type":{"option":{"defined":"TransitionFeeV0"}}},{"name":"sellTransitionFees","type":{"option":{"defined":"TransitionFeeV0"}}}]}},{"name":"TransitionFeeV0","type":{"kind":"struct","fields":[{"name":"percentage","type":"u32"},{"name":"interval","type":"u32"}]}},{"name":"PrimitiveCurve","type":{"kind":"enum","variants":[{"name":"ExponentialCurveV0","fields":[{"name":"c","type":"u128"},{"name":"b","type":"u128"},{"name":"pow","type":"u8"},{"name":"frac","type":"u8"}]},{"name":"TimeDecayExponentialCurveV0","fields":[{"name":"c","type":"u128"},{"name":"k1","type":"u128"},{"name":"k0","type":"u128"},{"name":"interval","type":"u32"},{"name":"d","type":"u128"}]}]}},{"name":"PiecewiseCurve","type":{"kind":"enum","variants":[{"name":"TimeV0","fields":[{"name":"curves","type":{"vec":{"defined":"TimeCurveV0"}}}]}]}}],"errors":[{"code":6000,"name":"NoMintAuthority","msg":"Target mint must have an authority"},{"code":6001,"name":"InvalidMintAuthority","msg":"Target mint must have an authority that is a pda of this program"},{"code":6002,"name":"InvalidBaseStorageAuthority","msg":"Invalid base storage authority pda or seed did not match canonical seed for base storage authority"},{"code":6003,"name":"NoAuthority","msg":"Token bonding does not have an authority"},{"code":6004,"name":"ArithmeticError","msg":"Error in precise number arithmetic"},{"code":6005,"name":"PriceTooHigh","msg":"Buy price was higher than the maximum buy price. Try increasing max_price or slippage configuration"},{"code":6006,"name":"PriceTooLow","msg":"Sell price was lower than the minimum sell price. Try decreasing min_price or increasing slippage configuration"},{"code":6007,"name":"MintSupplyTooLow","msg":"Cannot sell more than the target mint currently has in supply"},{"code":6008,"name":"SellDisabled","msg":"Sell is not enabled on this bonding curve"},{"code":6009,"name":"NotLiveYet","msg":"This bonding curve is not live yet"},{"code":6010,"name":"PassedMintCap","msg":"Passed the mint cap"},{"code":6011,"name":"OverPurchaseCap","msg":"Cannot purchase that many tokens because of purchase cap"},{"code":6012,"name":"BuyFrozen","msg":"Buy is frozen on this bonding curve, purchases not allowed"},{"code":6013,"name":"WrappedSolNotAllowed","msg":"Use token bonding wrapped sol via buy_wrapped_sol, sell_wrapped_sol commands. We may one day provide liquid staking rewards on this stored sol."},{"code":6014,"name":"InvalidCurve","msg":"The provided curve is invalid"},{"code":6015,"name":"InvalidMint","msg":"An account was provided that did not have the correct mint"},{"code":6016,"name":"IgnoreExternalV1Only","msg":"Ignoring external changes is only supported on v1 of buy and sell endpoints. Please upgrade your client"},{"code":6017,"name":"InvalidPad","msg":"Cannot pad token bonding without ignoring external reserve and supply changes. This is an advanced feature, incorrect use could lead to insufficient resreves to cover sells"}],"metadata":{"address":"TBondmkCYxaPCKG4CHYfVTcwQ8on31xnJrPzk8F8WsS"}};
+export type SplTokenBondingIDL = {"version":"3.9.2","name":"spl_token_bonding","instructions":[{"name":"initializeSolStorageV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"state","isMut":true,"isSigner":false},{"name":"solStorage","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":false,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeSolStorageV0Args"}}]},{"name":"initializeReserveStakingV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"upgradeAuthority","isMut":false,"isSigner":true},{"name":"programData","isMut":false,"isSigner":false},{"name":"state","isMut":false,"isSigner":false},{"name":"reserveStaking","isMut":true,"isSigner":false},{"name":"stakePool","isMut":false,"isSigner":false},{"name":"stakePoolProgram","isMut":false,"isSigner":false},{"name":"poolMint","isMut":false,"isSigner":false},{"name":"solStorage","isMut":false,"isSigner":false},{"name":"poolTokens","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeReserveStakingV0Args"}}]},{"name":"updateReserveStakingV0","accounts":[{"name":"reserveStaking","isMut":true,"isSigner":false},{"name":"authority","isMut":false,"isSigner":true}],"args":[{"name":"args","type":{"defined":"UpdateReserveStakingV0Args"}}]},{"name":"initializeReserveStakeV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true},{"name":"state","isMut":false,"isSigner":false},{"name":"reserveStaking","isMut":false,"isSigner":false}],"args":[]},{"name":"rebalanceReserveStakingV0","accounts":[{"name":"common","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"reserveStaking","isMut":true,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"poolTokens","isMut":true,"isSigner":false},{"name":"stakePool","isMut":true,"isSigner":false},{"name":"stakePoolWithdrawAuthority","isMut":false,"isSigner":false},{"name":"reserveStake","isMut":true,"isSigner":false},{"name":"managerFeeAccount","isMut":true,"isSigner":false},{"name":"poolMint","isMut":true,"isSigner":false},{"name":"stakePoolProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false},{"name":"stakeHistory","isMut":false,"isSigner":false},{"name":"stakeProgram","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}]},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false}],"args":[]},{"name":"claimReserveYieldV0","accounts":[{"name":"common","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"reserveStaking","isMut":true,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"poolTokens","isMut":true,"isSigner":false},{"name":"stakePool","isMut":true,"isSigner":false},{"name":"stakePoolWithdrawAuthority","isMut":false,"isSigner":false},{"name":"reserveStake","isMut":true,"isSigner":false},{"name":"managerFeeAccount","isMut":true,"isSigner":false},{"name":"poolMint","isMut":true,"isSigner":false},{"name":"stakePoolProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false},{"name":"stakeHistory","isMut":false,"isSigner":false},{"name":"stakeProgram","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}]},{"name":"authority","isMut":false,"isSigner":true},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false}],"args":[]},{"name":"addReserveYieldV0","accounts":[{"name":"common","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"reserveStaking","isMut":true,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"poolTokens","isMut":true,"isSigner":false},{"name":"stakePool","isMut":true,"isSigner":false},{"name":"stakePoolWithdrawAuthority","isMut":false,"isSigner":false},{"name":"reserveStake","isMut":true,"isSigner":false},{"name":"managerFeeAccount","isMut":true,"isSigner":false},{"name":"poolMint","isMut":true,"isSigner":false},{"name":"stakePoolProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false},{"name":"stakeHistory","isMut":false,"isSigner":false},{"name":"stakeProgram","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}]},{"name":"authority","isMut":false,"isSigner":true},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false}],"args":[]},{"name":"buyWrappedSolV0","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"BuyWrappedSolV0Args"}}]},{"name":"sellWrappedSolV0","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"owner","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellWrappedSolV0Args"}}]},{"name":"createCurveV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"curve","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"CreateCurveV0Args"}}]},{"name":"initializeTokenBondingV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"curve","isMut":false,"isSigner":false},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":false,"isSigner":false},{"name":"buyBaseRoyalties","isMut":false,"isSigner":false},{"name":"buyTargetRoyalties","isMut":false,"isSigner":false},{"name":"sellBaseRoyalties","isMut":false,"isSigner":false},{"name":"sellTargetRoyalties","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeTokenBondingV0Args"}}]},{"name":"initializeTokenBondingV1","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"curve","isMut":false,"isSigner":false},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":false,"isSigner":false},{"name":"buyBaseRoyalties","isMut":false,"isSigner":false},{"name":"buyTargetRoyalties","isMut":false,"isSigner":false},{"name":"sellBaseRoyalties","isMut":false,"isSigner":false},{"name":"sellTargetRoyalties","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeTokenBondingV1Args"}}]},{"name":"closeTokenBondingV0","accounts":[{"name":"refund","isMut":true,"isSigner":false},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"transferReservesV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferReservesV0Args"}}]},{"name":"transferReservesNativeV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferReservesV0Args"}}]},{"name":"transferReservesV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferReservesV0Args"}}]},{"name":"transferReservesNativeV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferReservesV0Args"}}]},{"name":"updateReserveAuthorityV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"reserveAuthority","isMut":false,"isSigner":true}],"args":[{"name":"args","type":{"defined":"UpdateReserveAuthorityV0Args"}}]},{"name":"updateCurveV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curveAuthority","isMut":false,"isSigner":true},{"name":"curve","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateCurveV0Args"}}]},{"name":"updateCurveV1","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curveAuthority","isMut":false,"isSigner":true},{"name":"curve","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateCurveV1Args"}}]},{"name":"proposeCurveUpdateV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curveAuthority","isMut":false,"isSigner":true},{"name":"curve","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"ProposeCurveUpdateV0Args"}}]},{"name":"applyCurveUpdateV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[]},{"name":"cancelCurveUpdateV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curveAuthority","isMut":false,"isSigner":true}],"args":[]},{"name":"lockTokenBondingV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true}],"args":[{"name":"args","type":{"defined":"LockTokenBondingV0Args"}}]},{"name":"lockCurveV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curveAuthority","isMut":false,"isSigner":true}],"args":[]},{"name":"windDownTokenBondingV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true}],"args":[]},{"name":"initializePriceOracleV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"priceOracle","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializePriceOracleV0Args"}}]},{"name":"recordPriceObservationV0","accounts":[{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"priceOracle","isMut":true,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[]},{"name":"getTwapV0","accounts":[{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"priceOracle","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"GetTwapV0Args"}}]},{"name":"updateTokenBondingV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"buyBaseRoyalties","isMut":false,"isSigner":false},{"name":"buyTargetRoyalties","isMut":false,"isSigner":false},{"name":"sellBaseRoyalties","isMut":false,"isSigner":false},{"name":"sellTargetRoyalties","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateTokenBondingV0Args"}}]},{"name":"updateTokenBondingV1","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"buyBaseRoyalties","isMut":false,"isSigner":false},{"name":"buyTargetRoyalties","isMut":false,"isSigner":false},{"name":"sellBaseRoyalties","isMut":false,"isSigner":false},{"name":"sellTargetRoyalties","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateTokenBondingV1Args"}}]},{"name":"buyV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"buyBaseRoyalties","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"buyTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"state","isMut":false,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true}],"args":[{"name":"args","type":{"defined":"BuyV0Args"}}]},{"name":"buyNativeV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"buyBaseRoyalties","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"buyTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"source","isMut":true,"isSigner":true},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"BuyV0Args"}}]},{"name":"buyV2","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"buyBaseRoyalties","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"buyTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"state","isMut":false,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true}],"args":[{"name":"args","type":{"defined":"BuyV1Args"}}]},{"name":"buyNativeV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"buyBaseRoyalties","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"buyTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"source","isMut":true,"isSigner":true},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"BuyV1Args"}}]},{"name":"sellV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"sellBaseRoyalties","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"sellTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"state","isMut":false,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellV0Args"}}]},{"name":"sellNativeV0","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"sellBaseRoyalties","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"sellTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellV0Args"}}]},{"name":"sellV2","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"sellBaseRoyalties","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"sellTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"state","isMut":false,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellV2Args"}}]},{"name":"sellNativeV1","accounts":[{"name":"common","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"sellBaseRoyalties","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"sellTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"destination","isMut":true,"isSigner":false},{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellV2Args"}}]},{"name":"quoteBuyV0","accounts":[{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":false,"isSigner":false},{"name":"buyBaseRoyalties","isMut":false,"isSigner":false},{"name":"destination","isMut":false,"isSigner":false},{"name":"buyTargetRoyalties","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"BuyV0Args"}}]},{"name":"quoteSellV0","accounts":[{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":false,"isSigner":false},{"name":"sellBaseRoyalties","isMut":false,"isSigner":false},{"name":"source","isMut":false,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":false},{"name":"sellTargetRoyalties","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SellV2Args"}}]},{"name":"quoteSpotPriceV0","accounts":[{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[]},{"name":"quoteSolvencyV0","accounts":[{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"baseStorage","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[]},{"name":"swapV0","accounts":[{"name":"sell","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"sellBaseRoyalties","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"sellTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"buy","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"curve","isMut":false,"isSigner":false},{"name":"baseMint","isMut":false,"isSigner":false},{"name":"targetMint","isMut":true,"isSigner":false},{"name":"baseStorage","isMut":true,"isSigner":false},{"name":"buyBaseRoyalties","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"buyTargetRoyalties","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]},{"name":"state","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"routeSwapV0","accounts":[{"name":"state","isMut":false,"isSigner":false},{"name":"wrappedSolMint","isMut":true,"isSigner":false},{"name":"mintAuthority","isMut":false,"isSigner":false},{"name":"solStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":true,"isSigner":true},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"RouteSwapV0Args"}}]},{"name":"initializeWalletPurchaseV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"tokenBonding","isMut":false,"isSigner":false},{"name":"wallet","isMut":false,"isSigner":false},{"name":"walletPurchase","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[]},{"name":"resizeTokenBondingV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"initializeRoyaltyVestingV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"generalAuthority","isMut":false,"isSigner":true},{"name":"targetMint","isMut":false,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeRoyaltyVestingV0Args"}}]},{"name":"claimVestedRoyaltiesV0","accounts":[{"name":"tokenBonding","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[]},{"name":"initializeRoyaltySplitV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"royaltySplit","isMut":true,"isSigner":true},{"name":"mint","isMut":false,"isSigner":false},{"name":"vault","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeRoyaltySplitV0Args"}}]},{"name":"claimRoyaltySplitV0","accounts":[{"name":"royaltySplit","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"vault","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"ClaimRoyaltySplitV0Args"}}]}],"accounts":[{"name":"programStateV0","type":{"kind":"struct","fields":[{"name":"wrappedSolMint","type":"publicKey"},{"name":"solStorage","type":"publicKey"},{"name":"mintAuthorityBumpSeed","type":"u8"},{"name":"solStorageBumpSeed","type":"u8"},{"name":"bumpSeed","type":"u8"}]}},{"name":"curveV0","type":{"kind":"struct","fields":[{"name":"definition","type":{"defined":"PiecewiseCurve"}}]}},{"name":"tokenBondingV0","type":{"kind":"struct","fields":[{"name":"baseMint","type":"publicKey"},{"name":"targetMint","type":"publicKey"},{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"reserveAuthority","type":{"option":"publicKey"}},{"name":"curveAuthority","type":{"option":"publicKey"}},{"name":"baseStorage","type":"publicKey"},{"name":"buyBaseRoyalties","type":"publicKey"},{"name":"buyTargetRoyalties","type":"publicKey"},{"name":"sellBaseRoyalties","type":"publicKey"},{"name":"sellTargetRoyalties","type":"publicKey"},{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"curve","type":"publicKey"},{"name":"mintCap","type":{"option":"u64"}},{"name":"purchaseCap","type":{"option":"u64"}},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeBuyUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"buyFrozen","type":"bool"},{"name":"sellFrozen","type":"bool"},{"name":"index","type":"u16"},{"name":"bumpSeed","type":"u8"},{"name":"baseStorageBumpSeed","type":"u8"},{"name":"targetMintAuthorityBumpSeed","type":"u8"},{"name":"baseStorageAuthorityBumpSeed","type":{"option":"u8"}},{"name":"reserveBalanceFromBonding","type":"u64"},{"name":"supplyFromBonding","type":"u64"},{"name":"ignoreExternalReserveChanges","type":"bool"},{"name":"ignoreExternalSupplyChanges","type":"bool"},{"name":"walletPurchaseCap","type":{"option":"u64"}},{"name":"walletPurchaseWindowSecs","type":{"option":"i64"}},{"name":"allowlistRoot","type":{"option":{"array":["u8",32]}}},{"name":"allowlistEndUnixTime","type":{"option":"i64"}},{"name":"buyGate","type":{"option":{"defined":"BuyGateV0"}}},{"name":"royaltyVesting","type":{"option":{"defined":"RoyaltyVestingV0"}}},{"name":"priceImpactFees","type":{"option":{"defined":"PriceImpactFeesV0"}}},{"name":"curveUpdateDelaySecs","type":{"option":"i64"}},{"name":"pendingCurveUpdate","type":{"option":{"defined":"PendingCurveUpdateV0"}}},{"name":"royaltiesLocked","type":"bool"},{"name":"capsLocked","type":"bool"},{"name":"curveLocked","type":"bool"},{"name":"royaltyChangeLimits","type":{"option":{"defined":"RoyaltyChangeLimitsV0"}}},{"name":"royaltyChangePeriod","type":{"option":{"defined":"RoyaltyChangePeriodV0"}}},{"name":"windDown","type":"bool"},{"name":"priceAccumulator","type":{"option":{"defined":"PriceAccumulatorV0"}}},{"name":"reserveStake","type":{"option":{"defined":"ReserveStakeV0"}}}]}},{"name":"royaltySplitV0","type":{"kind":"struct","fields":[{"name":"mint","type":"publicKey"},{"name":"escrow","type":"publicKey"},{"name":"vault","type":"publicKey"},{"name":"recipients","type":{"vec":{"defined":"RoyaltyRecipientV0"}}},{"name":"totalClaimedAmount","type":"u64"},{"name":"totalNativeClaimedAmount","type":"u64"},{"name":"escrowBumpSeed","type":"u8"},{"name":"vaultBumpSeed","type":"u8"}]}},{"name":"priceOracleV0","type":{"kind":"struct","fields":[{"name":"tokenBonding","type":"publicKey"},{"name":"observationIntervalSecs","type":"i64"},{"name":"nextIndex","type":"u16"},{"name":"observations","type":{"vec":{"defined":"PriceObservationV0"}}},{"name":"bumpSeed","type":"u8"}]}},{"name":"walletPurchaseV0","type":{"kind":"struct","fields":[{"name":"tokenBonding","type":"publicKey"},{"name":"wallet","type":"publicKey"},{"name":"purchasedAmount","type":"u64"},{"name":"windowStartUnixTime","type":"i64"},{"name":"bumpSeed","type":"u8"}]}},{"name":"reserveStakingV0","type":{"kind":"struct","fields":[{"name":"authority","type":"publicKey"},{"name":"stakePoolProgram","type":"publicKey"},{"name":"stakePool","type":"publicKey"},{"name":"poolTokens","type":"publicKey"},{"name":"maxStakedPercentage","type":"u32"},{"name":"bumpSeed","type":"u8"},{"name":"poolTokensBumpSeed","type":"u8"}]}}],"types":[{"name":"BuyWithBaseV0Args","type":{"kind":"struct","fields":[{"name":"baseAmount","type":"u64"},{"name":"minimumTargetAmount","type":"u64"}]}},{"name":"BuyTargetAmountV0Args","type":{"kind":"struct","fields":[{"name":"targetAmount","type":"u64"},{"name":"maximumPrice","type":"u64"}]}},{"name":"BuyV0Args","type":{"kind":"struct","fields":[{"name":"buyWithBase","type":{"option":{"defined":"BuyWithBaseV0Args"}}},{"name":"buyTargetAmount","type":{"option":{"defined":"BuyTargetAmountV0Args"}}}]}},{"name":"BuyV1Args","type":{"kind":"struct","fields":[{"name":"buyWithBase","type":{"option":{"defined":"BuyWithBaseV0Args"}}},{"name":"buyTargetAmount","type":{"option":{"defined":"BuyTargetAmountV0Args"}}},{"name":"allowlistProof","type":{"option":{"vec":{"array":["u8",32]}}}}]}},{"name":"BuyWrappedSolV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"ClaimRoyaltySplitV0Args","type":{"kind":"struct","fields":[{"name":"native","type":"bool"}]}},{"name":"CreateCurveV0Args","type":{"kind":"struct","fields":[{"name":"definition","type":{"defined":"PiecewiseCurve"}}]}},{"name":"GetTwapV0Args","type":{"kind":"struct","fields":[{"name":"windowSecs","type":"i64"}]}},{"name":"InitializePriceOracleV0Args","type":{"kind":"struct","fields":[{"name":"observationIntervalSecs","type":"i64"}]}},{"name":"RoyaltyRecipientArgsV0","type":{"kind":"struct","fields":[{"name":"wallet","type":"publicKey"},{"name":"shareBps","type":"u16"}]}},{"name":"InitializeRoyaltySplitV0Args","type":{"kind":"struct","fields":[{"name":"recipients","type":{"vec":{"defined":"RoyaltyRecipientArgsV0"}}}]}},{"name":"InitializeRoyaltyVestingV0Args","type":{"kind":"struct","fields":[{"name":"startUnixTime","type":{"option":"i64"}},{"name":"cliffSecs","type":"i64"},{"name":"durationSecs","type":"i64"}]}},{"name":"InitializeSolStorageV0Args","type":{"kind":"struct","fields":[{"name":"mintAuthorityBumpSeed","type":"u8"},{"name":"solStorageBumpSeed","type":"u8"},{"name":"bumpSeed","type":"u8"}]}},{"name":"InitializeTokenBondingV0Args","type":{"kind":"struct","fields":[{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeBuyUnixTime","type":{"option":"i64"}},{"name":"mintCap","type":{"option":"u64"}},{"name":"purchaseCap","type":{"option":"u64"}},{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"reserveAuthority","type":{"option":"publicKey"}},{"name":"curveAuthority","type":{"option":"publicKey"}},{"name":"buyFrozen","type":"bool"},{"name":"index","type":"u16"},{"name":"bumpSeed","type":"u8"},{"name":"sellFrozen","type":"bool"},{"name":"ignoreExternalReserveChanges","type":"bool"},{"name":"ignoreExternalSupplyChanges","type":"bool"},{"name":"initialReservesPad","type":"u64"},{"name":"initialSupplyPad","type":"u64"}]}},{"name":"InitializeTokenBondingV1Args","type":{"kind":"struct","fields":[{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeBuyUnixTime","type":{"option":"i64"}},{"name":"mintCap","type":{"option":"u64"}},{"name":"purchaseCap","type":{"option":"u64"}},{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"reserveAuthority","type":{"option":"publicKey"}},{"name":"curveAuthority","type":{"option":"publicKey"}},{"name":"buyFrozen","type":"bool"},{"name":"index","type":"u16"},{"name":"bumpSeed","type":"u8"},{"name":"sellFrozen","type":"bool"},{"name":"ignoreExternalReserveChanges","type":"bool"},{"name":"ignoreExternalSupplyChanges","type":"bool"},{"name":"initialReservesPad","type":"u64"},{"name":"initialSupplyPad","type":"u64"},{"name":"walletPurchaseCap","type":{"option":"u64"}},{"name":"walletPurchaseWindowSecs","type":{"option":"i64"}},{"name":"allowlistRoot","type":{"option":{"array":["u8",32]}}},{"name":"allowlistEndUnixTime","type":{"option":"i64"}},{"name":"buyGate","type":{"option":{"defined":"BuyGateV0"}}},{"name":"priceImpactFees","type":{"option":{"defined":"PriceImpactFeesV0"}}},{"name":"curveUpdateDelaySecs","type":{"option":"i64"}},{"name":"royaltyChangeLimits","type":{"option":{"defined":"RoyaltyChangeLimitsV0"}}}]}},{"name":"LockTokenBondingV0Args","type":{"kind":"struct","fields":[{"name":"lockRoyalties","type":"bool"},{"name":"lockCaps","type":"bool"}]}},{"name":"ProposeCurveUpdateV0Args","type":{"kind":"struct","fields":[{"name":"effectiveUnixTime","type":{"option":"i64"}}]}},{"name":"InitializeReserveStakingV0Args","type":{"kind":"struct","fields":[{"name":"authority","type":"publicKey"},{"name":"maxStakedPercentage","type":"u32"}]}},{"name":"UpdateReserveStakingV0Args","type":{"kind":"struct","fields":[{"name":"newAuthority","type":"publicKey"},{"name":"maxStakedPercentage","type":"u32"}]}},{"name":"RouteHopV0","type":{"kind":"struct","fields":[{"name":"extraAccounts","type":"u8"},{"name":"allowlistProof","type":{"option":{"vec":{"array":["u8",32]}}}}]}},{"name":"RouteSwapV0Args","type":{"kind":"struct","fields":[{"name":"inputMint","type":"publicKey"},{"name":"amount","type":"u64"},{"name":"minimumOutputAmount","type":"u64"},{"name":"hops","type":{"vec":{"defined":"RouteHopV0"}}}]}},{"name":"SellV0Args","type":{"kind":"struct","fields":[{"name":"targetAmount","type":"u64"},{"name":"minimumPrice","type":"u64"}]}},{"name":"SellForBaseV0Args","type":{"kind":"struct","fields":[{"name":"baseAmount","type":"u64"},{"name":"maximumTargetAmount","type":"u64"}]}},{"name":"SellV2Args","type":{"kind":"struct","fields":[{"name":"targetAmount","type":"u64"},{"name":"minimumPrice","type":"u64"},{"name":"sellForBase","type":{"option":{"defined":"SellForBaseV0Args"}}}]}},{"name":"SellWrappedSolV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"all","type":"bool"}]}},{"name":"SwapV0Args","type":{"kind":"struct","fields":[{"name":"targetAmount","type":"u64"},{"name":"minimumTargetAmount","type":"u64"},{"name":"allowlistProof","type":{"option":{"vec":{"array":["u8",32]}}}}]}},{"name":"TransferReservesV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"UpdateCurveV0Args","type":{"kind":"struct","fields":[{"name":"curveAuthority","type":{"option":"publicKey"}}]}},{"name":"UpdateCurveV1Args","type":{"kind":"struct","fields":[{"name":"curveAuthority","type":{"option":"publicKey"}},{"name":"curveUpdateDelaySecs","type":{"option":"i64"}}]}},{"name":"UpdateReserveAuthorityV0Args","type":{"kind":"struct","fields":[{"name":"newReserveAuthority","type":{"option":"publicKey"}}]}},{"name":"UpdateTokenBondingV0Args","type":{"kind":"struct","fields":[{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"buyFrozen","type":"bool"}]}},{"name":"UpdateTokenBondingV1Args","type":{"kind":"struct","fields":[{"name":"generalAuthority","type":{"option":"publicKey"}},{"name":"buyBaseRoyaltyPercentage","type":"u32"},{"name":"buyTargetRoyaltyPercentage","type":"u32"},{"name":"sellBaseRoyaltyPercentage","type":"u32"},{"name":"sellTargetRoyaltyPercentage","type":"u32"},{"name":"buyFrozen","type":"bool"},{"name":"allowlist","type":{"option":{"defined":"AllowlistUpdateV0"}}},{"name":"buyGate","type":{"option":{"defined":"BuyGateUpdateV0"}}},{"name":"priceImpactFees","type":{"option":{"defined":"PriceImpactFeesUpdateV0"}}},{"name":"royaltyChangeLimits","type":{"option":{"defined":"RoyaltyChangeLimitsV0"}}},{"name":"caps","type":{"option":{"defined":"CapsUpdateV0"}}}]}},{"name":"AllowlistUpdateV0","type":{"kind":"struct","fields":[{"name":"root","type":{"option":{"array":["u8",32]}}},{"name":"endUnixTime","type":{"option":"i64"}}]}},{"name":"BuyGateUpdateV0","type":{"kind":"struct","fields":[{"name":"buyGate","type":{"option":{"defined":"BuyGateV0"}}}]}},{"name":"PriceImpactFeesUpdateV0","type":{"kind":"struct","fields":[{"name":"priceImpactFees","type":{"option":{"defined":"PriceImpactFeesV0"}}}]}},{"name":"CapsUpdateV0","type":{"kind":"struct","fields":[{"name":"mintCap","type":{"option":"u64"}},{"name":"purchaseCap","type":{"option":"u64"}},{"name":"walletPurchaseCap","type":{"option":"u64"}},{"name":"walletPurchaseWindowSecs","type":{"option":"i64"}}]}},{"name":"TwapV0","type":{"kind":"struct","fields":[{"name":"price","type":"u64"},{"name":"startUnixTime","type":"i64"},{"name":"endUnixTime","type":"i64"}]}},{"name":"QuoteV0","type":{"kind":"struct","fields":[{"name":"price","type":"u64"},{"name":"totalAmount","type":"u64"},{"name":"baseRoyalties","type":"u64"},{"name":"targetRoyalties","type":"u64"}]}},{"name":"SpotPriceV0","type":{"kind":"struct","fields":[{"name":"buyPrice","type":"u64"},{"name":"sellPrice","type":"u64"}]}},{"name":"SolvencyV0","type":{"kind":"struct","fields":[{"name":"reserves","type":"u64"},{"name":"requiredReserves","type":"u64"},{"name":"solvencyRatioBps","type":"u64"}]}},{"name":"TimeCurveV0","type":{"kind":"struct","fields":[{"name":"offset","type":"i64"},{"name":"curve","type":{"defined":"PrimitiveCurve"}},{"name":"buyTransitionFees","type":{"option":{"defined":"TransitionFeeV0"}}},{"name":"sellTransitionFees","type":{"option":{"defined":"TransitionFeeV0"}}}]}},{"name":"SupplyCurveV0","type":{"kind":"struct","fields":[{"name":"offset","type":"u128"},{"name":"curve","type":{"defined":"PrimitiveCurve"}}]}},{"name":"PricePointV0","type":{"kind":"struct","fields":[{"name":"supply","type":"u128"},{"name":"price","type":"u128"}]}},{"name":"TransitionFeeV0","type":{"kind":"struct","fields":[{"name":"percentage","type":"u32"},{"name":"interval","type":"u32"}]}},{"name":"BuyGateV0","type":{"kind":"struct","fields":[{"name":"gateType","type":{"defined":"BuyGateType"}},{"name":"key","type":"publicKey"},{"name":"minimumAmount","type":"u64"}]}},{"name":"RoyaltyVestingV0","type":{"kind":"struct","fields":[{"name":"escrow","type":"publicKey"},{"name":"destination","type":"publicKey"},{"name":"startUnixTime","type":"i64"},{"name":"cliffSecs","type":"i64"},{"name":"durationSecs","type":"i64"},{"name":"totalVestingAmount","type":"u64"},{"name":"unvestedAmount","type":"u64"},{"name":"releaseUnixTime","type":"i64"},{"name":"endUnixTime","type":"i64"},{"name":"claimedAmount","type":"u64"},{"name":"escrowBumpSeed","type":"u8"}]}},{"name":"PriceImpactFeesV0","type":{"kind":"struct","fields":[{"name":"minImpactPercentage","type":"u32"},{"name":"maxImpactPercentage","type":"u32"},{"name":"maxRoyaltyPercentage","type":"u32"}]}},{"name":"RoyaltyChangeLimitsV0","type":{"kind":"struct","fields":[{"name":"maxChangePercentage","type":"u32"},{"name":"periodSecs","type":"i64"},{"name":"maxRoyaltyPercentage","type":"u32"}]}},{"name":"RoyaltyChangePeriodV0","type":{"kind":"struct","fields":[{"name":"startUnixTime","type":"i64"},{"name":"startPercentages","type":{"array":["u32",6]}}]}},{"name":"PendingCurveUpdateV0","type":{"kind":"struct","fields":[{"name":"curve","type":"publicKey"},{"name":"effectiveUnixTime","type":"i64"}]}},{"name":"PriceAccumulatorV0","type":{"kind":"struct","fields":[{"name":"lastPrice","type":"u64"},{"name":"lastUnixTime","type":"i64"},{"name":"cumulativePrice","type":"u128"}]}},{"name":"ReserveStakeV0","type":{"kind":"struct","fields":[{"name":"poolTokens","type":"u64"},{"name":"stakedLamports","type":"u64"},{"name":"totalYieldLamports","type":"u64"},{"name":"totalLossLamports","type":"u64"}]}},{"name":"RoyaltyRecipientV0","type":{"kind":"struct","fields":[{"name":"wallet","type":"publicKey"},{"name":"shareBps","type":"u16"},{"name":"claimedAmount","type":"u64"},{"name":"nativeClaimedAmount","type":"u64"}]}},{"name":"PriceObservationV0","type":{"kind":"struct","fields":[{"name":"unixTime","type":"i64"},{"name":"cumulativePrice","type":"u128"}]}},{"name":"PrimitiveCurve","type":{"kind":"enum","variants":[{"name":"ExponentialCurveV0","fields":[{"name":"c","type":"u128"},{"name":"b","type":"u128"},{"name":"pow","type":"u8"},{"name":"frac","type":"u8"}]},{"name":"TimeDecayExponentialCurveV0","fields":[{"name":"c","type":"u128"},{"name":"k1","type":"u128"},{"name":"k0","type":"u128"},{"name":"interval","type":"u32"},{"name":"d","type":"u128"}]},{"name":"LogarithmicCurveV0","fields":[{"name":"c","type":"u128"},{"name":"g","type":"u128"},{"name":"b","type":"u128"}]},{"name":"SigmoidCurveV0","fields":[{"name":"max_price","type":"u128"},{"name":"k","type":"u128"},{"name":"midpoint","type":"u128"}]},{"name":"PriceTableCurveV0","fields":[{"name":"points","type":{"vec":{"defined":"PricePointV0"}}}]}]}},{"name":"PiecewiseCurve","type":{"kind":"enum","variants":[{"name":"TimeV0","fields":[{"name":"curves","type":{"vec":{"defined":"TimeCurveV0"}}}]},{"name":"SupplyV0","fields":[{"name":"curves","type":{"vec":{"defined":"SupplyCurveV0"}}}]}]}},{"name":"BuyGateType","type":{"kind":"enum","variants":[{"name":"Token"},{"name":"Collection"}]}}],"events":[{"name":"BuyEventV0","fields":[{"name":"tokenBonding","type":"publicKey","index":false},{"name":"buyer","type":"publicKey","index":false},{"name":"targetAmount","type":"u64","index":false},{"name":"price","type":"u64","index":false},{"name":"baseRoyalties","type":"u64","index":false},{"name":"targetRoyalties","type":"u64","index":false},{"name":"reserves","type":"u64","index":false},{"name":"supply","type":"u64","index":false},{"name":"unixTimestamp","type":"i64","index":false}]},{"name":"SellEventV0","fields":[{"name":"tokenBonding","type":"publicKey","index":false},{"name":"seller","type":"publicKey","index":false},{"name":"targetAmount","type":"u64","index":false},{"name":"reclaimed","type":"u64","index":false},{"name":"baseRoyalties","type":"u64","index":false},{"name":"targetRoyalties","type":"u64","index":false},{"name":"reserves","type":"u64","index":false},{"name":"supply","type":"u64","index":false},{"name":"unixTimestamp","type":"i64","index":false}]},{"name":"TransferReservesEventV0","fields":[{"name":"tokenBonding","type":"publicKey","index":false},{"name":"reserveAuthority","type":"publicKey","index":false},{"name":"destination","type":"publicKey","index":false},{"name":"amount","type":"u64","index":false},{"name":"reserves","type":"u64","index":false},{"name":"unixTimestamp","type":"i64","index":false}]},{"name":"CurveUpdateEventV0","fields":[{"name":"tokenBonding","type":"publicKey","index":false},{"name":"previousCurve","type":"publicKey","index":false},{"name":"curve","type":"publicKey","index":false},{"name":"curveAuthority","type":{"option":"publicKey"},"index":false},{"name":"unixTimestamp","type":"i64","index":false}]},{"name":"TokenBondingUpdateEventV0","fields":[{"name":"tokenBonding","type":"publicKey","index":false},{"name":"generalAuthority","type":{"option":"publicKey"},"index":false},{"name":"buyBaseRoyaltyPercentage","type":"u32","index":false},{"name":"buyTargetRoyaltyPercentage","type":"u32","index":false},{"name":"sellBaseRoyaltyPercentage","type":"u32","index":false},{"name":"sellTargetRoyaltyPercentage","type":"u32","index":false},{"name":"buyBaseRoyalties","type":"publicKey","index":false},{"name":"buyTargetRoyalties","type":"publicKey","index":false},{"name":"sellBaseRoyalties","type":"publicKey","index":false},{"name":"sellTargetRoyalties","type":"publicKey","index":false},{"name":"buyFrozen","type":"bool","index":false},{"name":"unixTimestamp","type":"i64","index":false}]}],"errors":[{"code":6000,"name":"NoMintAuthority","msg":"Target mint must have an authority"},{"code":6001,"name":"InvalidMintAuthority","msg":"Target mint must have an authority that is a pda of this program"},{"code":6002,"name":"InvalidBaseStorageAuthority","msg":"Invalid base storage authority pda or seed did not match canonical seed for base storage authority"},{"code":6003,"name":"NoAuthority","msg":"Token bonding does not have an authority"},{"code":6004,"name":"ArithmeticError","msg":"Error in precise number arithmetic"},{"code":6005,"name":"PriceTooHigh","msg":"Buy price was higher than the maximum buy price. Try increasing max_price or slippage configuration"},{"code":6006,"name":"PriceTooLow","msg":"Sell price was lower than the minimum sell price. Try decreasing min_price or increasing slippage configuration"},{"code":6007,"name":"MintSupplyTooLow","msg":"Cannot sell more than the target mint currently has in supply"},{"code":6008,"name":"SellDisabled","msg":"Sell is not enabled on this bonding curve"},{"code":6009,"name":"NotLiveYet","msg":"This bonding curve is not live yet"},{"code":6010,"name":"PassedMintCap","msg":"Passed the mint cap"},{"code":6011,"name":"OverPurchaseCap","msg":"Cannot purchase that many tokens because of purchase cap"},{"code":6012,"name":"BuyFrozen","msg":"Buy is frozen on this bonding curve, purchases not allowed"},{"code":6013,"name":"WrappedSolNotAllowed","msg":"Use token bonding wrapped sol via buy_wrapped_sol, sell_wrapped_sol commands. We may one day provide liquid staking rewards on this stored sol."},{"code":6014,"name":"InvalidCurve","msg":"The provided curve is invalid"},{"code":6015,"name":"InvalidMint","msg":"An account was provided that did not have the correct mint"},{"code":6016,"name":"IgnoreExternalV1Only","msg":"Ignoring external changes is only supported on v1 of buy and sell endpoints. Please upgrade your client"},{"code":6017,"name":"InvalidPad","msg":"Cannot pad token bonding without ignoring external reserve and supply changes. This is an advanced feature, incorrect use could lead to insufficient resreves to cover sells"},{"code":6018,"name":"CurveAccountTooSmall","msg":"Curve account is too small to hold the curve definition"},{"code":6019,"name":"SellAmountTooHigh","msg":"Selling for that base amount would sell more than the maximum target amount. Try increasing maximum_target_amount or slippage configuration"},{"code":6020,"name":"InvalidRoute","msg":"Route accounts do not form a valid path through the provided token bondings"},{"code":6021,"name":"RouteOutputTooLow","msg":"Route output was lower than the minimum output amount. Try decreasing minimum_output_amount or increasing slippage configuration"},{"code":6022,"name":"WalletPurchaseRequired","msg":"This token bonding has a per wallet purchase cap. Pass the buyer's wallet purchase account as the first remaining account"},{"code":6023,"name":"OverWalletPurchaseCap","msg":"Cannot purchase that many tokens because of the per wallet purchase cap"},{"code":6024,"name":"NotAllowlisted","msg":"Buying is limited to an allowlist right now. Provide a valid allowlist proof for the buyer's wallet"},{"code":6025,"name":"BuyGateNotMet","msg":"Buyer does not hold enough of the token or NFT collection gating this bonding curve. Pass the holding accounts as remaining accounts"},{"code":6026,"name":"InvalidMetadata","msg":"Metadata account is not the Metaplex metadata for the provided mint"},{"code":6027,"name":"RoyaltyVestingAlreadySet","msg":"Royalty vesting has already been set up for this token bonding"},{"code":6028,"name":"InvalidVestingSchedule","msg":"Vesting cliff must be between zero and the vesting duration"},{"code":6029,"name":"NoRoyaltyVesting","msg":"This token bonding does not vest royalties"},{"code":6030,"name":"InvalidPriceImpactFees","msg":"Price impact fees must have min impact below max impact"},{"code":6031,"name":"InvalidRoyaltySplit","msg":"Royalty splits need 1 to 16 distinct recipients with positive shares adding up to 10000 basis points"},{"code":6032,"name":"UnknownRoyaltyRecipient","msg":"Account is not a recipient of this royalty split"},{"code":6033,"name":"InvalidCurveUpdateDelay","msg":"Curve update delay cannot be negative, and once set can only be raised"},{"code":6034,"name":"CurveUpdateTimelocked","msg":"This curve update is timelocked. Propose it with propose_curve_update_v0 and apply it once the delay has passed"},{"code":6035,"name":"NoPendingCurveUpdate","msg":"There is no pending curve update for this curve"},{"code":6036,"name":"RoyaltiesLocked","msg":"Royalties on this token bonding are locked and can never change"},{"code":6037,"name":"CurveLocked","msg":"The curve on this token bonding is locked and can never change"},{"code":6038,"name":"InvalidRoyaltyChangeLimits","msg":"Royalty change limits need a positive period, and once set can only be tightened"},{"code":6039,"name":"RoyaltyChangeTooLarge","msg":"Royalties cannot rise that much this period or above the maximum royalty percentage"},{"code":6040,"name":"WindingDown","msg":"This token bonding is winding down. Reserves are reserved for holders redeeming their tokens"},{"code":6041,"name":"PriceOracleWindowTooLong","msg":"Price oracle has no observation old enough for this window"},{"code":6042,"name":"InvalidPriceOracle","msg":"Price oracle does not belong to this token bonding"},{"code":6043,"name":"InvalidStakePool","msg":"Stake pool is not owned by the stake pool program, or does not match reserve staking"},{"code":6044,"name":"StakePoolNotUpdated","msg":"Stake pool has not been updated this epoch"},{"code":6045,"name":"NoReserveYield","msg":"Staked reserves have not earned any yield to claim"},{"code":6046,"name":"ReservesInsolvent","msg":"Base storage would not cover buying back the outstanding supply on the curve"},{"code":6047,"name":"InvalidMaxStakedPercentage","msg":"Reserve staking can stake at most MAX_STAKED_PERCENTAGE of wrapped sol"},{"code":6048,"name":"ReservesNotLiquid","msg":"Not enough unstaked reserves. Pass the reserve staking accounts so the sell can unstake, or run rebalance_reserve_staking_v0"},{"code":6049,"name":"TransferReservesV1Only","msg":"Reserve transfers on bondings that ignore external changes or are winding down are only supported on v1 of the transfer reserves endpoints. Please upgrade your client"},{"code":6050,"name":"CloseDestinationRequired","msg":"Closing a wound down bonding with reserves left needs a base mint token account of the general authority as the first remaining account"},{"code":6051,"name":"ReserveStakeNotInitialized","msg":"Token bonding is not staking its reserves"},{"code":6052,"name":"ReservesStaked","msg":"Token bonding still has reserves in the stake pool. Wind it down and run rebalance_reserve_staking_v0 to unstake them"},{"code":6053,"name":"CapsLocked","msg":"Caps on this token bonding are locked and can never change"}],"metadata":{"address":"TBondmkCYxaPCKG4CHYfVTcwQ8on31xnJrPzk8F8WsS"}};
 
 export type PrimitiveCurve = Record<string, Record<string, any>>
 export const PrimitiveCurve = {
   ExponentialCurveV0: { exponentialcurvev0: {} },
-  TimeDecayExponentialCurveV0: { timedecayexponentialcurvev0: {} }
+  TimeDecayExponentialCurveV0: { timedecayexponentialcurvev0: {} },
+  LogarithmicCurveV0: { logarithmiccurvev0: {} },
+  SigmoidCurveV0: { sigmoidcurvev0: {} },
+  PriceTableCurveV0: { pricetablecurvev0: {} }
 }
     
 
 export type PiecewiseCurve = Record<string, Record<string, any>>
 export const PiecewiseCurve = {
-  TimeV0: { timev0: {} }
+  TimeV0: { timev0: {} },
+  SupplyV0: { supplyv0: {} }
+}
+    
+
+export type BuyGateType = Record<string, Record<string, any>>
+export const BuyGateType = {
+  Token: { token: {} },
+  Collection: { collection: {} }
 }
     
 
@@ -1646,5 +5370,13 @@ export type ProgramStateV0 = IdlAccounts<SplTokenBondingIDL>["programStateV0"]
 export type CurveV0 = IdlAccounts<SplTokenBondingIDL>["curveV0"]
 
 export type TokenBondingV0 = IdlAccounts<SplTokenBondingIDL>["tokenBondingV0"]
+
+export type RoyaltySplitV0 = IdlAccounts<SplTokenBondingIDL>["royaltySplitV0"]
+
+export type PriceOracleV0 = IdlAccounts<SplTokenBondingIDL>["priceOracleV0"]
+
+export type WalletPurchaseV0 = IdlAccounts<SplTokenBondingIDL>["walletPurchaseV0"]
+
+export type ReserveStakingV0 = IdlAccounts<SplTokenBondingIDL>["reserveStakingV0"]
   
           
\ No newline at end of file
@@ -14,13 +14,16 @@ import {
   u64,
 } from "@solana/spl-token";
 import {
+  AccountMeta,
   Commitment,
   Keypair,
   PublicKey,
   Signer,
+  StakeProgram,
   SystemProgram,
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_RENT_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
   TransactionInstruction,
   ComputeBudgetProgram,
 } from "@solana/web3.js";
@@ -56,6 +59,9 @@ async function sleep(ms: number): Promise<void> {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
 
+/** Space allocated for token bondings. Bondings created before the newer settings have 512 bytes */
+export const TOKEN_BONDING_SIZE = 1024;
+
 function anyDefined(...args: any | undefined[]): boolean {
   return args.some((a: any | undefined) => typeof a !== "undefined");
 }
@@ -377,6 +383,10 @@ export interface ICreateTokenBondingArgs {
   mintCap?: BN;
   /** Maximum `targetMint` tokens that can be purchased in a single call to {@link SplTokenBonding.buy}. Useful for limiting volume. **Default:** 0 */
   purchaseCap?: BN;
+  /** Maximum `targetMint` tokens a single wallet can purchase across all calls to {@link SplTokenBonding.buy}. **Default:** infinite */
+  walletPurchaseCap?: BN;
+  /** Seconds after which each wallet's purchased amount resets. **Default:** never */
+  walletPurchaseWindowSecs?: BN;
   /** The date this bonding curve will go live. Before this date, {@link SplTokenBonding.buy} and {@link SplTokenBonding.sell} are disabled. **Default:** 1 second ago */
   goLiveDate?: Date;
   /** The date this bonding curve will shut down. After this date, {@link SplTokenBonding.buy} and {@link SplTokenBonding.sell} are disabled. **Default:** null */
@@ -424,6 +434,12 @@ export interface ICreateTokenBondingArgs {
   };
 }
 
+export interface IResizeTokenBondingArgs {
+  tokenBonding: PublicKey;
+  /** The payer for the extra rent, defaults to provider.wallet */
+  payer?: PublicKey;
+}
+
 export interface IUpdateTokenBondingCurveArgs {
   tokenBonding: PublicKey;
   curve: PublicKey;
@@ -452,6 +468,20 @@ export interface IUpdateTokenBondingArgs {
   reserveAuthority?: PublicKey | null;
   /** Should this bonding curve be frozen, disabling buy and sell? It can be unfrozen using {@link SplTokenBonding.updateTokenBonding}. **Default:** current */
   buyFrozen?: boolean;
+  /** Merkle root of the wallets allowed to buy during the allowlist phase, or null to remove the allowlist. **Default:** current */
+  allowlistRoot?: number[] | null;
+  /** When the allowlist phase ends, or null for no end. **Default:** current */
+  allowlistEndDate?: Date | null;
+  /** Only allow holders of a token or verified NFT collection to buy, or null to remove the gate. **Default:** current */
+  buyGate?: IdlTypes<SplTokenBondingIDL>["BuyGateV0"] | null;
+  /** Maximum `targetMint` tokens this bonding curve will mint, or null for no cap. Fails once caps are locked. **Default:** current */
+  mintCap?: BN | null;
+  /** Maximum `targetMint` tokens per purchase, or null for no cap. Fails once caps are locked. **Default:** current */
+  purchaseCap?: BN | null;
+  /** Maximum `targetMint` tokens a single wallet can purchase, or null for no cap. Fails once caps are locked. **Default:** current */
+  walletPurchaseCap?: BN | null;
+  /** Seconds after which each wallet's purchased amount resets, or null for never. Fails once caps are locked. **Default:** current */
+  walletPurchaseWindowSecs?: BN | null;
 }
 
 export interface IBuyArgs {
@@ -474,6 +504,8 @@ export interface IBuyArgs {
   expectedBaseAmount?: BN | number;
   /** Decimal number. max price will be (1 + slippage) * price_for_desired_target_amount */
   slippage: number;
+  /** Merkle proof that `sourceAuthority` is on the allowlist, required while the token bonding's allowlist phase is active */
+  allowlistProof?: number[][];
 }
 
 /** DEPRECATED. Will be removed in a future version */
@@ -554,6 +586,8 @@ export interface ISellArgs {
     | BN
     | number /** Expected output amount of `baseMint` before slippage */;
   slippage: number /* Decimal number. max price will be (1 + slippage) * price_for_desired_target_amount */;
+  /** Receive exactly this amount of `baseMint`, selling as many `targetMint` tokens as that takes. At most `targetAmount` * (1 + slippage) are sold. **Default:** null */
+  baseAmount?: BN | number;
 }
 
 export interface ICloseArgs {
@@ -940,6 +974,8 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     sellTargetRoyaltyPercentage = 0,
     mintCap,
     purchaseCap,
+    walletPurchaseCap,
+    walletPurchaseWindowSecs,
     goLiveDate,
     freezeBuyDate,
     targetMintDecimals,
@@ -1191,7 +1227,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     };
 
     instructions.push(
-      await this.instruction.initializeTokenBondingV0(
+      await this.instruction.initializeTokenBondingV1(
         {
           index: indexToUse,
           goLiveUnixTime: new BN(Math.floor(goLiveDate.valueOf() / 1000)),
@@ -1205,6 +1241,14 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
             percent(sellTargetRoyaltyPercentage) || 0,
           mintCap: mintCap || null,
           purchaseCap: purchaseCap || null,
+          walletPurchaseCap: walletPurchaseCap || null,
+          walletPurchaseWindowSecs: walletPurchaseWindowSecs || null,
+          allowlistRoot: null,
+          allowlistEndUnixTime: null,
+          buyGate: null,
+          priceImpactFees: null,
+          curveUpdateDelaySecs: null,
+          royaltyChangeLimits: null,
           generalAuthority,
           curveAuthority,
           reserveAuthority,
@@ -1364,6 +1408,13 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     generalAuthority,
     reserveAuthority,
     buyFrozen,
+    allowlistRoot,
+    allowlistEndDate,
+    buyGate,
+    mintCap,
+    purchaseCap,
+    walletPurchaseCap,
+    walletPurchaseWindowSecs,
   }: IUpdateTokenBondingArgs): Promise<InstructionResult<null>> {
     const tokenBondingAcct = (await this.getTokenBonding(tokenBonding))!;
 
@@ -1377,7 +1428,14 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
       sellBaseRoyalties,
       sellTargetRoyalties,
       generalAuthority,
-      buyFrozen
+      buyFrozen,
+      allowlistRoot,
+      allowlistEndDate,
+      buyGate,
+      mintCap,
+      purchaseCap,
+      walletPurchaseCap,
+      walletPurchaseWindowSecs
     );
 
     const reserveAuthorityChanges = anyDefined(reserveAuthority);
@@ -1391,7 +1449,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
         );
       }
 
-      const args: IdlTypes<SplTokenBondingIDL>["UpdateTokenBondingV0Args"] = {
+      const args: IdlTypes<SplTokenBondingIDL>["UpdateTokenBondingV1Args"] = {
         buyBaseRoyaltyPercentage: definedOr(
           percent(buyBaseRoyaltyPercentage),
           tokenBondingAcct.buyBaseRoyaltyPercentage
@@ -1417,9 +1475,59 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
           typeof buyFrozen === "undefined"
             ? (tokenBondingAcct.buyFrozen as boolean)
             : buyFrozen,
+        allowlist:
+          typeof allowlistRoot === "undefined" &&
+          typeof allowlistEndDate === "undefined"
+            ? null
+            : {
+                root:
+                  typeof allowlistRoot === "undefined"
+                    ? (tokenBondingAcct.allowlistRoot as number[] | null)
+                    : allowlistRoot,
+                endUnixTime:
+                  typeof allowlistEndDate === "undefined"
+                    ? (tokenBondingAcct.allowlistEndUnixTime as BN | null)
+                    : allowlistEndDate &&
+                      new BN(Math.floor(allowlistEndDate.valueOf() / 1000)),
+              },
+        buyGate: typeof buyGate === "undefined" ? null : { buyGate },
+        priceImpactFees: null,
+        royaltyChangeLimits: null,
+        caps: anyDefined(
+          mintCap,
+          purchaseCap,
+          walletPurchaseCap,
+          walletPurchaseWindowSecs
+        )
+          ? {
+              mintCap: definedOr(mintCap, tokenBondingAcct.mintCap as BN | null),
+              purchaseCap: definedOr(
+                purchaseCap,
+                tokenBondingAcct.purchaseCap as BN | null
+              ),
+              walletPurchaseCap: definedOr(
+                walletPurchaseCap,
+                tokenBondingAcct.walletPurchaseCap as BN | null
+              ),
+              walletPurchaseWindowSecs: definedOr(
+                walletPurchaseWindowSecs,
+                tokenBondingAcct.walletPurchaseWindowSecs as BN | null
+              ),
+            }
+          : null,
       };
+      // Old bondings need room before the newer settings can be stored
+      if (args.allowlist || args.buyGate || args.caps) {
+        instructions.push(
+          ...(
+            await this.resizeTokenBondingInstructions({
+              tokenBonding,
+            })
+          ).instructions
+        );
+      }
       instructions.push(
-        await this.instruction.updateTokenBondingV0(args, {
+        await this.instruction.updateTokenBondingV1(args, {
           accounts: {
             tokenBonding,
             generalAuthority: (tokenBondingAcct.generalAuthority as PublicKey)!,
@@ -1483,6 +1591,57 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     );
   }
 
+  /**
+   * Grow a token bonding created at the old 512 bytes to {@link TOKEN_BONDING_SIZE}, so it can hold the newer settings.
+   * Returns no instructions if the bonding is already large enough
+   *
+   * @param param0
+   * @returns
+   */
+  async resizeTokenBondingInstructions({
+    tokenBonding,
+    payer = this.wallet.publicKey,
+  }: IResizeTokenBondingArgs): Promise<InstructionResult<null>> {
+    const acct = await this.provider.connection.getAccountInfo(tokenBonding);
+    if (!acct) {
+      throw new Error("Token bonding does not exist");
+    }
+
+    const instructions: TransactionInstruction[] = [];
+    if (acct.data.length < TOKEN_BONDING_SIZE) {
+      instructions.push(
+        await this.instruction.resizeTokenBondingV0({
+          accounts: {
+            payer,
+            tokenBonding,
+            systemProgram: SystemProgram.programId,
+          },
+        })
+      );
+    }
+
+    return {
+      output: null,
+      signers: [],
+      instructions,
+    };
+  }
+
+  /**
+   * Runs {@link resizeTokenBondingInstructions}
+   * @param args
+   */
+  async resizeTokenBonding(
+    args: IResizeTokenBondingArgs,
+    commitment: Commitment = "confirmed"
+  ): Promise<void> {
+    await this.execute(
+      this.resizeTokenBondingInstructions(args),
+      args.payer,
+      commitment
+    );
+  }
+
   /**
    * Instructions to buy twSOL from normal SOL.
    *
@@ -1690,6 +1849,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     expectedBaseAmount,
     slippage,
     payer = this.wallet.publicKey,
+    allowlistProof,
   }: IBuyArgs): Promise<InstructionResult<null>> {
     const state = (await this.getState())!;
     const tokenBondingAcct = (await this.getTokenBonding(tokenBonding))!;
@@ -1831,11 +1991,12 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
       }
     }
 
-    const args: IdlTypes<SplTokenBondingIDL>["BuyV0Args"] = {
+    const args: IdlTypes<SplTokenBondingIDL>["BuyV1Args"] = {
       // @ts-ignore
       buyTargetAmount,
       // @ts-ignore
       buyWithBase,
+      allowlistProof: allowlistProof || null,
     };
 
     const common = {
@@ -1854,7 +2015,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
 
     if (isNative) {
       instructions.push(
-        await this.instruction.buyNativeV0(args, {
+        await this.instruction.buyNativeV1(args, {
           accounts: {
             common,
             state: state.publicKey,
@@ -1870,7 +2031,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
       );
     } else {
       instructions.push(
-        await this.instruction.buyV1(args, {
+        await this.instruction.buyV2(args, {
           accounts: {
             common,
             state: state.publicKey,
@@ -2182,6 +2343,64 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     return state;
   }
 
+  /**
+   * Reserve staking accounts, in the order sells of bondings that stake their reserves take them
+   * as remaining accounts, so they can unstake what sol storage does not cover
+   *
+   * @returns
+   */
+  async reserveStakingAccounts(): Promise<AccountMeta[]> {
+    const state = (await this.getState())!;
+    const reserveStaking = (
+      await PublicKey.findProgramAddress(
+        [Buffer.from("reserve-staking", "utf-8")],
+        this.programId
+      )
+    )[0];
+    const reserveStakingAcct = await this.account.reserveStakingV0.fetch(
+      reserveStaking
+    );
+    const stakePool = (await this.provider.connection.getAccountInfo(
+      reserveStakingAcct.stakePool
+    ))!;
+    // Offsets into the borsh encoded StakePool account
+    const stakePoolKey = (offset: number) =>
+      new PublicKey(stakePool.data.slice(offset, offset + 32));
+    const withdrawAuthority = (
+      await PublicKey.findProgramAddress(
+        [
+          reserveStakingAcct.stakePool.toBuffer(),
+          Buffer.from("withdraw", "utf-8"),
+        ],
+        reserveStakingAcct.stakePoolProgram
+      )
+    )[0];
+
+    const accounts: [PublicKey, boolean][] = [
+      [state.publicKey, false],
+      [reserveStaking, false],
+      [state.wrappedSolMint, true],
+      [state.solStorage, true],
+      [reserveStakingAcct.poolTokens, true],
+      [reserveStakingAcct.stakePool, true],
+      [withdrawAuthority, false],
+      [stakePoolKey(130), true], // reserve stake
+      [stakePoolKey(194), true], // manager fee account
+      [stakePoolKey(162), true], // pool mint
+      [reserveStakingAcct.stakePoolProgram, false],
+      [SYSVAR_CLOCK_PUBKEY, false],
+      [SYSVAR_STAKE_HISTORY_PUBKEY, false],
+      [StakeProgram.programId, false],
+      [TOKEN_PROGRAM_ID, false],
+      [SystemProgram.programId, false],
+    ];
+    return accounts.map(([pubkey, isWritable]) => ({
+      pubkey,
+      isWritable,
+      isSigner: false,
+    }));
+  }
+
   /**
    * Instructions to burn `targetMint` tokens in exchange for `baseMint` tokens
    *
@@ -2197,6 +2416,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     expectedOutputAmount,
     slippage,
     payer = this.wallet.publicKey,
+    baseAmount,
   }: ISellArgs): Promise<InstructionResult<null>> {
     const state = (await this.getState())!;
     const tokenBondingAcct = (await this.getTokenBonding(tokenBonding))!;
@@ -2298,11 +2518,24 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
           unixTime
         );
 
-    const args: IdlTypes<SplTokenBondingIDL>["SellV0Args"] = {
+    const args: IdlTypes<SplTokenBondingIDL>["SellV2Args"] = {
       targetAmount: toBN(targetAmount, targetMint),
       minimumPrice: new BN(
         Math.ceil(min * (1 - slippage) * Math.pow(10, baseMint.decimals))
       ),
+      sellForBase:
+        typeof baseAmount === "undefined"
+          ? null
+          : {
+              baseAmount: toBN(baseAmount, baseMint),
+              maximumTargetAmount: new BN(
+                Math.floor(
+                  targetAmountNum *
+                    (1 + slippage) *
+                    Math.pow(10, targetMint.decimals)
+                )
+              ),
+            },
     };
 
     const common = {
@@ -2321,7 +2554,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     };
     if (isNative) {
       instructions.push(
-        await this.instruction.sellNativeV0(args, {
+        await this.instruction.sellNativeV1(args, {
           accounts: {
             common,
             destination,
@@ -2333,11 +2566,15 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
             solStorage: state.solStorage,
             systemProgram: SystemProgram.programId,
           },
+          // Lets the sell unstake, in case it pays out more than is unstaked
+          remainingAccounts: tokenBondingAcct.reserveStake
+            ? await this.reserveStakingAccounts()
+            : [],
         })
       );
     } else {
       instructions.push(
-        await this.instruction.sellV1(args, {
+        await this.instruction.sellV2(args, {
           accounts: {
             common,
             state: state.publicKey,
@@ -2375,6 +2612,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     tokenBonding,
     generalAuthority,
     refund = this.wallet.publicKey,
+    payer = this.wallet.publicKey,
   }: ICloseArgs): Promise<InstructionResult<null>> {
     const tokenBondingAcct = (await this.getTokenBonding(tokenBonding))!;
 
@@ -2383,24 +2621,62 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
         "Cannot close a bonding account with no general authority"
       );
     }
+    generalAuthority =
+      generalAuthority || (tokenBondingAcct.generalAuthority! as PublicKey);
+
+    const instructions: TransactionInstruction[] = [];
+    const remainingAccounts: AccountMeta[] = [];
+    // A wound down bonding sweeps whatever its holders left behind to the general authority
+    const reserves = (
+      await this.provider.connection.getTokenAccountBalance(
+        tokenBondingAcct.baseStorage
+      )
+    ).value.amount;
+    if (tokenBondingAcct.windDown && reserves != "0") {
+      const sweepDestination = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        tokenBondingAcct.baseMint,
+        generalAuthority,
+        true
+      );
+      if (!(await this.accountExists(sweepDestination))) {
+        instructions.push(
+          Token.createAssociatedTokenAccountInstruction(
+            ASSOCIATED_TOKEN_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+            tokenBondingAcct.baseMint,
+            sweepDestination,
+            generalAuthority,
+            payer
+          )
+        );
+      }
+      remainingAccounts.push({
+        pubkey: sweepDestination,
+        isWritable: true,
+        isSigner: false,
+      });
+    }
+
+    instructions.push(
+      await this.instruction.closeTokenBondingV0({
+        accounts: {
+          refund,
+          tokenBonding,
+          generalAuthority,
+          targetMint: tokenBondingAcct.targetMint,
+          baseStorage: tokenBondingAcct.baseStorage,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        },
+        remainingAccounts,
+      })
+    );
 
     return {
       output: null,
       signers: [],
-      instructions: [
-        await this.instruction.closeTokenBondingV0({
-          accounts: {
-            refund,
-            tokenBonding,
-            generalAuthority:
-              generalAuthority ||
-              (tokenBondingAcct.generalAuthority! as PublicKey),
-            targetMint: tokenBondingAcct.targetMint,
-            baseStorage: tokenBondingAcct.baseStorage,
-            tokenProgram: TOKEN_PROGRAM_ID,
-          },
-        }),
-      ],
+      instructions,
     };
   }
 
@@ -2495,7 +2771,7 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
     };
     if (isNative) {
       instructions.push(
-        await this.instruction.transferReservesNativeV0(args, {
+        await this.instruction.transferReservesNativeV1(args, {
           accounts: {
             common,
             destination: destination!,
@@ -2506,15 +2782,19 @@ export class SplTokenBonding extends AnchorSdk<SplTokenBondingIDL> {
             )[0],
             solStorage: state.solStorage,
             systemProgram: SystemProgram.programId,
+            curve: tokenBondingAcct.curve,
+            targetMint: tokenBondingAcct.targetMint,
           },
         })
       );
     } else {
       instructions.push(
-        await this.instruction.transferReservesV0(args, {
+        await this.instruction.transferReservesV1(args, {
           accounts: {
             common,
             destination: destination!,
+            curve: tokenBondingAcct.curve,
+            targetMint: tokenBondingAcct.targetMint,
           },
         })
       );
@@ -1,6 +1,8 @@
 use crate::{
-  precise_number::{InnerUint, PreciseNumber, ONE_PREC, ZERO_PREC},
-  state::{PiecewiseCurve, PrimitiveCurve, TimeCurveV0},
+  precise_number::{InnerUint, PreciseNumber, ONE_PREC, TWO_PREC, ZERO_PREC},
+  signed_precise_number::SignedPreciseNumber,
+  state::{PiecewiseCurve, PricePointV0, PrimitiveCurve, SupplyCurveV0, TimeCurveV0},
+  uint::U192,
   util::get_percent_prec,
 };
 use std::convert::*;
@@ -21,6 +23,14 @@ pub trait Curve {
     target_supply: &PreciseNumber,
     reserve_change: &PreciseNumber,
   ) -> Option<PreciseNumber>;
+  // Marginal price of the next target token at the current reserves and supply, the limit of price / amount
+  fn spot_price(
+    &self,
+    time_offset: i64,
+    base_amount: &PreciseNumber,
+    target_supply: &PreciseNumber,
+    sell: bool,
+  ) -> Option<PreciseNumber>;
 }
 
 fn expected_target_amount_exp_initial(
@@ -130,6 +140,182 @@ fn price_exp(
   Some(exp.signed().checked_sub(&base_amount.signed())?.value)
 }
 
+fn reserves_exp_with_b(
+  a_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  one_plus_k_prec: &PreciseNumber,
+  x: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // a x^(1 + k) + b x
+  a_prec
+    .checked_mul(&x.pow(one_plus_k_prec)?)?
+    .checked_add(&b_prec.checked_mul(x)?)
+}
+
+// Steps smaller than this are beyond the 12 decimals a mint can hold
+const SOLVER_TOLERANCE: u64 = 1_000_000;
+const MAX_NEWTON_ITERATIONS: u8 = 12;
+
+fn solve_exp_with_b(
+  a_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  k_prec: &PreciseNumber,
+  target: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  /*
+   * Solve a x^(1 + k) + b x = T for x.
+   *
+   * There is no closed form unless k is 0, so use Newton's method on
+   * f(x) = a x^(1 + k) + b x - T, f'(x) = (1 + k) a x^k + b
+   *
+   * f is increasing and convex, so starting from an upper bound every step stays above the
+   * root and walks down towards it. Both T / b and (T / a)^(1 / (1 + k)) are upper bounds.
+   *
+   * Returns None if Newton has not settled within MAX_NEWTON_ITERATIONS, rather than an amount
+   * that could be too large. Buys fall back to solve_target_amount.
+   */
+  if target.eq(&ZERO_PREC) {
+    return Some(ZERO_PREC.clone());
+  }
+
+  if k_prec.eq(&ZERO_PREC) {
+    return target.checked_div(&a_prec.checked_add(b_prec)?);
+  }
+
+  let one_plus_k_prec = ONE_PREC.checked_add(k_prec)?;
+  let exp_bound = target
+    .checked_div(a_prec)?
+    .pow(&ONE_PREC.checked_div(&one_plus_k_prec)?)?;
+  let mut x = match target.checked_div(b_prec) {
+    Some(linear_bound) if linear_bound.less_than(&exp_bound) => linear_bound,
+    _ => exp_bound,
+  };
+
+  for _ in 0..MAX_NEWTON_ITERATIONS {
+    let current = reserves_exp_with_b(a_prec, b_prec, &one_plus_k_prec, &x)?;
+    if current.less_than_or_equal(target) {
+      return Some(x);
+    }
+
+    let slope = one_plus_k_prec
+      .checked_mul(a_prec)?
+      .checked_mul(&x.pow(k_prec)?)?
+      .checked_add(b_prec)?;
+    let step = current.checked_sub(target)?.checked_div(&slope)?;
+    x = x.checked_sub(&step)?;
+
+    if step.value < InnerUint::from(SOLVER_TOLERANCE) {
+      return Some(x);
+    }
+  }
+
+  None
+}
+
+fn expected_target_amount_exp_with_b_initial(
+  c_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  k_prec: &PreciseNumber,
+  reserve_change: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // dR = (c dS^(1 + k))/(1 + k) + b dS
+  let one_plus_k_prec = ONE_PREC.checked_add(k_prec)?;
+  solve_exp_with_b(
+    &c_prec.checked_div(&one_plus_k_prec)?,
+    b_prec,
+    k_prec,
+    reserve_change,
+  )
+}
+
+fn exp_with_b_weight(
+  c_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  k_prec: &PreciseNumber,
+  target_supply: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // w = (b (1 + k)) / (c S^k), done in log space since S^k overflows quickly
+  b_prec
+    .checked_mul(&ONE_PREC.checked_add(k_prec)?)?
+    .checked_div(c_prec)?
+    .log()?
+    .checked_sub(&k_prec.signed().checked_mul(&target_supply.log()?)?)?
+    .exp()
+}
+
+fn expected_target_amount_exp_with_b(
+  c_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  k_prec: &PreciseNumber,
+  reserve_change: &PreciseNumber,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  /*
+   * Invert price_exp_with_b. With u = (S + dS) / S,
+   *
+   * (R + dR) / R = (u^(1 + k) + w u) / (1 + w)
+   * u^(1 + k) + w u = (1 + w)(R + dR) / R
+   * dS = S (u - 1)
+   */
+  let w = exp_with_b_weight(c_prec, b_prec, k_prec, target_supply)?;
+  let target = ONE_PREC.checked_add(&w)?.checked_mul(
+    &base_amount
+      .checked_add(reserve_change)?
+      .checked_div(base_amount)?,
+  )?;
+  let u = solve_exp_with_b(&ONE_PREC, &w, k_prec, &target)?;
+
+  // Rounding can put u a hair below 1 on tiny purchases
+  if u.less_than_or_equal(&ONE_PREC) {
+    return Some(ZERO_PREC.clone());
+  }
+
+  target_supply.checked_mul(&u.checked_sub(&ONE_PREC)?)
+}
+
+fn price_exp_with_b(
+  c_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  k_prec: &PreciseNumber,
+  amount: &PreciseNumber,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+  sell: bool,
+) -> Option<PreciseNumber> {
+  /*
+   * Scale the curve so that it passes through the current reserves and supply, same as the
+   * single term curves. R(S) = (c S^(1 + k))/(1 + k) + b S, m = R / R(S)
+   *
+   * dR = m R(S + dS) - R
+   * dR = R (R(S + dS) / R(S)) - R
+   *
+   * Dividing through by (c S^(1 + k))/(1 + k), with u = (S + dS) / S and w = (b (1 + k)) / (c S^k)
+   *
+   * R(S + dS) / R(S) = (u^(1 + k) + w u) / (1 + w)
+   *
+   * Edge case: selling where dS = S. Just charge them the full base amount
+   */
+  let s_plus_ds = if sell {
+    target_supply.checked_sub(amount)?
+  } else {
+    target_supply.checked_add(amount)?
+  };
+
+  if s_plus_ds.eq(&ZERO_PREC) {
+    return Some(base_amount.clone());
+  }
+
+  let one_plus_k_prec = ONE_PREC.checked_add(k_prec)?;
+  let w = exp_with_b_weight(c_prec, b_prec, k_prec, target_supply)?;
+  let u = s_plus_ds.checked_div(target_supply)?;
+  let ratio = reserves_exp_with_b(&ONE_PREC, &w, &one_plus_k_prec, &u)?
+    .checked_div(&ONE_PREC.checked_add(&w)?)?;
+  let new_reserves = base_amount.checked_mul(&ratio)?;
+
+  Some(new_reserves.unsigned_sub(base_amount).0)
+}
+
 fn to_prec(i: u128) -> PreciseNumber {
   PreciseNumber {
     value: InnerUint::from(i) * 1_000_000_u64, // Add 6 precision
@@ -176,6 +362,397 @@ fn time_decay_k(
   )
 }
 
+// 0.001
+const LOG_SERIES_THRESHOLD: PreciseNumber = PreciseNumber {
+  value: U192([1_000_000_000_000_000_u64, 0_u64, 0_u64]),
+};
+
+fn reserves_log(
+  c_prec: &PreciseNumber,
+  g_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  x: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // b x + c ((1 + g x) ln(1 + g x) / g - x)
+  let u = g_prec.checked_mul(x)?;
+  let integral =
+    if u.less_than(&LOG_SERIES_THRESHOLD) {
+      // The two terms nearly cancel for small g x, use the series x (u/2 - u^2/6 + u^3/12 - u^4/20) instead
+      let term = |d: u128| ONE_PREC.checked_div(&PreciseNumber::new(d)?);
+      let series =
+        term(2)?.checked_sub(&u.checked_mul(&term(6)?.checked_sub(
+          &u.checked_mul(&term(12)?.checked_sub(&u.checked_mul(&term(20)?)?)?)?,
+        )?)?)?;
+      x.checked_mul(&u)?.checked_mul(&series)?
+    } else {
+      let one_plus_u = ONE_PREC.checked_add(&u)?;
+      one_plus_u
+        .checked_mul(&one_plus_u.log()?.value)?
+        .checked_div(g_prec)?
+        .checked_sub(x)
+        // Mathematically never negative, rounding can put it a hair under
+        .unwrap_or_else(|| ZERO_PREC.clone())
+    };
+
+  b_prec
+    .checked_mul(x)?
+    .checked_add(&c_prec.checked_mul(&integral)?)
+}
+
+fn spot_price_log(
+  c_prec: &PreciseNumber,
+  g_prec: &PreciseNumber,
+  b_prec: &PreciseNumber,
+  x: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // b + c ln(1 + g x)
+  b_prec
+    .checked_add(&c_prec.checked_mul(&ONE_PREC.checked_add(&g_prec.checked_mul(x)?)?.log()?.value)?)
+}
+
+// ln(1 + e^z). Written as max(z, 0) + ln(1 + e^(-|z|)) so exp never sees a large positive number
+fn softplus(z: &SignedPreciseNumber) -> Option<PreciseNumber> {
+  let tail = ONE_PREC
+    .checked_add(&z.value.signed().negate().exp()?)?
+    .log()?
+    .value;
+
+  if z.is_negative {
+    Some(tail)
+  } else {
+    z.value.checked_add(&tail)
+  }
+}
+
+fn sigmoid_exponent(
+  k_prec: &PreciseNumber,
+  midpoint_prec: &PreciseNumber,
+  x: &PreciseNumber,
+) -> Option<SignedPreciseNumber> {
+  // k (x - midpoint)
+  k_prec
+    .checked_mul(x)?
+    .signed()
+    .checked_sub(&k_prec.checked_mul(midpoint_prec)?.signed())
+}
+
+fn reserves_sigmoid(
+  max_price_prec: &PreciseNumber,
+  k_prec: &PreciseNumber,
+  midpoint_prec: &PreciseNumber,
+  x: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // (max_price / k) (ln(1 + e^(k (x - midpoint))) - ln(1 + e^(-k midpoint)))
+  let from = softplus(&sigmoid_exponent(k_prec, midpoint_prec, &ZERO_PREC)?)?;
+  let to = softplus(&sigmoid_exponent(k_prec, midpoint_prec, x)?)?;
+
+  max_price_prec
+    .checked_div(k_prec)?
+    .checked_mul(&to.checked_sub(&from).unwrap_or_else(|| ZERO_PREC.clone()))
+}
+
+fn spot_price_sigmoid(
+  max_price_prec: &PreciseNumber,
+  k_prec: &PreciseNumber,
+  midpoint_prec: &PreciseNumber,
+  x: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // max_price / (1 + e^(-z)), or max_price e^z / (1 + e^z) when z is negative
+  let z = sigmoid_exponent(k_prec, midpoint_prec, x)?;
+  let e = z.value.signed().negate().exp()?;
+  let numerator = if z.is_negative {
+    max_price_prec.checked_mul(&e)?
+  } else {
+    max_price_prec.clone()
+  };
+
+  numerator.checked_div(&ONE_PREC.checked_add(&e)?)
+}
+
+fn solve_reserves<R, P>(
+  reserves: R,
+  spot_price: P,
+  from: &PreciseNumber,
+  target: &PreciseNumber,
+) -> Option<PreciseNumber>
+where
+  R: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+  P: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+{
+  /*
+   * Solve R(x) = T for x >= from, where R is increasing and convex and R' is the spot price.
+   *
+   * The tangent at from lies below R, so following it up to T gives an upper bound on the root.
+   * Newton's method from an upper bound stays above the root and walks down towards it, same as
+   * solve_exp_with_b. If the curve is flat at from, double out until we pass T instead.
+   *
+   * Returns None if Newton has not settled within MAX_NEWTON_ITERATIONS, rather than an amount
+   * that could be too large, or if rounding put a large step below the root. Buys fall back to
+   * solve_target_amount.
+   */
+  let start = reserves(from)?;
+  if target.less_than_or_equal(&start) {
+    return Some(from.clone());
+  }
+
+  let slope = spot_price(from)?;
+  let mut width = if slope.eq(&ZERO_PREC) {
+    ONE_PREC.clone()
+  } else {
+    target.checked_sub(&start)?.checked_div(&slope)?
+  };
+  let mut x = from.checked_add(&width)?;
+  let mut iterations = 0;
+  while reserves(&x)?.less_than(target) {
+    iterations += 1;
+    if iterations > MAX_BRACKET_ITERATIONS {
+      return None;
+    }
+    width = width.checked_mul(&PreciseNumber::new(BRACKET_GROWTH)?)?;
+    x = from.checked_add(&width)?;
+  }
+
+  let mut settled = true;
+  for _ in 0..MAX_NEWTON_ITERATIONS {
+    let current = reserves(&x)?;
+    if current.less_than_or_equal(target) {
+      // From above, only rounding lands below the root. After a large step, such as one down from
+      // a far bracket on a curve that starts flat, that rounding can be worth whole tokens
+      return if settled { Some(x) } else { None };
+    }
+
+    let step = current.checked_sub(target)?.checked_div(&spot_price(&x)?)?;
+    x = x.checked_sub(&step)?;
+
+    settled = step.value < InnerUint::from(SOLVER_TOLERANCE);
+    if settled {
+      return Some(x);
+    }
+  }
+
+  None
+}
+
+// a b / c, rounded once. checked_div rounds by half of one, not half of the divisor, which swamps
+// the result when c is only a few units of precision, as reserves are on very flat curves
+fn mul_div(a: &PreciseNumber, b: &PreciseNumber, c: &PreciseNumber) -> Option<PreciseNumber> {
+  if c.eq(&ZERO_PREC) {
+    return None;
+  }
+
+  match a.value.checked_mul(b.value) {
+    Some(product) => Some(PreciseNumber {
+      value: product
+        .checked_add(c.value.checked_div(InnerUint::from(2))?)?
+        .checked_div(c.value)?,
+    }),
+    None => a.checked_mul(&b.checked_div(c)?),
+  }
+}
+
+fn expected_target_amount_scaled<R, P>(
+  reserves: R,
+  spot_price: P,
+  reserve_change: &PreciseNumber,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+) -> Option<PreciseNumber>
+where
+  R: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+  P: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+{
+  /*
+   * Invert price_scaled.
+   *
+   * R(S + dS) = R(S) (R + dR) / R
+   */
+  let current = reserves(target_supply)?;
+  let target = current.checked_add(&mul_div(&current, reserve_change, base_amount)?)?;
+  let new_supply = solve_reserves(reserves, spot_price, target_supply, &target)?;
+
+  new_supply.checked_sub(target_supply)
+}
+
+fn price_scaled<R>(
+  reserves: R,
+  amount: &PreciseNumber,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+  sell: bool,
+) -> Option<PreciseNumber>
+where
+  R: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+{
+  /*
+   * Scale the curve so that it passes through the current reserves and supply, same as the
+   * exponential curves. m = R / R(S)
+   *
+   * dR = |m R(S + dS) - R| = R |R(S + dS) - R(S)| / R(S)
+   *
+   * Edge case: selling where dS = S. Just charge them the full base amount
+   */
+  let s_plus_ds = if sell {
+    target_supply.checked_sub(amount)?
+  } else {
+    target_supply.checked_add(amount)?
+  };
+
+  if s_plus_ds.eq(&ZERO_PREC) {
+    return Some(base_amount.clone());
+  }
+
+  let current = reserves(target_supply)?;
+  let (change, _) = reserves(&s_plus_ds)?.unsigned_sub(&current);
+
+  mul_div(base_amount, &change, &current)
+}
+
+fn spot_price_scaled<R, P>(
+  reserves: R,
+  spot_price: P,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+) -> Option<PreciseNumber>
+where
+  R: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+  P: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+{
+  // m R'(S), m = R / R(S)
+  mul_div(
+    base_amount,
+    &spot_price(target_supply)?,
+    &reserves(target_supply)?,
+  )
+}
+
+// Index of the point that starts the segment holding supply x
+fn price_table_segment(points: &[PricePointV0], x: &PreciseNumber) -> Option<usize> {
+  points
+    .iter()
+    .rposition(|p| to_prec(p.supply).less_than_or_equal(x))
+}
+
+fn price_table_spot(points: &[PricePointV0], i: usize, x: &PreciseNumber) -> Option<PreciseNumber> {
+  // p_i + (p_i+1 - p_i) (x - s_i) / (s_i+1 - s_i), flat past the last point
+  let start = points.get(i)?;
+  let start_price = to_prec(start.price);
+  let end = match points.get(i + 1) {
+    Some(end) => end,
+    None => return Some(start_price),
+  };
+  let end_price = to_prec(end.price);
+  let start_supply = to_prec(start.supply);
+  let fraction = x
+    .checked_sub(&start_supply)?
+    .checked_div(&to_prec(end.supply).checked_sub(&start_supply)?)?;
+  let (rise, falling) = end_price.unsigned_sub(&start_price);
+  let change = rise.checked_mul(&fraction)?;
+
+  if falling {
+    start_price.checked_sub(&change)
+  } else {
+    start_price.checked_add(&change)
+  }
+}
+
+fn price_table_reserves(points: &[PricePointV0], x: &PreciseNumber) -> Option<PreciseNumber> {
+  // Sum of the trapezoids under the table up to x
+  let segment = price_table_segment(points, x)?;
+  let mut reserves = ZERO_PREC.clone();
+  for i in 0..segment {
+    let width = to_prec(points[i + 1].supply).checked_sub(&to_prec(points[i].supply))?;
+    let height = to_prec(points[i].price).checked_add(&to_prec(points[i + 1].price))?;
+    reserves = reserves.checked_add(&width.checked_mul(&height)?.checked_div(&TWO_PREC)?)?;
+  }
+
+  let width = x.checked_sub(&to_prec(points[segment].supply))?;
+  let height =
+    to_prec(points[segment].price).checked_add(&price_table_spot(points, segment, x)?)?;
+  reserves.checked_add(&width.checked_mul(&height)?.checked_div(&TWO_PREC)?)
+}
+
+fn solve_price_table(
+  points: &[PricePointV0],
+  from: &PreciseNumber,
+  target: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  /*
+   * Solve R(x) = T for x >= from. Walk whole segments until the one holding T, then solve
+   *
+   * p dx + m dx^2 / 2 = A, m = slope of the segment, A = area left to cover
+   * dx = 2A / (p + sqrt(p^2 + 2mA))
+   *
+   * This form avoids cancellation, and holds for falling segments since every price is positive.
+   */
+  let mut segment = price_table_segment(points, from)?;
+  let mut x = from.clone();
+  let mut remaining = target
+    .checked_sub(&price_table_reserves(points, from)?)
+    .unwrap_or_else(|| ZERO_PREC.clone());
+  loop {
+    let price = price_table_spot(points, segment, &x)?;
+    let end = match points.get(segment + 1) {
+      Some(end) => end,
+      None => return x.checked_add(&remaining.checked_div(&price)?),
+    };
+    let end_supply = to_prec(end.supply);
+    let end_price = to_prec(end.price);
+    let width = end_supply.checked_sub(&x)?;
+    let area = width
+      .checked_mul(&price.checked_add(&end_price)?)?
+      .checked_div(&TWO_PREC)?;
+
+    if remaining.greater_than(&area) {
+      remaining = remaining.checked_sub(&area)?;
+      x = end_supply;
+      segment += 1;
+      continue;
+    }
+
+    let start_supply = to_prec(points[segment].supply);
+    let (rise, falling) = end_price.unsigned_sub(&to_prec(points[segment].price));
+    let two_m_a = TWO_PREC
+      .checked_mul(&rise.checked_div(&end_supply.checked_sub(&start_supply)?)?)?
+      .checked_mul(&remaining)?;
+    let p_squared = price.checked_mul(&price)?;
+    let discriminant = if falling {
+      // Rounding can push this a hair under zero right at the end of the segment
+      p_squared
+        .checked_sub(&two_m_a)
+        .unwrap_or_else(|| ZERO_PREC.clone())
+    } else {
+      p_squared.checked_add(&two_m_a)?
+    };
+    let dx = TWO_PREC
+      .checked_mul(&remaining)?
+      .checked_div(&price.checked_add(&discriminant.sqrt()?)?)?;
+
+    // Never step past the end of the segment
+    return Some(if dx.greater_than(&width) {
+      end_supply
+    } else {
+      x.checked_add(&dx)?
+    });
+  }
+}
+
+fn expected_target_amount_price_table(
+  points: &[PricePointV0],
+  reserve_change: &PreciseNumber,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  /*
+   * Invert price_scaled.
+   *
+   * R(S + dS) = R(S) (R + dR) / R
+   */
+  let current = price_table_reserves(points, target_supply)?;
+  let target = current.checked_add(&mul_div(&current, reserve_change, base_amount)?)?;
+
+  solve_price_table(points, target_supply, &target)?.checked_sub(target_supply)
+}
+
 impl Curve for PrimitiveCurve {
   fn expected_target_amount(
     &self,
@@ -207,7 +784,12 @@ impl Curve for PrimitiveCurve {
                 .checked_div(&b_prec)
             }
           } else {
-            None // This math is too hard, have not implemented yet.
+            expected_target_amount_exp_with_b_initial(
+              &c_prec,
+              &b_prec,
+              &pow_prec.checked_div(&frac_prec)?,
+              reserve_change,
+            )
           }
         }
         PrimitiveCurve::TimeDecayExponentialCurveV0 {
@@ -221,6 +803,32 @@ impl Curve for PrimitiveCurve {
           &time_decay_k(d, k0, k1, time_offset, interval)?,
           reserve_change,
         ),
+        PrimitiveCurve::LogarithmicCurveV0 { c, g, b } => {
+          let (c_prec, g_prec, b_prec) = (to_prec(c), to_prec(g), to_prec(b));
+          solve_reserves(
+            |x| reserves_log(&c_prec, &g_prec, &b_prec, x),
+            |x| spot_price_log(&c_prec, &g_prec, &b_prec, x),
+            &ZERO_PREC,
+            reserve_change,
+          )
+        }
+        PrimitiveCurve::SigmoidCurveV0 {
+          max_price,
+          k,
+          midpoint,
+        } => {
+          let (max_price_prec, k_prec, midpoint_prec) =
+            (to_prec(max_price), to_prec(k), to_prec(midpoint));
+          solve_reserves(
+            |x| reserves_sigmoid(&max_price_prec, &k_prec, &midpoint_prec, x),
+            |x| spot_price_sigmoid(&max_price_prec, &k_prec, &midpoint_prec, x),
+            &ZERO_PREC,
+            reserve_change,
+          )
+        }
+        PrimitiveCurve::PriceTableCurveV0 { ref points } => {
+          solve_price_table(points, &ZERO_PREC, reserve_change)
+        }
       }
     } else {
       match *self {
@@ -243,7 +851,14 @@ impl Curve for PrimitiveCurve {
               .checked_mul(reserve_change)?
               .checked_div(base_amount)
           } else {
-            None // This math is too hard, have not implemented yet.
+            expected_target_amount_exp_with_b(
+              &to_prec(c),
+              &to_prec(b),
+              &pow_prec.checked_div(&frac_prec)?,
+              reserve_change,
+              base_amount,
+              target_supply,
+            )
           }
         }
         PrimitiveCurve::TimeDecayExponentialCurveV0 {
@@ -258,6 +873,34 @@ impl Curve for PrimitiveCurve {
           base_amount,
           target_supply,
         ),
+        PrimitiveCurve::LogarithmicCurveV0 { c, g, b } => {
+          let (c_prec, g_prec, b_prec) = (to_prec(c), to_prec(g), to_prec(b));
+          expected_target_amount_scaled(
+            |x| reserves_log(&c_prec, &g_prec, &b_prec, x),
+            |x| spot_price_log(&c_prec, &g_prec, &b_prec, x),
+            reserve_change,
+            base_amount,
+            target_supply,
+          )
+        }
+        PrimitiveCurve::SigmoidCurveV0 {
+          max_price,
+          k,
+          midpoint,
+        } => {
+          let (max_price_prec, k_prec, midpoint_prec) =
+            (to_prec(max_price), to_prec(k), to_prec(midpoint));
+          expected_target_amount_scaled(
+            |x| reserves_sigmoid(&max_price_prec, &k_prec, &midpoint_prec, x),
+            |x| spot_price_sigmoid(&max_price_prec, &k_prec, &midpoint_prec, x),
+            reserve_change,
+            base_amount,
+            target_supply,
+          )
+        }
+        PrimitiveCurve::PriceTableCurveV0 { ref points } => {
+          expected_target_amount_price_table(points, reserve_change, base_amount, target_supply)
+        }
       }
     }
   }
@@ -295,6 +938,15 @@ impl Curve for PrimitiveCurve {
           &time_decay_k(d, k0, k1, time_offset, interval)?,
           amount,
         ),
+        PrimitiveCurve::LogarithmicCurveV0 { c, g, b } => {
+          reserves_log(&to_prec(c), &to_prec(g), &to_prec(b), amount)
+        }
+        PrimitiveCurve::SigmoidCurveV0 {
+          max_price,
+          k,
+          midpoint,
+        } => reserves_sigmoid(&to_prec(max_price), &to_prec(k), &to_prec(midpoint), amount),
+        PrimitiveCurve::PriceTableCurveV0 { ref points } => price_table_reserves(points, amount),
       }
     } else {
       match *self {
@@ -308,7 +960,17 @@ impl Curve for PrimitiveCurve {
             // R dS / S
             base_amount.checked_mul(amount)?.checked_div(target_supply)
           } else {
-            None // Math is too hard, haven't implemented yet
+            let pow_prec = PreciseNumber::new(u128::try_from(pow).ok()?)?;
+            let frac_prec = PreciseNumber::new(u128::try_from(frac).ok()?)?;
+            price_exp_with_b(
+              &to_prec(c),
+              &to_prec(b),
+              &pow_prec.checked_div(&frac_prec)?,
+              amount,
+              base_amount,
+              target_supply,
+              sell,
+            )
           }
         }
         PrimitiveCurve::TimeDecayExponentialCurveV0 {
@@ -324,34 +986,368 @@ impl Curve for PrimitiveCurve {
           target_supply,
           sell,
         ),
+        PrimitiveCurve::LogarithmicCurveV0 { c, g, b } => {
+          let (c_prec, g_prec, b_prec) = (to_prec(c), to_prec(g), to_prec(b));
+          price_scaled(
+            |x| reserves_log(&c_prec, &g_prec, &b_prec, x),
+            amount,
+            base_amount,
+            target_supply,
+            sell,
+          )
+        }
+        PrimitiveCurve::SigmoidCurveV0 {
+          max_price,
+          k,
+          midpoint,
+        } => {
+          let (max_price_prec, k_prec, midpoint_prec) =
+            (to_prec(max_price), to_prec(k), to_prec(midpoint));
+          price_scaled(
+            |x| reserves_sigmoid(&max_price_prec, &k_prec, &midpoint_prec, x),
+            amount,
+            base_amount,
+            target_supply,
+            sell,
+          )
+        }
+        PrimitiveCurve::PriceTableCurveV0 { ref points } => price_scaled(
+          |x| price_table_reserves(points, x),
+          amount,
+          base_amount,
+          target_supply,
+          sell,
+        ),
       }
     }
   }
-}
-
-fn transition_fees(
-  time_offset: i64,
-  reserve_change: &PreciseNumber,
-  curve: &TimeCurveV0,
-  sell: bool,
-) -> Option<PreciseNumber> {
-  let transition_fees_opt = if sell {
-    curve.sell_transition_fees.as_ref()
-  } else {
-    curve.buy_transition_fees.as_ref()
-  };
-
-  if let Some(fees) = transition_fees_opt {
-    let offset_in_current_curve =
-      PreciseNumber::new(u128::try_from(time_offset.checked_sub(curve.offset)?).ok()?)?;
-    let interval = PreciseNumber::new(u128::try_from(fees.interval).ok()?)?;
-    // Decaying percentage. Starts at 100%, works its way down to 0 over the interval. (interval - curr_offset) / interval.
-    // When curr_offset is past interval, checked_sub fails and this is just none
-    let percent_of_fees_opt = interval
-      .checked_sub(&offset_in_current_curve)?
-      .checked_div(&interval);
-    if let Some(percent_of_fees) = percent_of_fees_opt {
-      let percent = get_percent_prec(fees.percentage).ok()?;
+
+  fn spot_price(
+    &self,
+    time_offset: i64,
+    base_amount: &PreciseNumber,
+    target_supply: &PreciseNumber,
+    _sell: bool,
+  ) -> Option<PreciseNumber> {
+    if base_amount.eq(&ZERO_PREC) || target_supply.eq(&ZERO_PREC) {
+      // Nothing is priced from the reserves yet, so this is the curve itself at zero supply
+      match *self {
+        // b + c 0^(pow/frac)
+        PrimitiveCurve::ExponentialCurveV0 { pow, c, b, .. } => {
+          if pow == 0 {
+            to_prec(b).checked_add(&to_prec(c))
+          } else {
+            Some(to_prec(b))
+          }
+        }
+        PrimitiveCurve::TimeDecayExponentialCurveV0 {
+          d,
+          c,
+          k1,
+          k0,
+          interval,
+        } => {
+          if time_decay_k(d, k0, k1, time_offset, interval)?.eq(&ZERO_PREC) {
+            Some(to_prec(c))
+          } else {
+            Some(ZERO_PREC.clone())
+          }
+        }
+        PrimitiveCurve::LogarithmicCurveV0 { b, .. } => Some(to_prec(b)),
+        PrimitiveCurve::SigmoidCurveV0 {
+          max_price,
+          k,
+          midpoint,
+        } => spot_price_sigmoid(
+          &to_prec(max_price),
+          &to_prec(k),
+          &to_prec(midpoint),
+          &ZERO_PREC,
+        ),
+        PrimitiveCurve::PriceTableCurveV0 { ref points } => {
+          price_table_spot(points, price_table_segment(points, &ZERO_PREC)?, &ZERO_PREC)
+        }
+      }
+    } else {
+      match *self {
+        PrimitiveCurve::ExponentialCurveV0 { pow, frac, c, b } => {
+          let pow_prec = PreciseNumber::new(u128::try_from(pow).ok()?)?;
+          let frac_prec = PreciseNumber::new(u128::try_from(frac).ok()?)?;
+          let k = pow_prec.checked_div(&frac_prec)?;
+          let one_plus_k = ONE_PREC.checked_add(&k)?;
+          // R / S, scaled by R'(S) S / R(S)
+          let average = base_amount.checked_div(target_supply)?;
+          if b == 0 && c != 0 {
+            // (1 + k) R / S
+            average.checked_mul(&one_plus_k)
+          } else if c == 0 {
+            Some(average)
+          } else {
+            // (R / S) ((1 + k) + w) / (1 + w), with w = (b (1 + k)) / (c S^k) as in price_exp_with_b
+            let w = exp_with_b_weight(&to_prec(c), &to_prec(b), &k, target_supply)?;
+            average
+              .checked_mul(&one_plus_k.checked_add(&w)?)?
+              .checked_div(&ONE_PREC.checked_add(&w)?)
+          }
+        }
+        PrimitiveCurve::TimeDecayExponentialCurveV0 {
+          d,
+          c: _,
+          k1,
+          k0,
+          interval,
+        } => base_amount
+          .checked_div(target_supply)?
+          .checked_mul(&ONE_PREC.checked_add(&time_decay_k(d, k0, k1, time_offset, interval)?)?),
+        PrimitiveCurve::LogarithmicCurveV0 { c, g, b } => {
+          let (c_prec, g_prec, b_prec) = (to_prec(c), to_prec(g), to_prec(b));
+          spot_price_scaled(
+            |x| reserves_log(&c_prec, &g_prec, &b_prec, x),
+            |x| spot_price_log(&c_prec, &g_prec, &b_prec, x),
+            base_amount,
+            target_supply,
+          )
+        }
+        PrimitiveCurve::SigmoidCurveV0 {
+          max_price,
+          k,
+          midpoint,
+        } => {
+          let (max_price_prec, k_prec, midpoint_prec) =
+            (to_prec(max_price), to_prec(k), to_prec(midpoint));
+          spot_price_scaled(
+            |x| reserves_sigmoid(&max_price_prec, &k_prec, &midpoint_prec, x),
+            |x| spot_price_sigmoid(&max_price_prec, &k_prec, &midpoint_prec, x),
+            base_amount,
+            target_supply,
+          )
+        }
+        PrimitiveCurve::PriceTableCurveV0 { ref points } => spot_price_scaled(
+          |x| price_table_reserves(points, x),
+          |x| price_table_spot(points, price_table_segment(points, x)?, x),
+          base_amount,
+          target_supply,
+        ),
+      }
+    }
+  }
+}
+
+// Each iteration prices the curve once, which costs at least a log and an exp. Seeded from the spot
+// price, buys rarely price the curve more than 20 times. Narrowing that runs out of iterations still
+// returns an amount in the buyer's favour, just a less exact one
+pub const MAX_BRACKET_ITERATIONS: u8 = 16;
+pub const MAX_SOLVER_ITERATIONS: u8 = 16;
+// Brackets widen by this factor, reaching any supply a mint can hold within MAX_BRACKET_ITERATIONS
+const BRACKET_GROWTH: u128 = 8;
+
+/// Numerically inverts `Curve::price` for curves that have no closed form `expected_target_amount`.
+///
+/// Returns the largest amount found whose price is at most `reserve_change`, so the buyer can never receive
+/// more than they paid for. Stops early once the bracket is narrower than a mint can represent.
+pub fn solve_target_amount<C: Curve + ?Sized>(
+  curve: &C,
+  time_offset: i64,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+  reserve_change: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  let price =
+    |amount: &PreciseNumber| curve.price(time_offset, base_amount, target_supply, amount, false);
+
+  /*
+   * Invariant: price(low) <= dR < price(high). Bracket, then narrow.
+   *
+   * Start from dR at the spot price, which is usually close to the root. Start from one token instead
+   * if the curve is flat here, or if the guess is so far out that it cannot be priced. Then step by
+   * BRACKET_GROWTH towards the root until it is bracketed, so narrowing starts from a bracket no wider
+   * than that factor.
+   */
+  let (start, start_price) = match curve
+    .spot_price(time_offset, base_amount, target_supply, false)
+    .filter(|spot_price| spot_price.greater_than(&ZERO_PREC))
+    .and_then(|spot_price| reserve_change.checked_div(&spot_price))
+    .filter(|guess| guess.greater_than(&ZERO_PREC))
+    .and_then(|guess| price(&guess).map(|guess_price| (guess, guess_price)))
+  {
+    Some(seed) => seed,
+    None => (ONE_PREC.clone(), price(&ONE_PREC)?),
+  };
+  let growth = PreciseNumber::new(BRACKET_GROWTH)?;
+  let ((low, low_price), (high, high_price)) = if start_price.less_than_or_equal(reserve_change) {
+    let mut low = (start, start_price);
+    let mut bracket_iterations = 0;
+    loop {
+      let high = low.0.checked_mul(&growth)?;
+      let high_price = price(&high)?;
+      if high_price.greater_than(reserve_change) {
+        break (low, (high, high_price));
+      }
+
+      bracket_iterations += 1;
+      if bracket_iterations >= MAX_BRACKET_ITERATIONS {
+        return None;
+      }
+      low = (high, high_price);
+    }
+  } else {
+    let mut high = (start, start_price);
+    let mut bracket_iterations = 0;
+    loop {
+      // Nothing is free, so this always ends at zero
+      bracket_iterations += 1;
+      let low = if bracket_iterations >= MAX_BRACKET_ITERATIONS {
+        ZERO_PREC.clone()
+      } else {
+        high.0.checked_div(&growth)?
+      };
+      let low_price = if low.eq(&ZERO_PREC) {
+        ZERO_PREC.clone()
+      } else {
+        price(&low)?
+      };
+      if low_price.less_than_or_equal(reserve_change) {
+        break ((low, low_price), high);
+      }
+
+      high = (low, low_price);
+    }
+  };
+  let low_err = reserve_change.checked_sub(&low_price)?; // dR - price(low)
+  let high_err = high_price.checked_sub(reserve_change)?; // price(high) - dR
+
+  narrow_bracket(price, reserve_change, low, low_err, high, high_err).map(|(low, _)| low)
+}
+
+/// Numerically inverts `Curve::price` for sells, finding how much supply must be sold to take
+/// `reserve_change` out of the reserves.
+///
+/// Returns the smallest amount found whose sell price is at least `reserve_change`, so the seller
+/// always receives what they asked for.
+pub fn solve_sell_amount<C: Curve + ?Sized>(
+  curve: &C,
+  time_offset: i64,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+  reserve_change: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  let price =
+    |amount: &PreciseNumber| curve.price(time_offset, base_amount, target_supply, amount, true);
+
+  // Selling the whole supply returns the whole reserves, so [0, S] always brackets a reachable dR
+  let high_price = price(target_supply)?;
+  if high_price.less_than(reserve_change) {
+    return None;
+  }
+
+  narrow_bracket(
+    price,
+    reserve_change,
+    ZERO_PREC.clone(),
+    reserve_change.clone(),
+    target_supply.clone(),
+    high_price.checked_sub(reserve_change)?,
+  )
+  .map(|(_, high)| high)
+}
+
+fn narrow_bracket<P>(
+  price: P,
+  reserve_change: &PreciseNumber,
+  mut low: PreciseNumber,
+  mut low_err: PreciseNumber, // dR - price(low)
+  mut high: PreciseNumber,
+  mut high_err: PreciseNumber, // price(high) - dR
+) -> Option<(PreciseNumber, PreciseNumber)>
+where
+  P: Fn(&PreciseNumber) -> Option<PreciseNumber>,
+{
+  /*
+   * Keep price(low) <= dR <= price(high) while narrowing using false position with the Illinois
+   * modification, so that a stale end of the bracket cannot stall convergence.
+   */
+  let tolerance = PreciseNumber {
+    value: InnerUint::from(SOLVER_TOLERANCE),
+  };
+  let mut last_side_low: Option<bool> = None;
+  let mut nudged = false;
+  for _ in 0..MAX_SOLVER_ITERATIONS {
+    let width = high.checked_sub(&low)?;
+    if width.less_than_or_equal(&tolerance) || low_err.eq(&ZERO_PREC) || high_err.eq(&ZERO_PREC) {
+      break;
+    }
+
+    let interpolated = low.checked_add(
+      &width
+        .checked_mul(&low_err)?
+        .checked_div(&low_err.checked_add(&high_err)?)?,
+    )?;
+    // Once the estimate is within tolerance of one end, whether by convergence or by rounding, the
+    // other end is stale. Step just inside the near end instead, which usually closes the bracket in
+    // one more price. If it does not, bisect before nudging again
+    let near_low = interpolated.less_than(&low.checked_add(&tolerance)?);
+    let near_high = high.checked_sub(&tolerance)?.less_than(&interpolated);
+    let nudge = (near_low || near_high) && !nudged;
+    let mid = if nudge && near_low {
+      low.checked_add(&tolerance)?
+    } else if nudge {
+      high.checked_sub(&tolerance)?
+    } else if interpolated.greater_than(&low) && interpolated.less_than(&high) {
+      interpolated
+    } else {
+      low.checked_add(&width.checked_div(&TWO_PREC)?)?
+    };
+    nudged = nudge;
+
+    let mid_price = price(&mid)?;
+    if mid_price.less_than_or_equal(reserve_change) {
+      low_err = reserve_change.checked_sub(&mid_price)?;
+      low = mid;
+      if last_side_low == Some(true) {
+        high_err = high_err.checked_div(&TWO_PREC)?;
+      }
+      last_side_low = Some(true);
+    } else {
+      high_err = mid_price.checked_sub(reserve_change)?;
+      high = mid;
+      if last_side_low == Some(false) {
+        low_err = low_err.checked_div(&TWO_PREC)?;
+      }
+      last_side_low = Some(false);
+    }
+  }
+
+  // Landed exactly on the root
+  if low_err.eq(&ZERO_PREC) {
+    high = low.clone();
+  }
+
+  Some((low, high))
+}
+
+fn transition_fees(
+  time_offset: i64,
+  reserve_change: &PreciseNumber,
+  curve: &TimeCurveV0,
+  sell: bool,
+) -> Option<PreciseNumber> {
+  let transition_fees_opt = if sell {
+    curve.sell_transition_fees.as_ref()
+  } else {
+    curve.buy_transition_fees.as_ref()
+  };
+
+  if let Some(fees) = transition_fees_opt {
+    let offset_in_current_curve =
+      PreciseNumber::new(u128::try_from(time_offset.checked_sub(curve.offset)?).ok()?)?;
+    let interval = PreciseNumber::new(u128::try_from(fees.interval).ok()?)?;
+    // Decaying percentage. Starts at 100%, works its way down to 0 over the interval. (interval - curr_offset) / interval.
+    // When curr_offset is past interval, checked_sub fails and this is just none
+    let percent_of_fees_opt = interval
+      .checked_sub(&offset_in_current_curve)?
+      .checked_div(&interval);
+    if let Some(percent_of_fees) = percent_of_fees_opt {
+      let percent = get_percent_prec(fees.percentage).ok()?;
 
       return reserve_change
         .checked_mul(&percent)?
@@ -372,6 +1368,231 @@ fn transition_fees_or_zero(
     .unwrap_or(PreciseNumber::new(0).unwrap())
 }
 
+// The tier holding the current supply. When selling, a supply sitting exactly on a boundary belongs to the tier below
+fn supply_tier_index(curves: &[SupplyCurveV0], target_supply: &PreciseNumber, sell: bool) -> usize {
+  curves
+    .iter()
+    .rposition(|c| {
+      let offset = to_prec(c.offset);
+      if sell {
+        offset.less_than(target_supply)
+      } else {
+        offset.less_than_or_equal(target_supply)
+      }
+    })
+    .unwrap_or(0)
+}
+
+// Reserves the curve would hold at the start of each tier up to and including index, if all of the supply
+// below it was bought along the curve
+fn supply_tier_reserves_before(
+  curves: &[SupplyCurveV0],
+  time_offset: i64,
+  index: usize,
+) -> Option<Vec<PreciseNumber>> {
+  let mut reserves_before = vec![ZERO_PREC.clone()];
+  for i in 0..index {
+    let next = supply_tier_next_reserves(curves, time_offset, i, &reserves_before[i])?;
+    reserves_before.push(next);
+  }
+
+  Some(reserves_before)
+}
+
+fn supply_tier_next_reserves(
+  curves: &[SupplyCurveV0],
+  time_offset: i64,
+  index: usize,
+  reserves_before: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  let tier_size =
+    to_prec(curves.get(index + 1)?.offset).checked_sub(&to_prec(curves[index].offset))?;
+  let tier_reserves =
+    curves[index]
+      .curve
+      .price(time_offset, &ZERO_PREC, &ZERO_PREC, &tier_size, false)?;
+
+  reserves_before.checked_add(&tier_reserves)
+}
+
+// Reserves and supply within a tier. Anything the reserves have drifted from the curve is carried by the current tier
+fn supply_tier_local(
+  curves: &[SupplyCurveV0],
+  index: usize,
+  reserves_before: &PreciseNumber,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+) -> Option<(PreciseNumber, PreciseNumber)> {
+  let local_supply = target_supply.checked_sub(&to_prec(curves[index].offset))?;
+  let local_reserves = if base_amount.greater_than(reserves_before) {
+    base_amount.checked_sub(reserves_before)?
+  } else {
+    ZERO_PREC.clone()
+  };
+
+  Some((local_reserves, local_supply))
+}
+
+// Largest gap allowed between the spot price a tier ends at and the price the next tier starts at, as a
+// fraction 1 / SUPPLY_TIER_PRICE_TOLERANCE of the higher price. Leaves room for rounding in the curve math
+pub const SUPPLY_TIER_PRICE_TOLERANCE: u128 = 10_000;
+
+// Spot price at the end of the first tier, when it was bought along the curve, and at the start of the second
+fn supply_tier_boundary_prices(tiers: &[SupplyCurveV0]) -> Option<(PreciseNumber, PreciseNumber)> {
+  let tier_size = to_prec(tiers.get(1)?.offset).checked_sub(&to_prec(tiers[0].offset))?;
+  let tier_reserves = tiers[0]
+    .curve
+    .price(0, &ZERO_PREC, &ZERO_PREC, &tier_size, false)?;
+  let end = tiers[0]
+    .curve
+    .spot_price(0, &tier_reserves, &tier_size, false)?;
+  let start = tiers[1]
+    .curve
+    .spot_price(0, &ZERO_PREC, &ZERO_PREC, false)?;
+
+  Some((end, start))
+}
+
+// Whether every tier ends at the price the next one starts at, so the price never jumps at a boundary.
+// Time dependent tiers are checked at the start of the curve
+pub fn supply_tiers_are_continuous(curves: &[SupplyCurveV0]) -> bool {
+  curves.windows(2).all(|tiers| {
+    supply_tier_boundary_prices(tiers)
+      .and_then(|(end, start)| {
+        let (gap, _) = end.unsigned_sub(&start);
+        let higher = if end.greater_than(&start) { end } else { start };
+        let scaled_gap = gap.checked_mul(&PreciseNumber::new(SUPPLY_TIER_PRICE_TOLERANCE)?)?;
+        // Zero prices leave no relative room, so allow the smallest price the curve can express
+        Some(scaled_gap.less_than_or_equal(&higher) || gap.less_than_or_equal(&to_prec(1)))
+      })
+      .unwrap_or(false)
+  })
+}
+
+fn price_supply(
+  curves: &[SupplyCurveV0],
+  time_offset: i64,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+  amount: &PreciseNumber,
+  sell: bool,
+) -> Option<PreciseNumber> {
+  /*
+   * Walk the tiers the trade passes through, pricing each piece on its own tier.
+   * Buys walk up from the current supply, sells walk down.
+   */
+  let mut index = supply_tier_index(curves, target_supply, sell);
+  let mut reserves_before = supply_tier_reserves_before(curves, time_offset, index)?;
+  let mut reserves = base_amount.clone();
+  let mut supply = target_supply.clone();
+  let mut remaining = amount.clone();
+  let mut total = ZERO_PREC.clone();
+
+  loop {
+    let (local_reserves, local_supply) =
+      supply_tier_local(curves, index, &reserves_before[index], &reserves, &supply)?;
+    let capacity = if sell {
+      Some(local_supply.clone())
+    } else {
+      match curves.get(index + 1) {
+        Some(next) => Some(to_prec(next.offset).checked_sub(&supply)?),
+        None => None,
+      }
+    };
+    let take = match capacity {
+      Some(capacity) if capacity.less_than(&remaining) => capacity,
+      _ => remaining.clone(),
+    };
+
+    let mut tier_price =
+      curves[index]
+        .curve
+        .price(time_offset, &local_reserves, &local_supply, &take, sell)?;
+    // Never pay out more than this tier holds
+    if sell && tier_price.greater_than(&local_reserves) {
+      tier_price = local_reserves;
+    }
+
+    total = total.checked_add(&tier_price)?;
+    remaining = remaining.checked_sub(&take)?;
+    if remaining.eq(&ZERO_PREC) {
+      return Some(total);
+    }
+
+    if sell {
+      reserves = reserves.unsigned_sub(&tier_price).0;
+      supply = supply.checked_sub(&take)?;
+      index = index.checked_sub(1)?;
+    } else {
+      let next_reserves =
+        supply_tier_next_reserves(curves, time_offset, index, &reserves_before[index])?;
+      reserves_before.push(next_reserves);
+      reserves = reserves.checked_add(&tier_price)?;
+      supply = supply.checked_add(&take)?;
+      index += 1;
+    }
+  }
+}
+
+fn expected_target_amount_supply(
+  curves: &[SupplyCurveV0],
+  time_offset: i64,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+  reserve_change: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  // Fill whole tiers while the reserve change covers them, then solve within the tier it runs out in
+  let mut index = supply_tier_index(curves, target_supply, false);
+  let mut reserves_before = supply_tier_reserves_before(curves, time_offset, index)?;
+  let mut reserves = base_amount.clone();
+  let mut supply = target_supply.clone();
+  let mut remaining = reserve_change.clone();
+  let mut total = ZERO_PREC.clone();
+
+  loop {
+    let (local_reserves, local_supply) =
+      supply_tier_local(curves, index, &reserves_before[index], &reserves, &supply)?;
+    let curve = &curves[index].curve;
+    let capacity = match curves.get(index + 1) {
+      Some(next) => Some(to_prec(next.offset).checked_sub(&supply)?),
+      None => None,
+    };
+
+    if let Some(capacity) = &capacity {
+      let tier_price = curve.price(time_offset, &local_reserves, &local_supply, capacity, false)?;
+      if tier_price.less_than_or_equal(&remaining) {
+        total = total.checked_add(capacity)?;
+        remaining = remaining.checked_sub(&tier_price)?;
+        let next_reserves =
+          supply_tier_next_reserves(curves, time_offset, index, &reserves_before[index])?;
+        reserves_before.push(next_reserves);
+        reserves = reserves.checked_add(&tier_price)?;
+        supply = supply.checked_add(capacity)?;
+        index += 1;
+        continue;
+      }
+    }
+
+    let amount = curve
+      .expected_target_amount(time_offset, &local_reserves, &local_supply, &remaining)
+      .or_else(|| {
+        solve_target_amount(
+          curve,
+          time_offset,
+          &local_reserves,
+          &local_supply,
+          &remaining,
+        )
+      })?;
+    let amount = match capacity {
+      Some(capacity) if capacity.less_than(&amount) => capacity,
+      _ => amount,
+    };
+
+    return total.checked_add(&amount);
+  }
+}
+
 impl Curve for PiecewiseCurve {
   fn price(
     &self,
@@ -404,6 +1625,14 @@ impl Curve for PiecewiseCurve {
           }
         })
       }
+      PiecewiseCurve::SupplyV0 { curves } => price_supply(
+        curves,
+        time_offset,
+        base_amount,
+        target_supply,
+        amount,
+        sell,
+      ),
     }
   }
 
@@ -427,6 +1656,937 @@ impl Curve for PiecewiseCurve {
           &reserve_change.checked_sub(&fees)?,
         )
       }
+      PiecewiseCurve::SupplyV0 { curves } => expected_target_amount_supply(
+        curves,
+        time_offset,
+        base_amount,
+        target_supply,
+        reserve_change,
+      ),
+    }
+  }
+
+  fn spot_price(
+    &self,
+    time_offset: i64,
+    base_amount: &PreciseNumber,
+    target_supply: &PreciseNumber,
+    sell: bool,
+  ) -> Option<PreciseNumber> {
+    match self {
+      PiecewiseCurve::TimeV0 { curves } => {
+        let curve = curves.iter().rev().find(|c| c.offset <= time_offset)?;
+
+        let spot_price =
+          curve
+            .curve
+            .spot_price(time_offset - curve.offset, base_amount, target_supply, sell)?;
+
+        // Transition fees are a percentage of the price, so they apply to the marginal price too
+        let fees = transition_fees_or_zero(time_offset, &spot_price, curve, sell);
+        if sell {
+          spot_price.checked_sub(&fees)
+        } else {
+          spot_price.checked_add(&fees)
+        }
+      }
+      PiecewiseCurve::SupplyV0 { curves } => {
+        let index = supply_tier_index(curves, target_supply, sell);
+        let reserves_before = supply_tier_reserves_before(curves, time_offset, index)?;
+        let (local_reserves, local_supply) = supply_tier_local(
+          curves,
+          index,
+          &reserves_before[index],
+          base_amount,
+          target_supply,
+        )?;
+
+        curves[index]
+          .curve
+          .spot_price(time_offset, &local_reserves, &local_supply, sell)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::state::{SupplyCurveV0, TimeCurveV0, TransitionFeeV0};
+  use proptest::prelude::*;
+
+  fn exp_curve_with_b() -> PrimitiveCurve {
+    PrimitiveCurve::ExponentialCurveV0 {
+      c: 1_000_000_000_000, // c = 1
+      b: 500_000_000_000,   // b = 0.5
+      pow: 1,
+      frac: 2,
+    }
+  }
+
+  fn decimal(numerator: u128, denominator: u128) -> PreciseNumber {
+    PreciseNumber::new(numerator)
+      .unwrap()
+      .checked_div(&PreciseNumber::new(denominator).unwrap())
+      .unwrap()
+  }
+
+  #[test]
+  fn test_exp_with_b_initial() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = exp_curve_with_b();
+    let amount = PreciseNumber::new(100).unwrap();
+
+    // 0.5 * 100 + 100^1.5 / 1.5
+    let price = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &amount, false)
+      .unwrap();
+    assert!(price.almost_eq(&decimal(2150, 3), precision));
+
+    let target = curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &price)
+      .unwrap();
+    assert!(target.almost_eq(&amount, precision));
+  }
+
+  #[test]
+  fn test_exp_with_b() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = exp_curve_with_b();
+    let supply = PreciseNumber::new(100).unwrap();
+    let reserves = decimal(2150, 3);
+    let amount = PreciseNumber::new(50).unwrap();
+
+    // (0.5 * 150 + 150^1.5 / 1.5) - (0.5 * 100 + 100^1.5 / 1.5)
+    let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+    let expected = decimal(1_299_744_871_391_589, 1_000_000_000_000)
+      .checked_sub(&reserves)
+      .unwrap();
+    assert!(price.almost_eq(&expected, precision));
+
+    let target = curve
+      .expected_target_amount(0, &reserves, &supply, &price)
+      .unwrap();
+    assert!(target.almost_eq(&amount, precision));
+
+    // Selling the same amount back off of the new supply returns the same reserves
+    let new_reserves = reserves.checked_add(&price).unwrap();
+    let new_supply = supply.checked_add(&amount).unwrap();
+    let reclaimed = curve
+      .price(0, &new_reserves, &new_supply, &amount, true)
+      .unwrap();
+    assert!(reclaimed.almost_eq(&price, precision));
+  }
+
+  #[test]
+  fn test_exp_with_b_scales_to_reserves() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = exp_curve_with_b();
+    let supply = PreciseNumber::new(100).unwrap();
+    // Twice the reserves the curve would have at this supply doubles the price
+    let reserves = decimal(4300, 3);
+    let amount = PreciseNumber::new(50).unwrap();
+
+    let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+    let expected = decimal(2_599_489_742_783_178, 1_000_000_000_000)
+      .checked_sub(&reserves)
+      .unwrap();
+    assert!(price.almost_eq(&expected, precision));
+
+    let target = curve
+      .expected_target_amount(0, &reserves, &supply, &price)
+      .unwrap();
+    assert!(target.almost_eq(&amount, precision));
+  }
+
+  proptest! {
+    #[test]
+    fn test_exp_with_b_round_trip(
+      c in 1..10_000_u128,
+      b in 1..10_000_u128,
+      pow in 0..4_u8,
+      frac in 1..4_u8,
+      reserves in 1..1_000_000_u128,
+      supply in 1..1_000_000_u128,
+      amount in 1..1_000_000_u128,
+    ) {
+      let curve = PrimitiveCurve::ExponentialCurveV0 {
+        c: c * 1_000_000_000, // Up to c = 10
+        b: b * 1_000_000_000, // Up to b = 10
+        pow,
+        frac,
+      };
+      let reserves = PreciseNumber::new(reserves).unwrap();
+      let supply = PreciseNumber::new(supply).unwrap();
+      let amount = decimal(amount, 1_000);
+
+      let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+      let target = curve
+        .expected_target_amount(0, &reserves, &supply, &price)
+        .unwrap();
+      // Correct to 6 decimal places relative to the amount purchased
+      let precision = amount.value / 1_000_000;
+      prop_assert!(target.almost_eq(&amount, precision));
+    }
+  }
+
+  fn time_curve(curve: PrimitiveCurve) -> PiecewiseCurve {
+    PiecewiseCurve::TimeV0 {
+      curves: vec![TimeCurveV0 {
+        offset: 0,
+        curve,
+        buy_transition_fees: None,
+        sell_transition_fees: None,
+      }],
+    }
+  }
+
+  #[test]
+  fn test_solver_matches_closed_form() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = time_curve(PrimitiveCurve::ExponentialCurveV0 {
+      c: 1_000_000_000_000, // c = 1
+      b: 0,
+      pow: 1,
+      frac: 2,
+    });
+    let reserves = decimal(2000, 3);
+    let supply = PreciseNumber::new(100).unwrap();
+    let reserve_change = PreciseNumber::new(250).unwrap();
+
+    let closed_form = curve
+      .expected_target_amount(0, &reserves, &supply, &reserve_change)
+      .unwrap();
+    let solved = solve_target_amount(&curve, 0, &reserves, &supply, &reserve_change).unwrap();
+    assert!(solved.almost_eq(&closed_form, precision));
+  }
+
+  proptest! {
+    #[test]
+    fn test_solver_never_overpays(
+      c in 0..10_000_u128,
+      b in 0..10_000_u128,
+      pow in 0..4_u8,
+      frac in 1..4_u8,
+      reserves in 0..1_000_000_u128,
+      supply in 0..1_000_000_u128,
+      reserve_change in 1..1_000_000_000_u128,
+    ) {
+      prop_assume!(c > 0 || b > 0);
+      let curve = time_curve(PrimitiveCurve::ExponentialCurveV0 {
+        c: c * 1_000_000_000, // Up to c = 10
+        b: b * 1_000_000_000, // Up to b = 10
+        pow,
+        frac,
+      });
+      let (reserves, supply) = if reserves == 0 || supply == 0 {
+        (ZERO_PREC.clone(), ZERO_PREC.clone())
+      } else {
+        (PreciseNumber::new(reserves).unwrap(), PreciseNumber::new(supply).unwrap())
+      };
+      let reserve_change = decimal(reserve_change, 1_000);
+
+      let amount = solve_target_amount(&curve, 0, &reserves, &supply, &reserve_change).unwrap();
+      let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+      prop_assert!(price.less_than_or_equal(&reserve_change));
+
+      // And it does not leave more than a rounding error on the table
+      let slightly_more = amount.checked_add(&decimal(1, 1_000_000)).unwrap();
+      let slightly_more_price = curve.price(0, &reserves, &supply, &slightly_more, false).unwrap();
+      prop_assert!(slightly_more_price.greater_than(&reserve_change));
+    }
+  }
+
+  // Counts how many times the solver prices the curve, each of which costs logs and exps on chain
+  struct CountingCurve {
+    curve: PrimitiveCurve,
+    prices: std::cell::Cell<u32>,
+  }
+
+  impl Curve for CountingCurve {
+    fn price(
+      &self,
+      time_offset: i64,
+      base_amount: &PreciseNumber,
+      target_supply: &PreciseNumber,
+      amount: &PreciseNumber,
+      sell: bool,
+    ) -> Option<PreciseNumber> {
+      self.prices.set(self.prices.get() + 1);
+      self
+        .curve
+        .price(time_offset, base_amount, target_supply, amount, sell)
+    }
+
+    fn expected_target_amount(
+      &self,
+      _time_offset: i64,
+      _base_amount: &PreciseNumber,
+      _target_supply: &PreciseNumber,
+      _reserve_change: &PreciseNumber,
+    ) -> Option<PreciseNumber> {
+      None
+    }
+
+    fn spot_price(
+      &self,
+      time_offset: i64,
+      base_amount: &PreciseNumber,
+      target_supply: &PreciseNumber,
+      sell: bool,
+    ) -> Option<PreciseNumber> {
+      self
+        .curve
+        .spot_price(time_offset, base_amount, target_supply, sell)
+    }
+  }
+
+  #[test]
+  fn test_solver_price_evaluations() {
+    // Supply and reserve change in thousandths of a token
+    let cases = [
+      // Far out along a log curve, where narrowing used to run into price rounding
+      (
+        PrimitiveCurve::LogarithmicCurveV0 {
+          c: 92_855_000_000,
+          g: 347_006_000_000,
+          b: 343_816_000_000,
+        },
+        613_358,
+        118_440_888,
+      ),
+      // Steep enough that the spot price guess is far past the root
+      (
+        PrimitiveCurve::ExponentialCurveV0 {
+          c: 1_000_000_000,
+          b: 0,
+          pow: 2,
+          frac: 1,
+        },
+        1_000,
+        964_020,
+      ),
+      // Flat until a midpoint far from one token
+      (
+        PrimitiveCurve::SigmoidCurveV0 {
+          max_price: 26_936_000_000,
+          k: 890_247_000_000,
+          midpoint: 932_792_000_000_000_000,
+        },
+        0,
+        569_739_532,
+      ),
+    ];
+
+    for (curve, supply, reserve_change) in cases {
+      let supply = decimal(supply, 1_000);
+      let reserves = curve
+        .price(0, &ZERO_PREC, &ZERO_PREC, &supply, false)
+        .unwrap();
+      let reserve_change = decimal(reserve_change, 1_000);
+      let counting = CountingCurve {
+        curve,
+        prices: std::cell::Cell::new(0),
+      };
+
+      let amount = solve_target_amount(&counting, 0, &reserves, &supply, &reserve_change).unwrap();
+      assert!(counting.prices.get() <= 20);
+      let price = counting
+        .curve
+        .price(0, &reserves, &supply, &amount, false)
+        .unwrap();
+      assert!(price.less_than_or_equal(&reserve_change));
+    }
+  }
+
+  // Flat price of 1 for the first 10 tokens, then 1 + x
+  fn tiered_curve() -> PiecewiseCurve {
+    PiecewiseCurve::SupplyV0 {
+      curves: vec![
+        SupplyCurveV0 {
+          offset: 0,
+          curve: PrimitiveCurve::ExponentialCurveV0 {
+            c: 0,
+            b: 1_000_000_000_000,
+            pow: 0,
+            frac: 1,
+          },
+        },
+        SupplyCurveV0 {
+          offset: 10_000_000_000_000,
+          curve: PrimitiveCurve::ExponentialCurveV0 {
+            c: 1_000_000_000_000,
+            b: 1_000_000_000_000,
+            pow: 1,
+            frac: 1,
+          },
+        },
+      ],
+    }
+  }
+
+  #[test]
+  fn test_supply_curve_buy_across_tiers() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = tiered_curve();
+
+    // 10 tokens at 1, then 10 + 10^2 / 2 for the next 10
+    let amount = PreciseNumber::new(20).unwrap();
+    let price = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &amount, false)
+      .unwrap();
+    assert!(price.almost_eq(&PreciseNumber::new(70).unwrap(), precision));
+    let target = curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &price)
+      .unwrap();
+    assert!(target.almost_eq(&amount, precision));
+
+    // From the middle of the flat tier. 5 tokens at 1, then 5 + 5^2 / 2
+    let reserves = PreciseNumber::new(5).unwrap();
+    let supply = PreciseNumber::new(5).unwrap();
+    let amount = PreciseNumber::new(10).unwrap();
+    let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+    assert!(price.almost_eq(&decimal(225, 10), precision));
+    let target = curve
+      .expected_target_amount(0, &reserves, &supply, &price)
+      .unwrap();
+    assert!(target.almost_eq(&amount, precision));
+
+    // Within the flat tier
+    let target = curve
+      .expected_target_amount(0, &reserves, &supply, &PreciseNumber::new(3).unwrap())
+      .unwrap();
+    assert!(target.almost_eq(&PreciseNumber::new(3).unwrap(), precision));
+  }
+
+  #[test]
+  fn test_supply_curve_sell_across_tiers() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = tiered_curve();
+    let reserves = PreciseNumber::new(70).unwrap();
+    let supply = PreciseNumber::new(20).unwrap();
+
+    // All 60 held by the second tier, then 5 of the 10 held by the flat tier
+    let reclaimed = curve
+      .price(
+        0,
+        &reserves,
+        &supply,
+        &PreciseNumber::new(15).unwrap(),
+        true,
+      )
+      .unwrap();
+    assert!(reclaimed.almost_eq(&PreciseNumber::new(65).unwrap(), precision));
+
+    // Selling everything returns all of the reserves
+    let reclaimed = curve.price(0, &reserves, &supply, &supply, true).unwrap();
+    assert!(reclaimed.almost_eq(&reserves, precision));
+
+    // Cannot sell more than the supply
+    assert!(curve
+      .price(
+        0,
+        &reserves,
+        &supply,
+        &PreciseNumber::new(21).unwrap(),
+        true
+      )
+      .is_none());
+  }
+
+  fn supply_tiers(curve: PiecewiseCurve) -> Vec<SupplyCurveV0> {
+    match curve {
+      PiecewiseCurve::SupplyV0 { curves } => curves,
+      _ => unreachable!(),
+    }
+  }
+
+  fn flat(price: u128) -> PrimitiveCurve {
+    PrimitiveCurve::ExponentialCurveV0 {
+      c: 0,
+      b: price,
+      pow: 0,
+      frac: 1,
+    }
+  }
+
+  #[test]
+  fn test_supply_tiers_must_be_continuous() {
+    let tiers = supply_tiers(tiered_curve());
+    assert!(supply_tiers_are_continuous(&tiers));
+
+    // The second tier starting at 2 jumps from the flat price of 1
+    let mut jump = tiers.clone();
+    jump[1].curve = PrimitiveCurve::ExponentialCurveV0 {
+      c: 1_000_000_000_000,
+      b: 2_000_000_000_000,
+      pow: 1,
+      frac: 1,
+    };
+    assert!(!supply_tiers_are_continuous(&jump));
+
+    // x over the first 10 tokens ends at a price of 10
+    let rising = |next: PrimitiveCurve| {
+      vec![
+        SupplyCurveV0 {
+          offset: 0,
+          curve: PrimitiveCurve::ExponentialCurveV0 {
+            c: 1_000_000_000_000,
+            b: 0,
+            pow: 1,
+            frac: 1,
+          },
+        },
+        SupplyCurveV0 {
+          offset: 10_000_000_000_000,
+          curve: next,
+        },
+      ]
+    };
+    assert!(supply_tiers_are_continuous(&rising(flat(
+      10_000_000_000_000
+    ))));
+    // Within the tolerance
+    assert!(supply_tiers_are_continuous(&rising(flat(
+      10_000_100_000_000
+    ))));
+    assert!(!supply_tiers_are_continuous(&rising(flat(
+      10_010_000_000_000
+    ))));
+    assert!(!supply_tiers_are_continuous(&rising(flat(
+      9_990_000_000_000
+    ))));
+
+    // The price table falls back to 1 by the end of its tier
+    let table = vec![
+      SupplyCurveV0 {
+        offset: 0,
+        curve: price_table(),
+      },
+      SupplyCurveV0 {
+        offset: 20_000_000_000_000,
+        curve: flat(1_000_000_000_000),
+      },
+    ];
+    assert!(supply_tiers_are_continuous(&table));
+    let mut table_jump = table;
+    table_jump[1].curve = flat(3_000_000_000_000);
+    assert!(!supply_tiers_are_continuous(&table_jump));
+  }
+
+  #[test]
+  fn test_supply_curve_boundary() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = tiered_curve();
+    let reserves = PreciseNumber::new(10).unwrap();
+    let supply = PreciseNumber::new(10).unwrap();
+    let amount = PreciseNumber::new(2).unwrap();
+
+    // Buying from the boundary is priced on the upper tier, 2 + 2^2 / 2
+    let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+    assert!(price.almost_eq(&PreciseNumber::new(4).unwrap(), precision));
+
+    // Selling from the boundary is priced on the lower tier
+    let reclaimed = curve.price(0, &reserves, &supply, &amount, true).unwrap();
+    assert!(reclaimed.almost_eq(&PreciseNumber::new(2).unwrap(), precision));
+  }
+
+  #[test]
+  fn test_log_initial() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+                                                         // ln(1 + x)
+    let curve = PrimitiveCurve::LogarithmicCurveV0 {
+      c: 1_000_000_000_000,
+      g: 1_000_000_000_000,
+      b: 0,
+    };
+
+    // (1 + x) ln(1 + x) - x at x = 1 is 2 ln(2) - 1
+    let price = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &ONE_PREC, false)
+      .unwrap();
+    assert!(price.almost_eq(
+      &decimal(386294361119890_u128, 1_000_000_000_000_000),
+      precision
+    ));
+
+    let amount = curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &price)
+      .unwrap();
+    assert!(amount.almost_eq(&ONE_PREC, precision));
+  }
+
+  #[test]
+  fn test_sigmoid_initial() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+                                                         // 2 / (1 + e^-x)
+    let curve = PrimitiveCurve::SigmoidCurveV0 {
+      max_price: 2_000_000_000_000,
+      k: 1_000_000_000_000,
+      midpoint: 0,
+    };
+
+    // 2 (ln(1 + e) - ln(2))
+    let price = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &ONE_PREC, false)
+      .unwrap();
+    assert!(price.almost_eq(
+      &decimal(1240229013916540_u128, 1_000_000_000_000_000),
+      precision
+    ));
+
+    let amount = curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &price)
+      .unwrap();
+    assert!(amount.almost_eq(&ONE_PREC, precision));
+  }
+
+  #[test]
+  fn test_sigmoid_levels_off() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = PrimitiveCurve::SigmoidCurveV0 {
+      max_price: 2_000_000_000_000,
+      k: 1_000_000_000_000,
+      midpoint: 10_000_000_000_000,
+    };
+    let supply = PreciseNumber::new(100).unwrap();
+    let reserves = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &supply, false)
+      .unwrap();
+
+    // Well past the midpoint every token costs max_price
+    let price = curve
+      .price(0, &reserves, &supply, &ONE_PREC, false)
+      .unwrap();
+    assert!(price.almost_eq(&TWO_PREC, precision));
+  }
+
+  #[test]
+  fn test_exp_with_b_falls_back_when_newton_does_not_settle() {
+    // Flat and far out enough that rounding in pow keeps the steps from settling within
+    // MAX_NEWTON_ITERATIONS
+    let k = decimal(2, 10);
+    let b = to_prec(1_000_000);
+    let target = PreciseNumber::new(1_000_000_000_000).unwrap();
+    assert!(solve_exp_with_b(&ONE_PREC, &b, &k, &target).is_none());
+
+    let curve = PrimitiveCurve::ExponentialCurveV0 {
+      c: 1_200_000_000_000, // 1 + k, so dR = dS^(1 + k) + b dS
+      b: 1_000_000,
+      pow: 2,
+      frac: 10,
+    };
+    assert!(curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &target)
+      .is_none());
+
+    let amount = solve_target_amount(&curve, 0, &ZERO_PREC, &ZERO_PREC, &target).unwrap();
+    let price = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &amount, false)
+      .unwrap();
+    assert!(price.less_than_or_equal(&target));
+    assert!(price.almost_eq(&target, ONE_PREC.value));
+  }
+
+  #[test]
+  fn test_sigmoid_round_trip_from_flat_start() {
+    // Reserves are a few units of precision, and the tangent at the supply brackets far past the
+    // midpoint
+    for (supply, amount) in [(841_502, 1), (842_042, 69_884)] {
+      let curve = PrimitiveCurve::SigmoidCurveV0 {
+        max_price: 577_341_000_000,
+        k: 586_389_000_000,
+        midpoint: 911_000_000_000_000,
+      };
+      check_round_trip(&curve, supply, amount);
+    }
+  }
+
+  proptest! {
+    #[test]
+    fn test_log_round_trip(
+      c in 1_u128..1_000_000,
+      g in 1_u128..1_000_000,
+      b in 0_u128..1_000_000,
+      supply in 1_u128..1_000_000,
+      amount in 1_u128..1_000_000,
+    ) {
+      let curve = PrimitiveCurve::LogarithmicCurveV0 {
+        c: c * 1_000_000,
+        g: g * 1_000_000,
+        b: b * 1_000_000,
+      };
+      check_round_trip(&curve, supply, amount);
+    }
+
+    #[test]
+    fn test_sigmoid_round_trip(
+      max_price in 1_u128..1_000_000,
+      k in 1_u128..1_000_000,
+      midpoint in 0_u128..1_000_000,
+      supply in 1_u128..1_000_000,
+      amount in 1_u128..1_000_000,
+    ) {
+      let curve = PrimitiveCurve::SigmoidCurveV0 {
+        max_price: max_price * 1_000_000,
+        k: k * 1_000_000,
+        midpoint: midpoint * 1_000_000_000_000,
+      };
+      check_round_trip(&curve, supply, amount);
+    }
+  }
+
+  // Supply and amount in thousandths of a token, params in millionths
+  fn check_round_trip(curve: &PrimitiveCurve, supply: u128, amount: u128) {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let supply = decimal(supply, 1_000);
+    let amount = decimal(amount, 1_000);
+    let reserves = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &supply, false)
+      .unwrap();
+
+    // Curves flat enough to round the reserves to zero are priced from scratch instead
+    if reserves.eq(&ZERO_PREC) {
+      return;
+    }
+
+    let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+    // Buying from the curve is the same as buying the first supply + amount tokens
+    let total = curve
+      .price(
+        0,
+        &ZERO_PREC,
+        &ZERO_PREC,
+        &supply.checked_add(&amount).unwrap(),
+        false,
+      )
+      .unwrap();
+    assert!(reserves
+      .checked_add(&price)
+      .unwrap()
+      .almost_eq(&total, precision));
+
+    // Spending the price buys back the amount. Where the curve is too flat to tell amounts apart, at least
+    // the buyer is charged the same
+    if let Some(target) = curve.expected_target_amount(0, &reserves, &supply, &price) {
+      let paid = curve.price(0, &reserves, &supply, &target, false).unwrap();
+      assert!(paid.almost_eq(&price, precision));
+    }
+
+    // Selling it back returns what was paid
+    let new_reserves = reserves.checked_add(&price).unwrap();
+    let new_supply = supply.checked_add(&amount).unwrap();
+    let reclaimed = curve
+      .price(0, &new_reserves, &new_supply, &amount, true)
+      .unwrap();
+    assert!(reclaimed.almost_eq(&price, precision));
+  }
+
+  // Rises from 1 to 3 over the first 10 tokens, falls back to 1 over the next 10, then stays flat
+  fn price_table() -> PrimitiveCurve {
+    let point = |supply: u128, price: u128| PricePointV0 {
+      supply: supply * 1_000_000_000_000,
+      price: price * 1_000_000_000_000,
+    };
+    PrimitiveCurve::PriceTableCurveV0 {
+      points: vec![point(0, 1), point(10, 3), point(20, 1)],
+    }
+  }
+
+  #[test]
+  fn test_price_table_initial() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = price_table();
+
+    // 20 for the rising segment, then 5 (3 + 2) / 2
+    let amount = PreciseNumber::new(15).unwrap();
+    let price = curve
+      .price(0, &ZERO_PREC, &ZERO_PREC, &amount, false)
+      .unwrap();
+    assert!(price.almost_eq(&decimal(325, 10), precision));
+    let target = curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &price)
+      .unwrap();
+    assert!(target.almost_eq(&amount, precision));
+
+    // dS + dS^2 / 10 = 10
+    let target = curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &PreciseNumber::new(10).unwrap())
+      .unwrap();
+    assert!(target.almost_eq(&decimal(61803398875, 10_000_000_000), precision));
+
+    // 40 for the table, then 5 at the flat price of 1
+    let target = curve
+      .expected_target_amount(0, &ZERO_PREC, &ZERO_PREC, &PreciseNumber::new(45).unwrap())
+      .unwrap();
+    assert!(target.almost_eq(&PreciseNumber::new(25).unwrap(), precision));
+  }
+
+  #[test]
+  fn test_price_table() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = price_table();
+
+    let reserves = PreciseNumber::new(20).unwrap();
+    let supply = PreciseNumber::new(10).unwrap();
+    let amount = PreciseNumber::new(5).unwrap();
+    let price = curve.price(0, &reserves, &supply, &amount, false).unwrap();
+    assert!(price.almost_eq(&decimal(125, 10), precision));
+    let target = curve
+      .expected_target_amount(0, &reserves, &supply, &price)
+      .unwrap();
+    assert!(target.almost_eq(&amount, precision));
+
+    // Sell back down to 5 from 30, 50 - 5 (1 + 2) / 2
+    let reclaimed = curve
+      .price(
+        0,
+        &PreciseNumber::new(50).unwrap(),
+        &PreciseNumber::new(30).unwrap(),
+        &PreciseNumber::new(25).unwrap(),
+        true,
+      )
+      .unwrap();
+    assert!(reclaimed.almost_eq(&decimal(425, 10), precision));
+  }
+
+  #[test]
+  fn test_spot_price_matches_small_trades() {
+    let precision = InnerUint::from(10_000_000_000_000_u128); // correct to at least 5 decimal places
+    let curves = vec![
+      exp_curve_with_b(),
+      PrimitiveCurve::ExponentialCurveV0 {
+        c: 1_000_000_000_000,
+        b: 0,
+        pow: 1,
+        frac: 2,
+      },
+      PrimitiveCurve::ExponentialCurveV0 {
+        c: 0,
+        b: 2_000_000_000_000,
+        pow: 0,
+        frac: 1,
+      },
+      PrimitiveCurve::LogarithmicCurveV0 {
+        c: 1_000_000_000_000,
+        g: 1_000_000_000_000,
+        b: 500_000_000_000,
+      },
+      PrimitiveCurve::SigmoidCurveV0 {
+        max_price: 2_000_000_000_000,
+        k: 1_000_000_000_000,
+        midpoint: 5_000_000_000_000,
+      },
+      price_table(),
+    ];
+    let supply = PreciseNumber::new(7).unwrap();
+    let amount = decimal(1, 10_000_000);
+
+    for curve in curves {
+      let reserves = curve
+        .price(0, &ZERO_PREC, &ZERO_PREC, &supply, false)
+        .unwrap();
+      for sell in [false, true] {
+        let spot_price = curve.spot_price(0, &reserves, &supply, sell).unwrap();
+        let average_price = curve
+          .price(0, &reserves, &supply, &amount, sell)
+          .unwrap()
+          .checked_div(&amount)
+          .unwrap();
+        assert!(spot_price.almost_eq(&average_price, precision));
+      }
     }
   }
+
+  #[test]
+  fn test_spot_price_initial() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let spot_price = |curve: &PrimitiveCurve| curve.spot_price(0, &ZERO_PREC, &ZERO_PREC, false);
+
+    assert!(spot_price(&exp_curve_with_b())
+      .unwrap()
+      .almost_eq(&decimal(1, 2), precision));
+    assert!(spot_price(&price_table())
+      .unwrap()
+      .almost_eq(&ONE_PREC, precision));
+    // 2 / (1 + e^0)
+    assert!(spot_price(&PrimitiveCurve::SigmoidCurveV0 {
+      max_price: 2_000_000_000_000,
+      k: 1_000_000_000_000,
+      midpoint: 0,
+    })
+    .unwrap()
+    .almost_eq(&ONE_PREC, precision));
+  }
+
+  #[test]
+  fn test_spot_price_includes_transition_fees() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = PiecewiseCurve::TimeV0 {
+      curves: vec![TimeCurveV0 {
+        offset: 0,
+        curve: exp_curve_with_b(),
+        buy_transition_fees: Some(TransitionFeeV0 {
+          percentage: u32::MAX / 10,
+          interval: 100,
+        }),
+        sell_transition_fees: None,
+      }],
+    };
+    let supply = PreciseNumber::new(4).unwrap();
+    let reserves = exp_curve_with_b()
+      .price(0, &ZERO_PREC, &ZERO_PREC, &supply, false)
+      .unwrap();
+    // 0.5 + 4^0.5
+    let spot_price = decimal(5, 2);
+
+    // Halfway through the interval, so half of the 10% fee
+    let buy = curve.spot_price(50, &reserves, &supply, false).unwrap();
+    assert!(buy.almost_eq(
+      &spot_price.checked_mul(&decimal(105, 100)).unwrap(),
+      precision
+    ));
+    let sell = curve.spot_price(50, &reserves, &supply, true).unwrap();
+    assert!(sell.almost_eq(&spot_price, precision));
+    let buy = curve.spot_price(100, &reserves, &supply, false).unwrap();
+    assert!(buy.almost_eq(&spot_price, precision));
+  }
+
+  #[test]
+  fn test_supply_curve_spot_price() {
+    let precision = InnerUint::from(1_000_000_000_u128); // correct to at least 9 decimal places
+    let curve = tiered_curve();
+    let ten = PreciseNumber::new(10).unwrap();
+
+    let spot_price = curve
+      .spot_price(
+        0,
+        &PreciseNumber::new(5).unwrap(),
+        &PreciseNumber::new(5).unwrap(),
+        false,
+      )
+      .unwrap();
+    assert!(spot_price.almost_eq(&ONE_PREC, precision));
+
+    // On the boundary buys price from the upper tier and sells from the lower, both 1 here
+    let buy = curve.spot_price(0, &ten, &ten, false).unwrap();
+    assert!(buy.almost_eq(&ONE_PREC, precision));
+    let sell = curve.spot_price(0, &ten, &ten, true).unwrap();
+    assert!(sell.almost_eq(&ONE_PREC, precision));
+
+    // 5 tokens into the second tier, 1 + 5
+    let reserves = curve
+      .price(
+        0,
+        &ZERO_PREC,
+        &ZERO_PREC,
+        &PreciseNumber::new(15).unwrap(),
+        false,
+      )
+      .unwrap();
+    let spot_price = curve
+      .spot_price(0, &reserves, &PreciseNumber::new(15).unwrap(), false)
+      .unwrap();
+    assert!(spot_price.almost_eq(&PreciseNumber::new(6).unwrap(), precision));
+  }
 }
@@ -55,4 +55,114 @@ pub enum ErrorCode {
 
   #[msg("Cannot pad token bonding without ignoring external reserve and supply changes. This is an advanced feature, incorrect use could lead to insufficient resreves to cover sells")]
   InvalidPad,
+
+  #[msg("Curve account is too small to hold the curve definition")]
+  CurveAccountTooSmall,
+
+  #[msg("Selling for that base amount would sell more than the maximum target amount. Try increasing maximum_target_amount or slippage configuration")]
+  SellAmountTooHigh,
+
+  #[msg("Route accounts do not form a valid path through the provided token bondings")]
+  InvalidRoute,
+
+  #[msg("Route output was lower than the minimum output amount. Try decreasing minimum_output_amount or increasing slippage configuration")]
+  RouteOutputTooLow,
+
+  #[msg("This token bonding has a per wallet purchase cap. Pass the buyer's wallet purchase account as the first remaining account")]
+  WalletPurchaseRequired,
+
+  #[msg("Cannot purchase that many tokens because of the per wallet purchase cap")]
+  OverWalletPurchaseCap,
+
+  #[msg("Buying is limited to an allowlist right now. Provide a valid allowlist proof for the buyer's wallet")]
+  NotAllowlisted,
+
+  #[msg("Buyer does not hold enough of the token or NFT collection gating this bonding curve. Pass the holding accounts as remaining accounts")]
+  BuyGateNotMet,
+
+  #[msg("Metadata account is not the Metaplex metadata for the provided mint")]
+  InvalidMetadata,
+
+  #[msg("Royalty vesting has already been set up for this token bonding")]
+  RoyaltyVestingAlreadySet,
+
+  #[msg("Vesting cliff must be between zero and the vesting duration")]
+  InvalidVestingSchedule,
+
+  #[msg("This token bonding does not vest royalties")]
+  NoRoyaltyVesting,
+
+  #[msg("Price impact fees must have min impact below max impact")]
+  InvalidPriceImpactFees,
+
+  #[msg("Royalty splits need 1 to 16 distinct recipients with positive shares adding up to 10000 basis points")]
+  InvalidRoyaltySplit,
+
+  #[msg("Account is not a recipient of this royalty split")]
+  UnknownRoyaltyRecipient,
+
+  #[msg("Curve update delay cannot be negative, and once set can only be raised")]
+  InvalidCurveUpdateDelay,
+
+  #[msg("This curve update is timelocked. Propose it with propose_curve_update_v0 and apply it once the delay has passed")]
+  CurveUpdateTimelocked,
+
+  #[msg("There is no pending curve update for this curve")]
+  NoPendingCurveUpdate,
+
+  #[msg("Royalties on this token bonding are locked and can never change")]
+  RoyaltiesLocked,
+
+  #[msg("The curve on this token bonding is locked and can never change")]
+  CurveLocked,
+
+  #[msg("Royalty change limits need a positive period, and once set can only be tightened")]
+  InvalidRoyaltyChangeLimits,
+
+  #[msg("Royalties cannot rise that much this period or above the maximum royalty percentage")]
+  RoyaltyChangeTooLarge,
+
+  #[msg(
+    "This token bonding is winding down. Reserves are reserved for holders redeeming their tokens"
+  )]
+  WindingDown,
+
+  #[msg("Price oracle has no observation old enough for this window")]
+  PriceOracleWindowTooLong,
+
+  #[msg("Price oracle does not belong to this token bonding")]
+  InvalidPriceOracle,
+
+  #[msg("Stake pool is not owned by the stake pool program, or does not match reserve staking")]
+  InvalidStakePool,
+
+  #[msg("Stake pool has not been updated this epoch")]
+  StakePoolNotUpdated,
+
+  #[msg("Staked reserves have not earned any yield to claim")]
+  NoReserveYield,
+
+  #[msg("Base storage would not cover buying back the outstanding supply on the curve")]
+  ReservesInsolvent,
+
+  #[msg("Reserve staking can stake at most MAX_STAKED_PERCENTAGE of wrapped sol")]
+  InvalidMaxStakedPercentage,
+
+  #[msg("Not enough unstaked reserves. Pass the reserve staking accounts so the sell can unstake, or run rebalance_reserve_staking_v0")]
+  ReservesNotLiquid,
+
+  #[msg("Reserve transfers on bondings that ignore external changes or are winding down are only supported on v1 of the transfer reserves endpoints. Please upgrade your client")]
+  TransferReservesV1Only,
+
+  #[msg("Closing a wound down bonding with reserves left needs a base mint token account of the general authority as the first remaining account")]
+  CloseDestinationRequired,
+
+  #[msg("Token bonding is not staking its reserves")]
+  ReserveStakeNotInitialized,
+
+  #[msg("Token bonding still has reserves in the stake pool. Wind it down and run rebalance_reserve_staking_v0 to unstake them")]
+  ReservesStaked,
+
+  #[msg("Caps on this token bonding are locked and can never change")]
+  CapsLocked,
 }
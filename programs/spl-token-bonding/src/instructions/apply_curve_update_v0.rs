@@ -0,0 +1,39 @@
+use super::update_curve_v0::CurveUpdateEventV0;
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+/// Permissionless, so a proposed curve takes effect even if the curve authority goes away
+#[derive(Accounts)]
+pub struct ApplyCurveUpdateV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.pending_curve_update.map(|u| u.curve) == Some(curve.key()) @ ErrorCode::NoPendingCurveUpdate,
+    constraint = !token_bonding.curve_locked @ ErrorCode::CurveLocked
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve: Box<Account<'info, CurveV0>>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<ApplyCurveUpdateV0>) -> Result<()> {
+  let bonding = &mut ctx.accounts.token_bonding;
+  let pending_curve_update = bonding
+    .pending_curve_update
+    .ok_or_else(|| error!(ErrorCode::NoPendingCurveUpdate))?;
+  if ctx.accounts.clock.unix_timestamp < pending_curve_update.effective_unix_time {
+    return Err(error!(ErrorCode::CurveUpdateTimelocked));
+  }
+
+  emit!(CurveUpdateEventV0 {
+    token_bonding: bonding.key(),
+    previous_curve: bonding.curve,
+    curve: pending_curve_update.curve,
+    curve_authority: bonding.curve_authority,
+    unix_timestamp: ctx.accounts.clock.unix_timestamp,
+  });
+
+  bonding.curve = pending_curve_update.curve;
+  bonding.pending_curve_update = None;
+
+  Ok(())
+}
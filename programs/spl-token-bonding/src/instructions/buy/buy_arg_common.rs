@@ -22,3 +22,22 @@ pub struct BuyV0Args {
   pub buy_with_base: Option<BuyWithBaseV0Args>,
   pub buy_target_amount: Option<BuyTargetAmountV0Args>,
 }
+
+// Taken by buy_v2 and buy_native_v1. BuyV0Args stays as is so existing clients keep working
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BuyV1Args {
+  pub buy_with_base: Option<BuyWithBaseV0Args>,
+  pub buy_target_amount: Option<BuyTargetAmountV0Args>,
+  // Merkle proof that the buyer is on the allowlist. Only needed while the allowlist phase is active
+  pub allowlist_proof: Option<Vec<[u8; 32]>>,
+}
+
+impl From<BuyV0Args> for BuyV1Args {
+  fn from(args: BuyV0Args) -> Self {
+    BuyV1Args {
+      buy_with_base: args.buy_with_base,
+      buy_target_amount: args.buy_target_amount,
+      allowlist_proof: None,
+    }
+  }
+}
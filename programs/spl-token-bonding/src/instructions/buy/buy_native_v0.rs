@@ -7,7 +7,7 @@ use anchor_spl::token::Mint;
 
 use super::{
   buy_account_common::*,
-  buy_arg_common::BuyV0Args,
+  buy_arg_common::BuyV1Args,
   buy_wrapped_sol_v0::{buy_wrapped_sol, BuyWrappedSolV0, BuyWrappedSolV0Args},
   common::{buy_shared_logic, mint_to_dest, BuyAmount},
 };
@@ -33,13 +33,18 @@ pub struct BuyNativeV0<'info> {
   pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<BuyNativeV0>, args: BuyV0Args) -> Result<()> {
+pub fn handler(ctx: Context<BuyNativeV0>, args: BuyV1Args) -> Result<()> {
   let BuyAmount {
     price,
     base_royalties,
     target_royalties,
     total_amount,
-  } = buy_shared_logic(&mut ctx.accounts.common, &args)?;
+  } = buy_shared_logic(
+    &mut ctx.accounts.common,
+    &args,
+    &ctx.accounts.source.key(),
+    ctx.remaining_accounts,
+  )?;
 
   mint_to_dest(
     total_amount,
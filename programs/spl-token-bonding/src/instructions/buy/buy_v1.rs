@@ -1,6 +1,6 @@
 use super::{
   buy_account_common::*,
-  buy_arg_common::BuyV0Args,
+  buy_arg_common::BuyV1Args,
   common::{buy_shared_logic, mint_to_dest, BuyAmount},
 };
 use crate::state::*;
@@ -20,13 +20,18 @@ pub struct BuyV1<'info> {
   pub source_authority: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<BuyV1>, args: BuyV0Args) -> Result<()> {
+pub fn handler(ctx: Context<BuyV1>, args: BuyV1Args) -> Result<()> {
   let BuyAmount {
     total_amount,
     price,
     target_royalties,
     base_royalties,
-  } = buy_shared_logic(&mut ctx.accounts.common, &args)?;
+  } = buy_shared_logic(
+    &mut ctx.accounts.common,
+    &args,
+    &ctx.accounts.source_authority.key(),
+    ctx.remaining_accounts,
+  )?;
 
   mint_to_dest(
     total_amount,
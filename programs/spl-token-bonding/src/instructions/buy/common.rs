@@ -1,177 +1,234 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, MintTo};
+use anchor_spl::token::{self, MintTo, TokenAccount};
 
-use crate::{curve::*, error::ErrorCode, util::*};
+use crate::{
+  error::ErrorCode,
+  instructions::claim_vested_royalties_v0::add_vesting_royalties,
+  oracle::{record_observation, take_price_oracle},
+  quote::{buy_amounts, QuoteState},
+  state::{BuyGateType, BuyGateV0, TokenBondingV0, WalletPurchaseV0},
+  util::{assert_valid_metadata, verify_merkle_proof},
+};
+use anchor_lang::solana_program::keccak;
 
-use super::{buy_account_common::BuyCommonV0, buy_arg_common::BuyV0Args};
+use super::{buy_account_common::BuyCommonV0, buy_arg_common::BuyV1Args};
 
-pub struct BuyAmount {
+pub use crate::quote::BuyAmount;
+
+#[event]
+pub struct BuyEventV0 {
+  pub token_bonding: Pubkey,
+  pub buyer: Pubkey,
+  /// Target tokens minted, including target royalties
+  pub target_amount: u64,
+  /// Base tokens paid into reserves
   pub price: u64,
-  pub total_amount: u64,
   pub base_royalties: u64,
   pub target_royalties: u64,
+  /// Base storage amount after the buy
+  pub reserves: u64,
+  /// Target supply after the buy
+  pub supply: u64,
+  pub unix_timestamp: i64,
 }
 
-pub fn buy_shared_logic(common: &mut BuyCommonV0, args: &BuyV0Args) -> Result<BuyAmount> {
-  let token_bonding = &mut common.token_bonding;
-  let curve = &common.curve;
-  let base_mint = &common.base_mint;
-  let target_mint = &common.target_mint;
-  let base_storage = &common.base_storage;
-  let clock = &common.clock;
-
-  // Not yet initialized since reserve_balance_from_bonding is a new feature
-  if !token_bonding.sell_frozen
-    && target_mint.supply > 0
-    && token_bonding.reserve_balance_from_bonding == 0
-    && token_bonding.go_live_unix_time < 1646092800_i64
-  {
-    token_bonding.reserve_balance_from_bonding = base_storage.amount;
-    token_bonding.supply_from_bonding = target_mint.supply;
-  }
-
-  let base_amount_u64 = if token_bonding.ignore_external_reserve_changes {
-    token_bonding.reserve_balance_from_bonding
-  } else {
-    base_storage.amount
-  };
-  let base_amount = precise_supply_amt(base_amount_u64, base_mint);
-  let target_supply_u64 = if token_bonding.ignore_external_supply_changes {
-    token_bonding.supply_from_bonding
+/// Checks the buyer may buy, prices the buy, and records it against the buyer's wallet purchase.
+///
+/// `remaining_accounts` holds, in order, the token bonding's PriceOracleV0 if the buyer wants to
+/// record an observation, the buyer's WalletPurchaseV0 if the token bonding has a wallet purchase
+/// cap, then the holding accounts for the buy gate if it has one.
+pub fn buy_shared_logic(
+  common: &mut BuyCommonV0,
+  args: &BuyV1Args,
+  buyer: &Pubkey,
+  remaining_accounts: &[AccountInfo],
+) -> Result<BuyAmount> {
+  let state = QuoteState::from_accounts(
+    &common.base_mint,
+    &common.target_mint,
+    &common.base_storage,
+    &common.clock,
+  );
+
+  assert_allowlisted(
+    &common.token_bonding,
+    buyer,
+    args.allowlist_proof.as_deref(),
+    state.unix_timestamp,
+  )?;
+
+  let (price_oracle, remaining_accounts) =
+    take_price_oracle(&common.token_bonding.key(), remaining_accounts)?;
+  let remaining_accounts = &mut remaining_accounts.iter();
+  let wallet_purchase = if common.token_bonding.wallet_purchase_cap.is_some() {
+    Some(
+      next_account_info(remaining_accounts)
+        .map_err(|_| error!(ErrorCode::WalletPurchaseRequired))?,
+    )
   } else {
-    target_mint.supply
+    None
   };
-  let target_supply = precise_supply_amt(target_supply_u64, target_mint);
-
-  // msg!(
-  //   "Current reserves {} and supply {}",
-  //   base_storage.amount,
-  //   target_mint.supply
-  // );
-
-  if token_bonding.go_live_unix_time > clock.unix_timestamp {
-    return Err(error!(ErrorCode::NotLiveYet));
-  }
-
-  if token_bonding.buy_frozen {
-    return Err(error!(ErrorCode::BuyFrozen));
-  }
-
-  if token_bonding.freeze_buy_unix_time.is_some()
-    && token_bonding.freeze_buy_unix_time.unwrap() < clock.unix_timestamp
-  {
-    return Err(error!(ErrorCode::BuyFrozen));
-  }
-
-  let base_royalties_percent = token_bonding.buy_base_royalty_percentage;
-  let target_royalties_percent = token_bonding.buy_target_royalty_percentage;
-
-  let price: u64;
-  let total_amount: u64;
-  let base_royalties: u64;
-  let target_royalties: u64;
-  if args.buy_target_amount.is_some() {
-    let buy_target_amount = args.buy_target_amount.clone().unwrap();
-
-    total_amount = buy_target_amount.target_amount;
-    let amount_prec = precise_supply_amt(total_amount, target_mint);
-    let price_prec = curve
-      .definition
-      .price(
-        clock
-          .unix_timestamp
-          .checked_sub(token_bonding.go_live_unix_time)
-          .unwrap(),
-        &base_amount,
-        &target_supply,
-        &amount_prec,
-        false,
-      )
-      .or_arith_error()?;
-
-    price = to_mint_amount(&price_prec, base_mint, true);
-    base_royalties = get_percent(price, base_royalties_percent)?;
-    target_royalties = get_percent(total_amount, target_royalties_percent)?;
-
-    if price.checked_add(base_royalties).unwrap() > buy_target_amount.maximum_price {
-      msg!(
-        "Price {} too high for max price {}",
-        price + base_royalties,
-        buy_target_amount.maximum_price
-      );
-      return Err(error!(ErrorCode::PriceTooHigh));
+  if let Some(buy_gate) = &common.token_bonding.buy_gate {
+    assert_buy_gate(buy_gate, buyer, remaining_accounts.as_slice())?;
+  }
+
+  let amount = buy_amounts(&mut common.token_bonding, &common.curve, &state, args)?;
+
+  // Target royalties are minted into the escrow, which is the buy target royalties account
+  if let Some(royalty_vesting) = &mut common.token_bonding.royalty_vesting {
+    add_vesting_royalties(
+      royalty_vesting,
+      amount.target_royalties,
+      state.unix_timestamp,
+    )
+    .ok_or(error!(ErrorCode::ArithmeticError))?;
+  }
+
+  if let Some(wallet_purchase_info) = wallet_purchase {
+    let mut wallet_purchase: Account<WalletPurchaseV0> = Account::try_from(wallet_purchase_info)?;
+    if wallet_purchase.token_bonding != common.token_bonding.key()
+      || wallet_purchase.wallet != *buyer
+    {
+      return Err(error!(ErrorCode::WalletPurchaseRequired));
     }
-  } else {
-    let buy_with_base = args.buy_with_base.clone().unwrap();
-    let total_price = buy_with_base.base_amount;
-    base_royalties = get_percent(total_price, base_royalties_percent)?;
-    let price_prec = precise_supply_amt(
-      total_price.checked_sub(base_royalties).or_arith_error()?,
-      base_mint,
+
+    record_wallet_purchase(
+      &common.token_bonding,
+      &mut wallet_purchase,
+      amount.total_amount,
+      state.unix_timestamp,
+    )?;
+    wallet_purchase.exit(&crate::id())?;
+  }
+
+  if let Some(mut price_oracle) = price_oracle {
+    record_observation(
+      &mut price_oracle,
+      &common.token_bonding.price_accumulator.unwrap_or_default(),
+      state.unix_timestamp,
     );
+    price_oracle.exit(&crate::id())?;
+  }
+
+  emit!(BuyEventV0 {
+    token_bonding: common.token_bonding.key(),
+    buyer: *buyer,
+    target_amount: amount.total_amount,
+    price: amount.price,
+    base_royalties: amount.base_royalties,
+    target_royalties: amount.target_royalties,
+    reserves: state.base_storage_amount.saturating_add(amount.price),
+    supply: state.target_supply.saturating_add(amount.total_amount),
+    unix_timestamp: state.unix_timestamp,
+  });
+
+  Ok(amount)
+}
 
-    let amount_prec = curve
-      .definition
-      .expected_target_amount(
-        clock
-          .unix_timestamp
-          .checked_sub(token_bonding.go_live_unix_time)
-          .unwrap(),
-        &base_amount,
-        &target_supply,
-        &price_prec,
-      )
-      .or_arith_error()?;
+/// While the allowlist phase is active, the buyer's wallet must be a leaf of the allowlist root
+pub fn assert_allowlisted(
+  token_bonding: &TokenBondingV0,
+  buyer: &Pubkey,
+  proof: Option<&[[u8; 32]]>,
+  unix_timestamp: i64,
+) -> Result<()> {
+  let root = match token_bonding.allowlist_root {
+    Some(root) => root,
+    None => return Ok(()),
+  };
+  if let Some(end) = token_bonding.allowlist_end_unix_time {
+    if unix_timestamp >= end {
+      return Ok(());
+    }
+  }
 
-    total_amount = to_mint_amount(&amount_prec, target_mint, false);
+  let leaf = keccak::hash(buyer.as_ref()).0;
+  match proof {
+    Some(proof) if verify_merkle_proof(proof, root, leaf) => Ok(()),
+    _ => Err(error!(ErrorCode::NotAllowlisted)),
+  }
+}
 
-    price = to_mint_amount(&price_prec, base_mint, false);
+/// Token gates take token accounts of the gate mint. Collection gates take pairs of an NFT token
+/// account and its metadata. Amounts held by the buyer are summed, counting each account once
+pub fn assert_buy_gate(
+  buy_gate: &BuyGateV0,
+  buyer: &Pubkey,
+  holding_accounts: &[AccountInfo],
+) -> Result<()> {
+  let accounts_per_holding = match buy_gate.gate_type {
+    BuyGateType::Token => 1,
+    BuyGateType::Collection => 2,
+  };
 
-    target_royalties = get_percent(total_amount, target_royalties_percent)?;
+  let mut counted: Vec<Pubkey> = vec![];
+  let mut held: u64 = 0;
+  for holding in holding_accounts.chunks_exact(accounts_per_holding) {
+    let token_account: Account<TokenAccount> = Account::try_from(&holding[0])?;
+    if token_account.owner != *buyer || counted.contains(&token_account.key()) {
+      continue;
+    }
 
-    let target_amount_minus_royalties = total_amount.checked_sub(target_royalties).unwrap();
-    if target_amount_minus_royalties < buy_with_base.minimum_target_amount {
-      msg!(
-        "{} less than minimum tokens {}",
-        target_amount_minus_royalties,
-        buy_with_base.minimum_target_amount
-      );
-      return Err(error!(ErrorCode::PriceTooHigh));
+    let gate_key = match buy_gate.gate_type {
+      BuyGateType::Token => Some(token_account.mint),
+      BuyGateType::Collection => assert_valid_metadata(&holding[1], token_account.mint)?
+        .collection
+        .filter(|collection| collection.verified)
+        .map(|collection| collection.key),
+    };
+    if gate_key == Some(buy_gate.key) {
+      counted.push(token_account.key());
+      held = held.saturating_add(token_account.amount);
     }
   }
 
-  if token_bonding.mint_cap.is_some()
-    && target_mint.supply.checked_add(total_amount).unwrap() > token_bonding.mint_cap.unwrap()
-  {
+  if held < buy_gate.minimum_amount {
     msg!(
-      "Mint cap is {} {} {}",
-      token_bonding.mint_cap.unwrap(),
-      target_mint.supply,
-      total_amount
+      "Buyer holds {} of the gate, needs {}",
+      held,
+      buy_gate.minimum_amount
     );
-    return Err(error!(ErrorCode::PassedMintCap));
+    return Err(error!(ErrorCode::BuyGateNotMet));
   }
 
-  if token_bonding.purchase_cap.is_some() && total_amount > token_bonding.purchase_cap.unwrap() {
-    return Err(error!(ErrorCode::OverPurchaseCap));
-  }
+  Ok(())
+}
 
-  token_bonding.supply_from_bonding = token_bonding
-    .supply_from_bonding
-    .checked_add(total_amount)
-    .or_arith_error()?;
+pub fn record_wallet_purchase(
+  token_bonding: &TokenBondingV0,
+  wallet_purchase: &mut WalletPurchaseV0,
+  amount: u64,
+  unix_timestamp: i64,
+) -> Result<()> {
+  if let Some(window_secs) = token_bonding.wallet_purchase_window_secs {
+    if unix_timestamp
+      >= wallet_purchase
+        .window_start_unix_time
+        .saturating_add(window_secs)
+    {
+      wallet_purchase.purchased_amount = 0;
+      wallet_purchase.window_start_unix_time = unix_timestamp;
+    }
+  }
 
-  token_bonding.reserve_balance_from_bonding = token_bonding
-    .reserve_balance_from_bonding
-    .checked_add(price)
-    .or_arith_error()?;
+  let purchased_amount = wallet_purchase
+    .purchased_amount
+    .checked_add(amount)
+    .ok_or(error!(ErrorCode::ArithmeticError))?;
+  if let Some(cap) = token_bonding.wallet_purchase_cap {
+    if purchased_amount > cap {
+      msg!(
+        "Wallet has purchased {}, cannot purchase {} more with a cap of {}",
+        wallet_purchase.purchased_amount,
+        amount,
+        cap
+      );
+      return Err(error!(ErrorCode::OverWalletPurchaseCap));
+    }
+  }
+  wallet_purchase.purchased_amount = purchased_amount;
 
-  Ok(BuyAmount {
-    price,
-    base_royalties,
-    target_royalties,
-    total_amount,
-  })
+  Ok(())
 }
 
 pub fn mint_to_dest<'info>(
@@ -225,3 +282,215 @@ pub fn mint_to_dest<'info>(
 
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spl_token::solana_program::program_pack::Pack;
+
+  struct Holding {
+    key: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+  }
+
+  fn holding(mint: Pubkey, owner: Pubkey, amount: u64) -> Holding {
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+      mint,
+      owner,
+      amount,
+      state: spl_token::state::AccountState::Initialized,
+      ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+
+    Holding {
+      key: Pubkey::new_unique(),
+      lamports: 0,
+      data,
+    }
+  }
+
+  fn infos(holdings: &mut [Holding]) -> Vec<AccountInfo<'_>> {
+    holdings
+      .iter_mut()
+      .map(|holding| {
+        AccountInfo::new(
+          &holding.key,
+          false,
+          false,
+          &mut holding.lamports,
+          &mut holding.data,
+          &anchor_spl::token::ID,
+          false,
+          0,
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_token_buy_gate() {
+    let mint = Pubkey::new_unique();
+    let buyer = Pubkey::new_unique();
+    let buy_gate = BuyGateV0 {
+      gate_type: BuyGateType::Token,
+      key: mint,
+      minimum_amount: 100,
+    };
+
+    let mut holdings = [
+      holding(mint, buyer, 40),
+      holding(mint, buyer, 60),
+      holding(Pubkey::new_unique(), buyer, 1000),
+      holding(mint, Pubkey::new_unique(), 1000),
+    ];
+    let accounts = infos(&mut holdings);
+
+    assert_buy_gate(&buy_gate, &buyer, &accounts[..2]).unwrap();
+    assert_eq!(
+      assert_buy_gate(&buy_gate, &buyer, &accounts[1..]).unwrap_err(),
+      error!(ErrorCode::BuyGateNotMet)
+    );
+    assert_eq!(
+      assert_buy_gate(&buy_gate, &buyer, &[]).unwrap_err(),
+      error!(ErrorCode::BuyGateNotMet)
+    );
+
+    // Passing the same account twice does not double count it
+    let duplicated = [accounts[1].clone(), accounts[1].clone()];
+    assert_eq!(
+      assert_buy_gate(&buy_gate, &buyer, &duplicated).unwrap_err(),
+      error!(ErrorCode::BuyGateNotMet)
+    );
+  }
+
+  fn token_bonding(cap: u64, window_secs: Option<i64>) -> TokenBondingV0 {
+    TokenBondingV0 {
+      wallet_purchase_cap: Some(cap),
+      wallet_purchase_window_secs: window_secs,
+      ..Default::default()
+    }
+  }
+
+  fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    if a <= b {
+      keccak::hashv(&[&a, &b]).0
+    } else {
+      keccak::hashv(&[&b, &a]).0
+    }
+  }
+
+  // Four wallet allowlist, returning the root and the proof for the first wallet
+  fn allowlist(wallets: &[Pubkey; 4]) -> ([u8; 32], Vec<[u8; 32]>) {
+    let leaves: Vec<[u8; 32]> = wallets
+      .iter()
+      .map(|wallet| keccak::hash(wallet.as_ref()).0)
+      .collect();
+    let left = hash_pair(leaves[0], leaves[1]);
+    let right = hash_pair(leaves[2], leaves[3]);
+
+    (hash_pair(left, right), vec![leaves[1], right])
+  }
+
+  fn allowlisted_bonding(root: [u8; 32], end: Option<i64>) -> TokenBondingV0 {
+    TokenBondingV0 {
+      allowlist_root: Some(root),
+      allowlist_end_unix_time: end,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_allowlist_valid_proof() {
+    let wallets = [
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    ];
+    let (root, proof) = allowlist(&wallets);
+    let token_bonding = allowlisted_bonding(root, Some(100));
+
+    assert_allowlisted(&token_bonding, &wallets[0], Some(&proof), 50).unwrap();
+    assert_allowlisted(&TokenBondingV0::default(), &wallets[0], None, 50).unwrap();
+  }
+
+  #[test]
+  fn test_allowlist_invalid_proof() {
+    let wallets = [
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    ];
+    let (root, proof) = allowlist(&wallets);
+    let token_bonding = allowlisted_bonding(root, None);
+
+    let outsider = Pubkey::new_unique();
+    assert_eq!(
+      assert_allowlisted(&token_bonding, &outsider, Some(&proof), 50).unwrap_err(),
+      error!(ErrorCode::NotAllowlisted)
+    );
+    assert_eq!(
+      assert_allowlisted(&token_bonding, &wallets[2], Some(&proof), 50).unwrap_err(),
+      error!(ErrorCode::NotAllowlisted)
+    );
+    assert_eq!(
+      assert_allowlisted(&token_bonding, &wallets[0], None, 50).unwrap_err(),
+      error!(ErrorCode::NotAllowlisted)
+    );
+  }
+
+  #[test]
+  fn test_allowlist_expired() {
+    let wallets = [
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+      Pubkey::new_unique(),
+    ];
+    let (root, _) = allowlist(&wallets);
+    let token_bonding = allowlisted_bonding(root, Some(100));
+
+    let outsider = Pubkey::new_unique();
+    assert_eq!(
+      assert_allowlisted(&token_bonding, &outsider, None, 99).unwrap_err(),
+      error!(ErrorCode::NotAllowlisted)
+    );
+    assert_allowlisted(&token_bonding, &outsider, None, 100).unwrap();
+    assert_allowlisted(&token_bonding, &outsider, Some(&[[0; 32]]), 200).unwrap();
+  }
+
+  #[test]
+  fn test_wallet_purchase_cap() {
+    let token_bonding = token_bonding(100, None);
+    let mut wallet_purchase = WalletPurchaseV0::default();
+
+    record_wallet_purchase(&token_bonding, &mut wallet_purchase, 60, 10).unwrap();
+    record_wallet_purchase(&token_bonding, &mut wallet_purchase, 40, 20).unwrap();
+    assert_eq!(wallet_purchase.purchased_amount, 100);
+
+    let err = record_wallet_purchase(&token_bonding, &mut wallet_purchase, 1, 1_000_000);
+    assert_eq!(err.unwrap_err(), error!(ErrorCode::OverWalletPurchaseCap));
+    assert_eq!(wallet_purchase.purchased_amount, 100);
+  }
+
+  #[test]
+  fn test_wallet_purchase_window_resets() {
+    let token_bonding = token_bonding(100, Some(60));
+    let mut wallet_purchase = WalletPurchaseV0 {
+      window_start_unix_time: 1000,
+      ..Default::default()
+    };
+
+    record_wallet_purchase(&token_bonding, &mut wallet_purchase, 100, 1030).unwrap();
+    let err = record_wallet_purchase(&token_bonding, &mut wallet_purchase, 1, 1059);
+    assert_eq!(err.unwrap_err(), error!(ErrorCode::OverWalletPurchaseCap));
+
+    record_wallet_purchase(&token_bonding, &mut wallet_purchase, 70, 1060).unwrap();
+    assert_eq!(wallet_purchase.purchased_amount, 70);
+    assert_eq!(wallet_purchase.window_start_unix_time, 1060);
+  }
+}
@@ -4,9 +4,11 @@ pub mod buy_native_v0;
 pub mod buy_v1;
 pub mod buy_wrapped_sol_v0;
 pub mod common;
+pub mod quote_buy_v0;
 
 pub use buy_account_common::*;
 pub use buy_arg_common::*;
 pub use buy_native_v0::*;
 pub use buy_v1::*;
 pub use buy_wrapped_sol_v0::*;
+pub use quote_buy_v0::*;
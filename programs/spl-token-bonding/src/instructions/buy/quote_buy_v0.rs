@@ -0,0 +1,65 @@
+use super::buy_arg_common::BuyV0Args;
+use crate::{
+  quote::{quote_buy, BuyAmount, QuoteState, QuoteV0},
+  state::*,
+};
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+// Same accounts as BuyCommonV0, but nothing is writable
+#[derive(Accounts)]
+pub struct QuoteBuyV0<'info> {
+  #[account(
+    has_one = base_mint,
+    has_one = target_mint,
+    has_one = base_storage,
+    has_one = buy_base_royalties,
+    has_one = buy_target_royalties,
+    has_one = curve
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve: Box<Account<'info, CurveV0>>,
+  pub base_mint: Box<Account<'info, Mint>>,
+  pub target_mint: Box<Account<'info, Mint>>,
+  pub base_storage: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Token account could have been closed. Not used when quoting
+  pub buy_base_royalties: AccountInfo<'info>,
+  /// CHECK: Not used when quoting, so the destination need not exist yet
+  pub destination: AccountInfo<'info>,
+  /// CHECK: Token account could have been closed. Not used when quoting
+  pub buy_target_royalties: AccountInfo<'info>,
+  pub token_program: Program<'info, Token>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<QuoteBuyV0>, args: BuyV0Args) -> Result<()> {
+  let state = QuoteState::from_accounts(
+    &ctx.accounts.base_mint,
+    &ctx.accounts.target_mint,
+    &ctx.accounts.base_storage,
+    &ctx.accounts.clock,
+  );
+  let BuyAmount {
+    price,
+    total_amount,
+    base_royalties,
+    target_royalties,
+  } = quote_buy(
+    &ctx.accounts.token_bonding,
+    &ctx.accounts.curve,
+    &state,
+    &args.into(),
+  )?;
+
+  set_return_data(
+    &QuoteV0 {
+      price,
+      total_amount,
+      base_royalties,
+      target_royalties,
+    }
+    .try_to_vec()?,
+  );
+
+  Ok(())
+}
@@ -0,0 +1,19 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelCurveUpdateV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.curve_authority.ok_or(error!(ErrorCode::NoAuthority))? == curve_authority.key(),
+    constraint = token_bonding.pending_curve_update.is_some() @ ErrorCode::NoPendingCurveUpdate
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelCurveUpdateV0>) -> Result<()> {
+  ctx.accounts.token_bonding.pending_curve_update = None;
+
+  Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ClaimRoyaltySplitV0Args {
+  /// Pay out lamports held by the vault instead of tokens held by the escrow
+  pub native: bool,
+}
+
+/// Pays recipients their share of everything the split has received. Recipients are passed as
+/// remaining accounts: their wallets for native claims, or their token accounts of the split mint.
+/// Any subset can be paid per transaction, so splits with many recipients are paid over several
+#[derive(Accounts)]
+#[instruction(args: ClaimRoyaltySplitV0Args)]
+pub struct ClaimRoyaltySplitV0<'info> {
+  #[account(
+    mut,
+    has_one = escrow,
+    has_one = vault
+  )]
+  pub royalty_split: Box<Account<'info, RoyaltySplitV0>>,
+  #[account(mut)]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub vault: SystemAccount<'info>,
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  pub rent: Sysvar<'info, Rent>,
+}
+
+/// What a recipient is still owed once the split has received `total_received` in total
+pub fn owed_amount(total_received: u64, share_bps: u16, claimed_amount: u64) -> Result<u64> {
+  let entitled = (total_received as u128 * share_bps as u128 / 10_000) as u64;
+  entitled
+    .checked_sub(claimed_amount)
+    .ok_or_else(|| error!(ErrorCode::ArithmeticError))
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, ClaimRoyaltySplitV0<'info>>,
+  args: ClaimRoyaltySplitV0Args,
+) -> Result<()> {
+  let royalty_split = &mut ctx.accounts.royalty_split;
+  let split_key = royalty_split.key();
+  let vault_seeds: &[&[&[u8]]] = &[&[
+    b"royalty-split-vault",
+    split_key.as_ref(),
+    &[royalty_split.vault_bump_seed],
+  ]];
+
+  // Everything ever paid in is what is held now plus what has already gone out
+  let total_received = if args.native {
+    let held = ctx
+      .accounts
+      .vault
+      .lamports()
+      .saturating_sub(ctx.accounts.rent.minimum_balance(0));
+    held + royalty_split.total_native_claimed_amount
+  } else {
+    ctx.accounts.escrow.amount + royalty_split.total_claimed_amount
+  };
+
+  for destination in ctx.remaining_accounts {
+    let wallet = if args.native {
+      destination.key()
+    } else {
+      let token_account: Account<TokenAccount> = Account::try_from(destination)?;
+      if token_account.mint != royalty_split.mint {
+        return Err(error!(ErrorCode::InvalidMint));
+      }
+      token_account.owner
+    };
+    let index = royalty_split
+      .recipients
+      .iter()
+      .position(|r| r.wallet == wallet)
+      .ok_or_else(|| error!(ErrorCode::UnknownRoyaltyRecipient))?;
+    let recipient = &royalty_split.recipients[index];
+    let claimed_amount = if args.native {
+      recipient.native_claimed_amount
+    } else {
+      recipient.claimed_amount
+    };
+    let owed = owed_amount(total_received, recipient.share_bps, claimed_amount)?;
+    if owed == 0 {
+      continue;
+    }
+
+    if args.native {
+      royalty_split.recipients[index].native_claimed_amount += owed;
+      royalty_split.total_native_claimed_amount += owed;
+      invoke_signed(
+        &system_instruction::transfer(&ctx.accounts.vault.key(), &destination.key(), owed),
+        &[
+          ctx.accounts.vault.to_account_info().clone(),
+          destination.clone(),
+          ctx.accounts.system_program.to_account_info().clone(),
+        ],
+        vault_seeds,
+      )?;
+    } else {
+      royalty_split.recipients[index].claimed_amount += owed;
+      royalty_split.total_claimed_amount += owed;
+      token::transfer(
+        CpiContext::new_with_signer(
+          ctx.accounts.token_program.to_account_info(),
+          Transfer {
+            from: ctx.accounts.escrow.to_account_info(),
+            to: destination.clone(),
+            authority: ctx.accounts.vault.to_account_info(),
+          },
+          vault_seeds,
+        ),
+        owed,
+      )?;
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_owed_amount_follows_shares() {
+    assert_eq!(owed_amount(1_000, 2_500, 0).unwrap(), 250);
+    assert_eq!(owed_amount(1_000, 7_500, 0).unwrap(), 750);
+    assert_eq!(owed_amount(u64::MAX, 10_000, 0).unwrap(), u64::MAX);
+  }
+
+  #[test]
+  fn test_owed_amount_excludes_claims() {
+    assert_eq!(owed_amount(1_000, 2_500, 250).unwrap(), 0);
+    // More royalties arrived since the last claim
+    assert_eq!(owed_amount(3_000, 2_500, 250).unwrap(), 500);
+  }
+
+  #[test]
+  fn test_owed_amounts_never_exceed_received() {
+    let shares = [3_333, 3_333, 3_334];
+    let total: u64 = shares
+      .iter()
+      .map(|s| owed_amount(1_000, *s, 0).unwrap())
+      .sum();
+    assert!(total <= 1_000);
+  }
+}
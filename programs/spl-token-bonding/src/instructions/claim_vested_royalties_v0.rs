@@ -0,0 +1,235 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(Accounts)]
+pub struct ClaimVestedRoyaltiesV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.royalty_vesting.as_ref().map(|v| v.escrow) == Some(escrow.key()) @ ErrorCode::NoRoyaltyVesting,
+    constraint = token_bonding.royalty_vesting.as_ref().map(|v| v.destination) == Some(destination.key())
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  #[account(mut)]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+  pub token_program: Program<'info, Token>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+/// Move whatever the schedule has released by `unix_time` out of the unvested amount
+pub fn release_vested(royalty_vesting: &mut RoyaltyVestingV0, unix_time: i64) {
+  if royalty_vesting.unvested_amount == 0 {
+    return;
+  }
+  if unix_time >= royalty_vesting.end_unix_time {
+    royalty_vesting.unvested_amount = 0;
+  } else if unix_time > royalty_vesting.release_unix_time {
+    let released = (royalty_vesting.unvested_amount as u128
+      * (unix_time - royalty_vesting.release_unix_time) as u128
+      / (royalty_vesting.end_unix_time - royalty_vesting.release_unix_time) as u128)
+      as u64;
+    royalty_vesting.unvested_amount -= released;
+    royalty_vesting.release_unix_time = unix_time;
+  }
+}
+
+/// Escrow `amount` more royalties. They vest on their own cliff and duration from `unix_time`,
+/// merged into the schedule of what is still unvested. The merged schedule takes the later
+/// release and end time, so no deposit is ever released sooner than its own schedule allows
+pub fn add_vesting_royalties(
+  royalty_vesting: &mut RoyaltyVestingV0,
+  amount: u64,
+  unix_time: i64,
+) -> Option<()> {
+  if amount == 0 {
+    return Some(());
+  }
+  release_vested(royalty_vesting, unix_time);
+
+  let start = unix_time.max(royalty_vesting.start_unix_time);
+  let release_unix_time = start.checked_add(royalty_vesting.cliff_secs)?;
+  let end_unix_time = start.checked_add(royalty_vesting.duration_secs)?;
+  if royalty_vesting.unvested_amount == 0 {
+    royalty_vesting.release_unix_time = release_unix_time;
+    royalty_vesting.end_unix_time = end_unix_time;
+  } else {
+    royalty_vesting.release_unix_time = royalty_vesting.release_unix_time.max(release_unix_time);
+    royalty_vesting.end_unix_time = royalty_vesting.end_unix_time.max(end_unix_time);
+  }
+  royalty_vesting.unvested_amount = royalty_vesting.unvested_amount.checked_add(amount)?;
+  royalty_vesting.total_vesting_amount =
+    royalty_vesting.total_vesting_amount.checked_add(amount)?;
+
+  Some(())
+}
+
+/// Released royalties that have not been claimed yet
+pub fn claimable_amount(royalty_vesting: &RoyaltyVestingV0) -> Option<u64> {
+  royalty_vesting
+    .total_vesting_amount
+    .checked_sub(royalty_vesting.unvested_amount)?
+    .checked_sub(royalty_vesting.claimed_amount)
+}
+
+pub fn handler(ctx: Context<ClaimVestedRoyaltiesV0>) -> Result<()> {
+  let unix_time = ctx.accounts.clock.unix_timestamp;
+  let token_bonding = &mut ctx.accounts.token_bonding;
+  let royalty_vesting = token_bonding
+    .royalty_vesting
+    .as_mut()
+    .ok_or(error!(ErrorCode::NoRoyaltyVesting))?;
+
+  release_vested(royalty_vesting, unix_time);
+  let claimable = claimable_amount(royalty_vesting).ok_or(error!(ErrorCode::ArithmeticError))?;
+  royalty_vesting.claimed_amount += claimable;
+  msg!(
+    "Claiming {} of {} vesting royalties, {} claimed in total",
+    claimable,
+    royalty_vesting.total_vesting_amount,
+    royalty_vesting.claimed_amount
+  );
+
+  if claimable > 0 {
+    let bonding_seeds: &[&[&[u8]]] = &[&[
+      b"token-bonding",
+      token_bonding.target_mint.as_ref(),
+      &token_bonding.index.to_le_bytes(),
+      &[token_bonding.bump_seed],
+    ]];
+    token::transfer(
+      CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+          from: ctx.accounts.escrow.to_account_info(),
+          to: ctx.accounts.destination.to_account_info(),
+          authority: token_bonding.to_account_info(),
+        },
+        bonding_seeds,
+      ),
+      claimable,
+    )?;
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn royalty_vesting() -> RoyaltyVestingV0 {
+    RoyaltyVestingV0 {
+      start_unix_time: 1000,
+      cliff_secs: 100,
+      duration_secs: 400,
+      ..Default::default()
+    }
+  }
+
+  fn claimable_at(royalty_vesting: &RoyaltyVestingV0, unix_time: i64) -> u64 {
+    let mut royalty_vesting = royalty_vesting.clone();
+    release_vested(&mut royalty_vesting, unix_time);
+    claimable_amount(&royalty_vesting).unwrap()
+  }
+
+  #[test]
+  fn test_nothing_vests_before_cliff() {
+    let mut royalty_vesting = royalty_vesting();
+    add_vesting_royalties(&mut royalty_vesting, 1_000, 1000).unwrap();
+    assert_eq!(claimable_at(&royalty_vesting, 1000), 0);
+    assert_eq!(claimable_at(&royalty_vesting, 1099), 0);
+    assert_eq!(claimable_at(&royalty_vesting, 1100), 0);
+  }
+
+  #[test]
+  fn test_vests_linearly_after_cliff() {
+    let mut royalty_vesting = royalty_vesting();
+    add_vesting_royalties(&mut royalty_vesting, 1_000, 1000).unwrap();
+    assert_eq!(claimable_at(&royalty_vesting, 1200), 333);
+    assert_eq!(claimable_at(&royalty_vesting, 1399), 996);
+    assert_eq!(claimable_at(&royalty_vesting, 1400), 1_000);
+    assert_eq!(claimable_at(&royalty_vesting, i64::MAX), 1_000);
+  }
+
+  #[test]
+  fn test_releasing_in_steps_matches_releasing_once() {
+    let mut royalty_vesting = royalty_vesting();
+    add_vesting_royalties(&mut royalty_vesting, 1_000, 1000).unwrap();
+    release_vested(&mut royalty_vesting, 1200);
+    assert_eq!(claimable_at(&royalty_vesting, 1300), 666);
+    assert_eq!(claimable_at(&royalty_vesting, 1400), 1_000);
+  }
+
+  #[test]
+  fn test_deposits_before_start_wait_for_start() {
+    let mut royalty_vesting = royalty_vesting();
+    add_vesting_royalties(&mut royalty_vesting, 1_000, 500).unwrap();
+    assert_eq!(claimable_at(&royalty_vesting, 1100), 0);
+    assert_eq!(claimable_at(&royalty_vesting, 1400), 1_000);
+  }
+
+  #[test]
+  fn test_royalties_after_duration_are_still_locked() {
+    let mut royalty_vesting = royalty_vesting();
+    add_vesting_royalties(&mut royalty_vesting, 1_000, 1000).unwrap();
+    assert_eq!(claimable_at(&royalty_vesting, 2000), 1_000);
+
+    // Minted well after the first deposit fully vested, so these start their own schedule
+    add_vesting_royalties(&mut royalty_vesting, 500, 2000).unwrap();
+    assert_eq!(claimable_at(&royalty_vesting, 2000), 1_000);
+    assert_eq!(claimable_at(&royalty_vesting, 2100), 1_000);
+    assert_eq!(claimable_at(&royalty_vesting, 2250), 1_250);
+    assert_eq!(claimable_at(&royalty_vesting, 2400), 1_500);
+  }
+
+  #[test]
+  fn test_deposits_never_release_before_their_own_schedule() {
+    // What each deposit would have released on its own schedule
+    fn per_deposit_released(deposits: &[(u64, i64)], unix_time: i64) -> u64 {
+      deposits
+        .iter()
+        .map(|(amount, time)| {
+          let release = (time + 100) as u128;
+          let end = (time + 400) as u128;
+          let now = unix_time as u128;
+          if now <= release {
+            0
+          } else if now >= end {
+            *amount
+          } else {
+            (*amount as u128 * (now - release) / (end - release)) as u64
+          }
+        })
+        .sum()
+    }
+
+    let mut royalty_vesting = royalty_vesting();
+    add_vesting_royalties(&mut royalty_vesting, 1_000, 1000).unwrap();
+    add_vesting_royalties(&mut royalty_vesting, 500, 1250).unwrap();
+    let deposits = [(1_000, 1000), (500, 1250)];
+
+    // Nothing from the second deposit before its cliff at 1350
+    assert!(claimable_at(&royalty_vesting, 1349) <= 1_000);
+    assert!(claimable_at(&royalty_vesting, 1500) <= 1_250);
+    for unix_time in (1250..1700).step_by(7) {
+      assert!(
+        claimable_at(&royalty_vesting, unix_time) <= per_deposit_released(&deposits, unix_time),
+        "released early at {}",
+        unix_time
+      );
+    }
+    assert_eq!(claimable_at(&royalty_vesting, 1650), 1_500);
+  }
+
+  #[test]
+  fn test_no_schedule_vests_immediately() {
+    let mut royalty_vesting = RoyaltyVestingV0 {
+      start_unix_time: 1000,
+      ..Default::default()
+    };
+    add_vesting_royalties(&mut royalty_vesting, u64::MAX, 1000).unwrap();
+    assert_eq!(claimable_at(&royalty_vesting, 1000), u64::MAX);
+  }
+}
@@ -1,10 +1,11 @@
 use crate::{
   error::ErrorCode,
+  quote::supply_amount,
   state::*,
   util::{close_token_account, CloseTokenAccount},
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{set_authority, Mint, SetAuthority, Token, TokenAccount};
+use anchor_spl::token::{self, set_authority, Mint, SetAuthority, Token, TokenAccount, Transfer};
 
 #[derive(Accounts)]
 pub struct CloseTokenBondingV0<'info> {
@@ -15,12 +16,16 @@ pub struct CloseTokenBondingV0<'info> {
     close = refund,
     constraint = token_bonding.general_authority.ok_or(error!(ErrorCode::NoAuthority))? == general_authority.key(),
     has_one = target_mint,
-    has_one = base_storage
+    has_one = base_storage,
+    constraint = token_bonding.reserve_stake.map_or(true, |stake| stake.pool_tokens == 0 && stake.staked_lamports == 0) @ ErrorCode::ReservesStaked
   )]
   pub token_bonding: Account<'info, TokenBondingV0>,
   #[account(
-    // Bonding can be closed by the authority if reserves are empty
-    constraint = base_storage.amount == 0
+    // Bonding can be closed by the authority if reserves are empty, or once every holder of a
+    // wound down bonding has redeemed. Then whatever is left goes to the general authority
+    constraint = base_storage.amount == 0 || (
+      token_bonding.wind_down && supply_amount(&token_bonding, target_mint.supply) == 0
+    )
   )]
   pub general_authority: Signer<'info>,
 
@@ -31,7 +36,9 @@ pub struct CloseTokenBondingV0<'info> {
   pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<CloseTokenBondingV0>) -> Result<()> {
+/// `remaining_accounts` holds a base mint token account of the general authority when a wound
+/// down bonding still has reserves left
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseTokenBondingV0<'info>>) -> Result<()> {
   let token_bonding = &mut ctx.accounts.token_bonding;
   let bonding_seeds: &[&[&[u8]]] = &[&[
     b"token-bonding",
@@ -40,6 +47,35 @@ pub fn handler(ctx: Context<CloseTokenBondingV0>) -> Result<()> {
     &[token_bonding.bump_seed],
   ]];
 
+  let leftover = ctx.accounts.base_storage.amount;
+  if leftover > 0 {
+    let destination_info = ctx
+      .remaining_accounts
+      .get(0)
+      .ok_or(error!(ErrorCode::CloseDestinationRequired))?;
+    let destination = Account::<TokenAccount>::try_from(destination_info)?;
+    if destination.mint != ctx.accounts.base_storage.mint
+      || destination.owner != ctx.accounts.general_authority.key()
+    {
+      return Err(error!(ErrorCode::CloseDestinationRequired));
+    }
+
+    msg!("Sweeping {} left in base storage", leftover);
+    token::transfer(
+      CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info().clone(),
+        Transfer {
+          from: ctx.accounts.base_storage.to_account_info().clone(),
+          to: destination_info.clone(),
+          authority: token_bonding.to_account_info().clone(),
+        },
+        bonding_seeds,
+      ),
+      leftover,
+    )?;
+    ctx.accounts.base_storage.reload()?;
+  }
+
   if ctx.accounts.base_storage.owner == token_bonding.key() {
     close_token_account(CpiContext::new_with_signer(
       ctx.accounts.token_program.to_account_info().clone(),
@@ -1,4 +1,4 @@
-use crate::{error::ErrorCode, state::*};
+use crate::{curve::supply_tiers_are_continuous, error::ErrorCode, state::*};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -6,12 +6,23 @@ pub struct CreateCurveV0Args {
   pub definition: PiecewiseCurve,
 }
 
+pub const MAX_PRICE_TABLE_POINTS: usize = 32;
+
 pub fn primitive_curve_is_valid(curve: &PrimitiveCurve) -> bool {
   match *curve {
-    PrimitiveCurve::ExponentialCurveV0 { frac, c, b, pow } => {
-      (c == 0 || b == 0) && frac > 0 && frac <= 10 && pow <= 10
-    }
+    PrimitiveCurve::ExponentialCurveV0 { frac, pow, .. } => frac > 0 && frac <= 10 && pow <= 10,
     PrimitiveCurve::TimeDecayExponentialCurveV0 { .. } => true,
+    PrimitiveCurve::LogarithmicCurveV0 { c, g, .. } => c > 0 && g > 0,
+    PrimitiveCurve::SigmoidCurveV0 { max_price, k, .. } => max_price > 0 && k > 0,
+    PrimitiveCurve::PriceTableCurveV0 { ref points } => {
+      points.len() <= MAX_PRICE_TABLE_POINTS &&
+        // Every price is positive, so the curve can always be inverted
+        points.iter().all(|p| p.price > 0) &&
+        // The table starts at zero supply
+        points.get(0).map(|p| p.supply).unwrap_or(1) == 0 &&
+        // Supply points are strictly increasing
+        points.windows(2).all(|p| p[0].supply < p[1].supply)
+    }
   }
 }
 
@@ -26,6 +37,17 @@ pub fn curve_is_valid(curve: &PiecewiseCurve) -> bool {
         // The curves list is ordered by offset
         curves.windows(2).all(|c| c[0].offset <= c[1].offset)
     }
+    PiecewiseCurve::SupplyV0 { curves } =>
+    // All inner curves are valid
+    {
+      curves.iter().all(|c| primitive_curve_is_valid(&c.curve)) &&
+        // The first tier starts at zero supply
+        curves.get(0).map(|c| c.offset).unwrap_or(1) == 0 &&
+        // Tiers are contiguous and ordered by offset. Each tier ends where the next begins, so none may be empty
+        curves.windows(2).all(|c| c[0].offset < c[1].offset) &&
+        // Each tier ends at the price the next one starts at
+        supply_tiers_are_continuous(curves)
+    }
   }
 }
 
@@ -45,6 +67,12 @@ pub fn handler(ctx: Context<InitializeCurveV0>, args: CreateCurveV0Args) -> Resu
     return Err(error!(ErrorCode::InvalidCurve));
   }
 
+  // Price tables can need more room than a default sized curve account
+  let space = 8 + args.definition.try_to_vec()?.len();
+  if ctx.accounts.curve.to_account_info().data_len() < space {
+    return Err(error!(ErrorCode::CurveAccountTooSmall));
+  }
+
   let curve = &mut ctx.accounts.curve;
   curve.definition = args.definition;
 
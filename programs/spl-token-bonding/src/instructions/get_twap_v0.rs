@@ -0,0 +1,33 @@
+use crate::{oracle::twap, state::*};
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct GetTwapV0Args {
+  pub window_secs: i64,
+}
+
+/// Returns a TwapV0 via return data
+#[derive(Accounts)]
+#[instruction(args: GetTwapV0Args)]
+pub struct GetTwapV0<'info> {
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  #[account(has_one = token_bonding)]
+  pub price_oracle: Box<Account<'info, PriceOracleV0>>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<GetTwapV0>, args: GetTwapV0Args) -> Result<()> {
+  let result = twap(
+    &ctx.accounts.price_oracle,
+    &ctx
+      .accounts
+      .token_bonding
+      .price_accumulator
+      .unwrap_or_default(),
+    ctx.accounts.clock.unix_timestamp,
+    args.window_secs,
+  )?;
+  set_return_data(&result.try_to_vec()?);
+
+  Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::{oracle::record_observation, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializePriceOracleV0Args {
+  pub observation_interval_secs: i64,
+}
+
+/// Anyone can start an oracle for a token bonding. It only observes the price
+#[derive(Accounts)]
+#[instruction(args: InitializePriceOracleV0Args)]
+pub struct InitializePriceOracleV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  #[account(
+    init,
+    seeds = [b"price-oracle", token_bonding.key().as_ref()],
+    bump,
+    payer = payer,
+    space = PRICE_ORACLE_SIZE
+  )]
+  pub price_oracle: Box<Account<'info, PriceOracleV0>>,
+  pub system_program: Program<'info, System>,
+  pub rent: Sysvar<'info, Rent>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(
+  ctx: Context<InitializePriceOracleV0>,
+  args: InitializePriceOracleV0Args,
+) -> Result<()> {
+  let price_oracle = &mut ctx.accounts.price_oracle;
+  price_oracle.token_bonding = ctx.accounts.token_bonding.key();
+  price_oracle.observation_interval_secs = args.observation_interval_secs;
+  price_oracle.bump_seed = *ctx.bumps.get("price_oracle").unwrap();
+  record_observation(
+    price_oracle,
+    &ctx
+      .accounts
+      .token_bonding
+      .price_accumulator
+      .unwrap_or_default(),
+    ctx.accounts.clock.unix_timestamp,
+  );
+
+  Ok(())
+}
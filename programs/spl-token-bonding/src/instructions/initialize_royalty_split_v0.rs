@@ -0,0 +1,108 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RoyaltyRecipientArgsV0 {
+  pub wallet: Pubkey,
+  pub share_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeRoyaltySplitV0Args {
+  pub recipients: Vec<RoyaltyRecipientArgsV0>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeRoyaltySplitV0Args)]
+pub struct InitializeRoyaltySplitV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = ROYALTY_SPLIT_SIZE
+  )]
+  pub royalty_split: Box<Account<'info, RoyaltySplitV0>>,
+  pub mint: Box<Account<'info, Mint>>,
+  #[account(
+    mut,
+    seeds = [b"royalty-split-vault", royalty_split.key().as_ref()],
+    bump
+  )]
+  pub vault: SystemAccount<'info>,
+  #[account(
+    init,
+    seeds = [b"royalty-split-escrow", royalty_split.key().as_ref()],
+    bump,
+    payer = payer,
+    token::mint = mint,
+    token::authority = vault
+  )]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn recipients_are_valid(recipients: &[RoyaltyRecipientArgsV0]) -> bool {
+  !recipients.is_empty() &&
+    recipients.len() <= MAX_ROYALTY_RECIPIENTS &&
+    recipients.iter().all(|r| r.share_bps > 0) &&
+    recipients.iter().map(|r| r.share_bps as u64).sum::<u64>() == 10_000 &&
+    // Each wallet appears once, so claims can't pay the same share twice
+    recipients
+      .iter()
+      .enumerate()
+      .all(|(i, r)| recipients[..i].iter().all(|o| o.wallet != r.wallet))
+}
+
+pub fn handler(
+  ctx: Context<InitializeRoyaltySplitV0>,
+  args: InitializeRoyaltySplitV0Args,
+) -> Result<()> {
+  if !recipients_are_valid(&args.recipients) {
+    return Err(error!(ErrorCode::InvalidRoyaltySplit));
+  }
+
+  // Keep the vault rent exempt so native payouts of any size can land in it
+  let vault = &ctx.accounts.vault;
+  let rent_exempt_lamports = ctx.accounts.rent.minimum_balance(0);
+  if vault.lamports() < rent_exempt_lamports {
+    invoke(
+      &system_instruction::transfer(
+        &ctx.accounts.payer.key(),
+        &vault.key(),
+        rent_exempt_lamports - vault.lamports(),
+      ),
+      &[
+        ctx.accounts.payer.to_account_info().clone(),
+        vault.to_account_info().clone(),
+        ctx.accounts.system_program.to_account_info().clone(),
+      ],
+    )?;
+  }
+
+  let royalty_split = &mut ctx.accounts.royalty_split;
+  royalty_split.mint = ctx.accounts.mint.key();
+  royalty_split.escrow = ctx.accounts.escrow.key();
+  royalty_split.vault = vault.key();
+  royalty_split.recipients = args
+    .recipients
+    .iter()
+    .map(|r| RoyaltyRecipientV0 {
+      wallet: r.wallet,
+      share_bps: r.share_bps,
+      claimed_amount: 0,
+      native_claimed_amount: 0,
+    })
+    .collect();
+  royalty_split.escrow_bump_seed = *ctx.bumps.get("escrow").unwrap();
+  royalty_split.vault_bump_seed = *ctx.bumps.get("vault").unwrap();
+
+  Ok(())
+}
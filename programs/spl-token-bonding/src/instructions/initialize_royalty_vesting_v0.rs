@@ -0,0 +1,73 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeRoyaltyVestingV0Args {
+  // Defaults to when the token bonding goes live
+  pub start_unix_time: Option<i64>,
+  pub cliff_secs: i64,
+  pub duration_secs: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeRoyaltyVestingV0Args)]
+pub struct InitializeRoyaltyVestingV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    constraint = token_bonding.general_authority.ok_or(error!(ErrorCode::NoAuthority))? == general_authority.key(),
+    has_one = target_mint
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub general_authority: Signer<'info>,
+  pub target_mint: Box<Account<'info, Mint>>,
+  #[account(
+    init,
+    seeds = [b"royalty-escrow", token_bonding.key().as_ref()],
+    bump,
+    payer = payer,
+    token::mint = target_mint,
+    token::authority = token_bonding
+  )]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+  ctx: Context<InitializeRoyaltyVestingV0>,
+  args: InitializeRoyaltyVestingV0Args,
+) -> Result<()> {
+  let bonding = &mut ctx.accounts.token_bonding;
+  if bonding.royalties_locked {
+    return Err(error!(ErrorCode::RoyaltiesLocked));
+  }
+  if bonding.royalty_vesting.is_some() {
+    return Err(error!(ErrorCode::RoyaltyVestingAlreadySet));
+  }
+  if args.cliff_secs < 0 || args.duration_secs < args.cliff_secs {
+    return Err(error!(ErrorCode::InvalidVestingSchedule));
+  }
+
+  // Buys mint target royalties into the escrow from now on. The old account receives claims
+  bonding.royalty_vesting = Some(RoyaltyVestingV0 {
+    escrow: ctx.accounts.escrow.key(),
+    destination: bonding.buy_target_royalties,
+    start_unix_time: args.start_unix_time.unwrap_or(bonding.go_live_unix_time),
+    cliff_secs: args.cliff_secs,
+    duration_secs: args.duration_secs,
+    total_vesting_amount: 0,
+    unvested_amount: 0,
+    release_unix_time: 0,
+    end_unix_time: 0,
+    claimed_amount: 0,
+    escrow_bump_seed: *ctx.bumps.get("escrow").unwrap(),
+  });
+  bonding.buy_target_royalties = ctx.accounts.escrow.key();
+
+  Ok(())
+}
@@ -1,4 +1,9 @@
-use crate::{error::ErrorCode, state::*, util::verify_empty_or_mint};
+use super::update_token_bonding_v0::royalty_config;
+use crate::{
+  error::ErrorCode,
+  state::*,
+  util::{verify_empty_or_mint, verify_price_impact_fees, verify_royalty_change_limits},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
@@ -38,6 +43,92 @@ pub struct InitializeTokenBondingV0Args {
   pub initial_supply_pad: u64,
 }
 
+// Taken by initialize_token_bonding_v1. Starts with the v0 fields, so #[instruction] below
+// reads the same `index` from either version
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeTokenBondingV1Args {
+  /// Percentage of purchases that go to the founder
+  /// Percentage Value is (founder_reward_percentage / u32.MAX_VALUE) * 100
+  pub buy_base_royalty_percentage: u32,
+  pub buy_target_royalty_percentage: u32,
+  pub sell_base_royalty_percentage: u32,
+  pub sell_target_royalty_percentage: u32,
+  pub go_live_unix_time: i64,
+  pub freeze_buy_unix_time: Option<i64>, // Cut this bonding curve off at some time
+  // The maximum number of target tokens that can be minted.
+  pub mint_cap: Option<u64>,
+  // The maximum target tokens per purchase
+  pub purchase_cap: Option<u64>,
+  pub general_authority: Option<Pubkey>,
+  pub reserve_authority: Option<Pubkey>,
+  pub curve_authority: Option<Pubkey>,
+  pub buy_frozen: bool,
+  pub index: u16, // A given target mint can have multiple curves associated with it. Index 0 is reserved for the primary curve that holds mint authority
+  pub bump_seed: u8,
+
+  pub sell_frozen: bool,
+
+  /** Whether or not to ignore changes to base storage and target supply outside of the curve */
+  pub ignore_external_reserve_changes: bool,
+  pub ignore_external_supply_changes: bool,
+
+  /**
+   * Allow starting a curve from a later reserve/supply ratio of ignor reserve and supply changes.
+   *
+   * This allows for things like the LBC where you don't need to provide any initial liquidity
+   */
+  pub initial_reserves_pad: u64,
+  pub initial_supply_pad: u64,
+
+  // The maximum target tokens a single wallet can purchase in total
+  pub wallet_purchase_cap: Option<u64>,
+  // Optionally reset each wallet's purchased amount after this many seconds
+  pub wallet_purchase_window_secs: Option<i64>,
+
+  // Merkle root of the wallets allowed to buy before allowlist_end_unix_time
+  pub allowlist_root: Option<[u8; 32]>,
+  pub allowlist_end_unix_time: Option<i64>,
+
+  // Only allow buys from holders of a token or NFT collection
+  pub buy_gate: Option<BuyGateV0>,
+
+  // Raise base royalties on trades that move a large fraction of supply
+  pub price_impact_fees: Option<PriceImpactFeesV0>,
+
+  // Require curve changes to be proposed this many seconds before they take effect
+  pub curve_update_delay_secs: Option<i64>,
+
+  // Limit how quickly the general authority can raise royalties
+  pub royalty_change_limits: Option<RoyaltyChangeLimitsV0>,
+}
+
+impl From<InitializeTokenBondingV0Args> for InitializeTokenBondingV1Args {
+  fn from(args: InitializeTokenBondingV0Args) -> Self {
+    InitializeTokenBondingV1Args {
+      buy_base_royalty_percentage: args.buy_base_royalty_percentage,
+      buy_target_royalty_percentage: args.buy_target_royalty_percentage,
+      sell_base_royalty_percentage: args.sell_base_royalty_percentage,
+      sell_target_royalty_percentage: args.sell_target_royalty_percentage,
+      go_live_unix_time: args.go_live_unix_time,
+      freeze_buy_unix_time: args.freeze_buy_unix_time,
+      mint_cap: args.mint_cap,
+      purchase_cap: args.purchase_cap,
+      general_authority: args.general_authority,
+      reserve_authority: args.reserve_authority,
+      curve_authority: args.curve_authority,
+      buy_frozen: args.buy_frozen,
+      index: args.index,
+      bump_seed: args.bump_seed,
+      sell_frozen: args.sell_frozen,
+      ignore_external_reserve_changes: args.ignore_external_reserve_changes,
+      ignore_external_supply_changes: args.ignore_external_supply_changes,
+      initial_reserves_pad: args.initial_reserves_pad,
+      initial_supply_pad: args.initial_supply_pad,
+      ..Default::default()
+    }
+  }
+}
+
 #[derive(Accounts)]
 #[instruction(args: InitializeTokenBondingV0Args)]
 pub struct InitializeTokenBondingV0<'info> {
@@ -51,7 +142,7 @@ pub struct InitializeTokenBondingV0<'info> {
     // Index 0 is reserved for the primary bonding curve, the one with which new tokens can be minted
     constraint = args.index != 0 || target_mint.mint_authority.unwrap() == token_bonding.key(),
     payer = payer,
-    space = 512
+    space = TOKEN_BONDING_SIZE
   )]
   pub token_bonding: Box<Account<'info, TokenBondingV0>>,
   #[account(
@@ -87,7 +178,7 @@ pub struct InitializeTokenBondingV0<'info> {
 
 pub fn handler(
   ctx: Context<InitializeTokenBondingV0>,
-  args: InitializeTokenBondingV0Args,
+  args: InitializeTokenBondingV1Args,
 ) -> Result<()> {
   verify_empty_or_mint(
     &ctx.accounts.buy_base_royalties,
@@ -137,6 +228,25 @@ pub fn handler(
   bonding.curve = ctx.accounts.curve.key();
   bonding.mint_cap = args.mint_cap;
   bonding.purchase_cap = args.purchase_cap;
+  bonding.wallet_purchase_cap = args.wallet_purchase_cap;
+  bonding.wallet_purchase_window_secs = args.wallet_purchase_window_secs;
+  bonding.allowlist_root = args.allowlist_root;
+  bonding.allowlist_end_unix_time = args.allowlist_end_unix_time;
+  bonding.buy_gate = args.buy_gate;
+  verify_price_impact_fees(&args.price_impact_fees)?;
+  bonding.price_impact_fees = args.price_impact_fees;
+  if args.curve_update_delay_secs.unwrap_or(0) < 0 {
+    return Err(error!(ErrorCode::InvalidCurveUpdateDelay));
+  }
+  bonding.curve_update_delay_secs = args.curve_update_delay_secs;
+  verify_royalty_change_limits(&None, &args.royalty_change_limits)?;
+  bonding.royalty_change_limits = args.royalty_change_limits;
+  if args.royalty_change_limits.is_some() {
+    bonding.royalty_change_period = Some(RoyaltyChangePeriodV0 {
+      start_unix_time: ctx.accounts.clock.unix_timestamp,
+      start_percentages: royalty_config(bonding).limited_percentages(),
+    });
+  }
   // We need to own the mint authority if this bonding curve supports buying.
   // This can be a sell only bonding curve
   bonding.buy_frozen = args.buy_frozen
@@ -0,0 +1,32 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeWalletPurchaseV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  /// CHECK: Only used as a seed. Purchases are tracked for whichever wallet pays for the buy
+  pub wallet: UncheckedAccount<'info>,
+  #[account(
+    init,
+    seeds = [b"wallet-purchase", token_bonding.key().as_ref(), wallet.key().as_ref()],
+    bump,
+    payer = payer,
+    space = 8 + 32 + 32 + 8 + 8 + 1
+  )]
+  pub wallet_purchase: Box<Account<'info, WalletPurchaseV0>>,
+  pub system_program: Program<'info, System>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<InitializeWalletPurchaseV0>) -> Result<()> {
+  let wallet_purchase = &mut ctx.accounts.wallet_purchase;
+  wallet_purchase.token_bonding = ctx.accounts.token_bonding.key();
+  wallet_purchase.wallet = ctx.accounts.wallet.key();
+  wallet_purchase.purchased_amount = 0;
+  wallet_purchase.window_start_unix_time = ctx.accounts.clock.unix_timestamp;
+  wallet_purchase.bump_seed = *ctx.bumps.get("wallet_purchase").unwrap();
+
+  Ok(())
+}
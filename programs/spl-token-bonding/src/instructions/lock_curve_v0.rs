@@ -0,0 +1,21 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+/// Permanently freezes the curve, dropping any pending curve update
+#[derive(Accounts)]
+pub struct LockCurveV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.curve_authority.ok_or(error!(ErrorCode::NoAuthority))? == curve_authority.key()
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<LockCurveV0>) -> Result<()> {
+  let bonding = &mut ctx.accounts.token_bonding;
+  bonding.curve_locked = true;
+  bonding.pending_curve_update = None;
+
+  Ok(())
+}
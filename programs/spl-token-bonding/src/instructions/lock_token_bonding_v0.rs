@@ -0,0 +1,28 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct LockTokenBondingV0Args {
+  pub lock_royalties: bool,
+  pub lock_caps: bool,
+}
+
+/// Permanently freezes parts of the token bonding. Locks can never be undone
+#[derive(Accounts)]
+#[instruction(args: LockTokenBondingV0Args)]
+pub struct LockTokenBondingV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.general_authority.ok_or(error!(ErrorCode::NoAuthority))? == general_authority.key()
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub general_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<LockTokenBondingV0>, args: LockTokenBondingV0Args) -> Result<()> {
+  let bonding = &mut ctx.accounts.token_bonding;
+  bonding.royalties_locked = bonding.royalties_locked || args.lock_royalties;
+  bonding.caps_locked = bonding.caps_locked || args.lock_caps;
+
+  Ok(())
+}
@@ -1,21 +1,61 @@
+pub mod apply_curve_update_v0;
 pub mod buy;
+pub mod cancel_curve_update_v0;
+pub mod claim_royalty_split_v0;
+pub mod claim_vested_royalties_v0;
 pub mod close_token_bonding_v0;
 pub mod create_curve_v0;
+pub mod get_twap_v0;
+pub mod initialize_price_oracle_v0;
+pub mod initialize_royalty_split_v0;
+pub mod initialize_royalty_vesting_v0;
 pub mod initialize_sol_storage_v0;
 pub mod initialize_token_bonding_v0;
+pub mod initialize_wallet_purchase_v0;
+pub mod lock_curve_v0;
+pub mod lock_token_bonding_v0;
+pub mod propose_curve_update_v0;
+pub mod quote_solvency_v0;
+pub mod quote_spot_price_v0;
+pub mod record_price_observation_v0;
+pub mod reserve_staking;
+pub mod resize_token_bonding_v0;
+pub mod route_swap_v0;
 pub mod sell;
+pub mod swap_v0;
 pub mod transfer_reserves;
 pub mod update_curve_v0;
 pub mod update_reserve_authority_v0;
 pub mod update_token_bonding_v0;
+pub mod wind_down_token_bonding_v0;
 
+pub use apply_curve_update_v0::*;
 pub use buy::*;
+pub use cancel_curve_update_v0::*;
+pub use claim_royalty_split_v0::*;
+pub use claim_vested_royalties_v0::*;
 pub use close_token_bonding_v0::*;
 pub use create_curve_v0::*;
+pub use get_twap_v0::*;
+pub use initialize_price_oracle_v0::*;
+pub use initialize_royalty_split_v0::*;
+pub use initialize_royalty_vesting_v0::*;
 pub use initialize_sol_storage_v0::*;
 pub use initialize_token_bonding_v0::*;
+pub use initialize_wallet_purchase_v0::*;
+pub use lock_curve_v0::*;
+pub use lock_token_bonding_v0::*;
+pub use propose_curve_update_v0::*;
+pub use quote_solvency_v0::*;
+pub use quote_spot_price_v0::*;
+pub use record_price_observation_v0::*;
+pub use reserve_staking::*;
+pub use resize_token_bonding_v0::*;
+pub use route_swap_v0::*;
 pub use sell::*;
+pub use swap_v0::*;
 pub use transfer_reserves::*;
 pub use update_curve_v0::*;
 pub use update_reserve_authority_v0::*;
 pub use update_token_bonding_v0::*;
+pub use wind_down_token_bonding_v0::*;
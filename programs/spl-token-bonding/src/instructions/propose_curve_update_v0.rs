@@ -0,0 +1,82 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ProposeCurveUpdateV0Args {
+  // Defaults to the earliest time allowed by the curve update delay
+  pub effective_unix_time: Option<i64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ProposeCurveUpdateV0Args)]
+pub struct ProposeCurveUpdateV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.curve_authority.ok_or(error!(ErrorCode::NoAuthority))? == curve_authority.key(),
+    constraint = !token_bonding.curve_locked @ ErrorCode::CurveLocked
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve_authority: Signer<'info>,
+  pub curve: Box<Account<'info, CurveV0>>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+/// When a curve update proposed at `unix_time` takes effect, failing if `requested` is too early
+pub fn curve_update_effective_time(
+  curve_update_delay_secs: Option<i64>,
+  unix_time: i64,
+  requested: Option<i64>,
+) -> Result<i64> {
+  let earliest = unix_time
+    .checked_add(curve_update_delay_secs.unwrap_or(0))
+    .ok_or_else(|| error!(ErrorCode::ArithmeticError))?;
+  match requested {
+    Some(requested) if requested < earliest => Err(error!(ErrorCode::CurveUpdateTimelocked)),
+    Some(requested) => Ok(requested),
+    None => Ok(earliest),
+  }
+}
+
+pub fn handler(ctx: Context<ProposeCurveUpdateV0>, args: ProposeCurveUpdateV0Args) -> Result<()> {
+  let bonding = &mut ctx.accounts.token_bonding;
+
+  // Replaces any earlier proposal, restarting the timelock
+  bonding.pending_curve_update = Some(PendingCurveUpdateV0 {
+    curve: ctx.accounts.curve.key(),
+    effective_unix_time: curve_update_effective_time(
+      bonding.curve_update_delay_secs,
+      ctx.accounts.clock.unix_timestamp,
+      args.effective_unix_time,
+    )?,
+  });
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_effective_time_defaults_to_delay() {
+    assert_eq!(
+      curve_update_effective_time(Some(100), 1000, None).unwrap(),
+      1100
+    );
+    assert_eq!(curve_update_effective_time(None, 1000, None).unwrap(), 1000);
+  }
+
+  #[test]
+  fn test_effective_time_respects_delay() {
+    assert_eq!(
+      curve_update_effective_time(Some(100), 1000, Some(1100)).unwrap(),
+      1100
+    );
+    assert_eq!(
+      curve_update_effective_time(Some(100), 1000, Some(5000)).unwrap(),
+      5000
+    );
+    assert!(curve_update_effective_time(Some(100), 1000, Some(1099)).is_err());
+    assert!(curve_update_effective_time(Some(i64::MAX), 1000, None).is_err());
+  }
+}
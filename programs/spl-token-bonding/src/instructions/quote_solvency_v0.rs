@@ -0,0 +1,42 @@
+use crate::{
+  quote::{liquid_reserves, solvency, QuoteState},
+  state::*,
+};
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token::{Mint, TokenAccount};
+
+/// Returns a SolvencyV0 via return data
+#[derive(Accounts)]
+pub struct QuoteSolvencyV0<'info> {
+  #[account(
+    has_one = base_mint,
+    has_one = target_mint,
+    has_one = base_storage,
+    has_one = curve
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve: Box<Account<'info, CurveV0>>,
+  pub base_mint: Box<Account<'info, Mint>>,
+  pub target_mint: Box<Account<'info, Mint>>,
+  pub base_storage: Box<Account<'info, TokenAccount>>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<QuoteSolvencyV0>) -> Result<()> {
+  let state = QuoteState::from_accounts(
+    &ctx.accounts.base_mint,
+    &ctx.accounts.target_mint,
+    &ctx.accounts.base_storage,
+    &ctx.accounts.clock,
+  );
+  // The bonding's staked lamports cannot be paid out until they are unstaked
+  let solvency = solvency(
+    &ctx.accounts.token_bonding,
+    &ctx.accounts.curve,
+    &state,
+    liquid_reserves(&ctx.accounts.token_bonding, state.base_storage_amount),
+  )?;
+  set_return_data(&solvency.try_to_vec()?);
+
+  Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::{
+  quote::{quote_spot_price, QuoteState},
+  state::*,
+};
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token::{Mint, TokenAccount};
+
+/// Returns a SpotPriceV0 via return data
+#[derive(Accounts)]
+pub struct QuoteSpotPriceV0<'info> {
+  #[account(
+    has_one = base_mint,
+    has_one = target_mint,
+    has_one = base_storage,
+    has_one = curve
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve: Box<Account<'info, CurveV0>>,
+  pub base_mint: Box<Account<'info, Mint>>,
+  pub target_mint: Box<Account<'info, Mint>>,
+  pub base_storage: Box<Account<'info, TokenAccount>>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<QuoteSpotPriceV0>) -> Result<()> {
+  let state = QuoteState::from_accounts(
+    &ctx.accounts.base_mint,
+    &ctx.accounts.target_mint,
+    &ctx.accounts.base_storage,
+    &ctx.accounts.clock,
+  );
+  let spot_price = quote_spot_price(&ctx.accounts.token_bonding, &ctx.accounts.curve, &state)?;
+  set_return_data(&spot_price.try_to_vec()?);
+
+  Ok(())
+}
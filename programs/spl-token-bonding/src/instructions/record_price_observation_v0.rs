@@ -0,0 +1,28 @@
+use crate::{oracle::record_observation, state::*};
+use anchor_lang::prelude::*;
+
+/// Permissionless crank, so the oracle keeps observations while nobody passes it to trades
+#[derive(Accounts)]
+pub struct RecordPriceObservationV0<'info> {
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  #[account(
+    mut,
+    has_one = token_bonding
+  )]
+  pub price_oracle: Box<Account<'info, PriceOracleV0>>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<RecordPriceObservationV0>) -> Result<()> {
+  record_observation(
+    &mut ctx.accounts.price_oracle,
+    &ctx
+      .accounts
+      .token_bonding
+      .price_accumulator
+      .unwrap_or_default(),
+    ctx.accounts.clock.unix_timestamp,
+  );
+
+  Ok(())
+}
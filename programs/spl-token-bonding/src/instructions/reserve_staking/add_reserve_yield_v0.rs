@@ -0,0 +1,53 @@
+use super::{claim_reserve_yield_v0::accrued_yield, reserve_staking_account_common::*};
+use crate::{error::ErrorCode, state::*, util::OrArithError};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+/// Adds a native token bonding's staking yield to its reserves, minted as wrapped sol into its
+/// base storage. The lamports stay staked
+#[derive(Accounts)]
+pub struct AddReserveYieldV0<'info> {
+  pub common: ReserveStakingCommonV0<'info>,
+  #[account(address = common.reserve_staking.authority)]
+  pub authority: Signer<'info>,
+  #[account(
+    seeds = ["wrapped-sol-authority".as_bytes()],
+    bump = common.state.mint_authority_bump_seed
+  )]
+  /// CHECK: Authority, used in CPIs
+  pub mint_authority: AccountInfo<'info>,
+  #[account(
+    mut,
+    has_one = base_storage,
+    constraint = token_bonding.reserve_stake.is_some() @ ErrorCode::ReserveStakeNotInitialized
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  #[account(mut)]
+  pub base_storage: Box<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<AddReserveYieldV0>) -> Result<()> {
+  let common = &ctx.accounts.common;
+  let token_bonding = &mut ctx.accounts.token_bonding;
+  let mut stake = token_bonding.reserve_stake.unwrap();
+  let (accrued, _) = accrued_yield(common, &stake)?;
+
+  // The new wrapped sol is backed by lamports already in the pool
+  stake.staked_lamports = stake
+    .staked_lamports
+    .checked_add(accrued)
+    .or_arith_error()?;
+  stake.total_yield_lamports = stake
+    .total_yield_lamports
+    .checked_add(accrued)
+    .or_arith_error()?;
+  token_bonding.reserve_stake = Some(stake);
+
+  add_to_reserves(
+    common,
+    &ctx.accounts.mint_authority,
+    token_bonding,
+    &mut ctx.accounts.base_storage,
+    accrued,
+  )
+}
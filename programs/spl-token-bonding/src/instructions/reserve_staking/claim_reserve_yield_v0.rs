@@ -0,0 +1,75 @@
+use super::reserve_staking_account_common::*;
+use crate::{
+  error::ErrorCode,
+  stake_pool::{pool_tokens_for_lamports, pool_tokens_value, StakePoolTotals},
+  state::*,
+  util::OrArithError,
+};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::invoke_signed, system_instruction},
+};
+
+#[derive(Accounts)]
+pub struct ClaimReserveYieldV0<'info> {
+  pub common: ReserveStakingCommonV0<'info>,
+  #[account(address = common.reserve_staking.authority)]
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = token_bonding.reserve_stake.is_some() @ ErrorCode::ReserveStakeNotInitialized
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  #[account(mut)]
+  pub destination: SystemAccount<'info>,
+}
+
+/// Lamports the bonding's pool tokens are worth above its staked lamports
+pub fn accrued_yield(
+  common: &ReserveStakingCommonV0,
+  stake: &ReserveStakeV0,
+) -> Result<(u64, StakePoolTotals)> {
+  let totals = fresh_stake_pool_totals(common)?;
+  let value = pool_tokens_value(stake.pool_tokens, &totals).or_arith_error()?;
+  let accrued = value.saturating_sub(stake.staked_lamports);
+  if accrued == 0 {
+    return Err(error!(ErrorCode::NoReserveYield));
+  }
+
+  Ok((accrued, totals))
+}
+
+pub fn handler(ctx: Context<ClaimReserveYieldV0>) -> Result<()> {
+  let common = &ctx.accounts.common;
+  let mut stake = ctx.accounts.token_bonding.reserve_stake.unwrap();
+  let (accrued, totals) = accrued_yield(common, &stake)?;
+
+  // Rounds down, so the withdrawal fee comes out of the yield and what is left still covers the
+  // staked lamports
+  let pool_tokens = pool_tokens_for_lamports(accrued, &totals).or_arith_error()?;
+  let (received, _) = unstake(common, &mut stake, pool_tokens, &totals)?;
+  invoke_signed(
+    &system_instruction::transfer(
+      &common.sol_storage.key(),
+      &ctx.accounts.destination.key(),
+      received,
+    ),
+    &[
+      common.sol_storage.to_account_info().clone(),
+      ctx.accounts.destination.to_account_info().clone(),
+      common.system_program.to_account_info().clone(),
+    ],
+    &[&[
+      "sol-storage".as_bytes(),
+      &[common.state.sol_storage_bump_seed],
+    ]],
+  )?;
+
+  stake.total_yield_lamports = stake
+    .total_yield_lamports
+    .checked_add(received)
+    .or_arith_error()?;
+  ctx.accounts.token_bonding.reserve_stake = Some(stake);
+
+  Ok(())
+}
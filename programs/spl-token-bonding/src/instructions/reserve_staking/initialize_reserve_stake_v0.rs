@@ -0,0 +1,28 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+/// Opts a native token bonding into reserve staking. Rebalances then stake up to the reserve
+/// staking percentage of its base storage
+#[derive(Accounts)]
+pub struct InitializeReserveStakeV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.general_authority.ok_or(error!(ErrorCode::NoAuthority))? == general_authority.key(),
+    constraint = token_bonding.base_mint == state.wrapped_sol_mint @ ErrorCode::InvalidMint,
+    constraint = token_bonding.reserve_stake.is_none()
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub general_authority: Signer<'info>,
+  pub state: Box<Account<'info, ProgramStateV0>>,
+  #[account(
+    seeds = [b"reserve-staking"],
+    bump = reserve_staking.bump_seed
+  )]
+  pub reserve_staking: Box<Account<'info, ReserveStakingV0>>,
+}
+
+pub fn handler(ctx: Context<InitializeReserveStakeV0>) -> Result<()> {
+  ctx.accounts.token_bonding.reserve_stake = Some(ReserveStakeV0::default());
+
+  Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::{error::ErrorCode, stake_pool::stake_pool_totals, state::*};
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeReserveStakingV0Args {
+  pub authority: Pubkey,
+  pub max_staked_percentage: u32,
+}
+
+/// Staking puts every holder's wrapped sol at the mercy of the stake pool, so only the program's
+/// upgrade authority may choose it
+#[derive(Accounts)]
+#[instruction(args: InitializeReserveStakingV0Args)]
+pub struct InitializeReserveStakingV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub upgrade_authority: Signer<'info>,
+  #[account(
+    seeds = [crate::id().as_ref()],
+    bump,
+    seeds::program = bpf_loader_upgradeable::id(),
+    constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+  )]
+  pub program_data: Box<Account<'info, ProgramData>>,
+  #[account(has_one = sol_storage)]
+  pub state: Box<Account<'info, ProgramStateV0>>,
+  #[account(
+    init,
+    seeds = [b"reserve-staking"],
+    bump,
+    payer = payer,
+    space = RESERVE_STAKING_SIZE
+  )]
+  pub reserve_staking: Box<Account<'info, ReserveStakingV0>>,
+  /// CHECK: Parsed by stake_pool_totals, which checks the owner
+  pub stake_pool: AccountInfo<'info>,
+  /// CHECK: Any stake pool program deployment, so staking can be tested against a local one
+  #[account(executable)]
+  pub stake_pool_program: AccountInfo<'info>,
+  pub pool_mint: Box<Account<'info, Mint>>,
+  pub sol_storage: SystemAccount<'info>,
+  #[account(
+    init,
+    seeds = [b"reserve-staking-pool-tokens"],
+    bump,
+    payer = payer,
+    token::mint = pool_mint,
+    token::authority = sol_storage
+  )]
+  pub pool_tokens: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+  ctx: Context<InitializeReserveStakingV0>,
+  args: InitializeReserveStakingV0Args,
+) -> Result<()> {
+  let totals = stake_pool_totals(
+    &ctx.accounts.stake_pool,
+    &ctx.accounts.stake_pool_program.key(),
+  )?;
+  if totals.pool_mint != ctx.accounts.pool_mint.key() {
+    return Err(error!(ErrorCode::InvalidStakePool));
+  }
+  if args.max_staked_percentage > MAX_STAKED_PERCENTAGE {
+    return Err(error!(ErrorCode::InvalidMaxStakedPercentage));
+  }
+
+  let reserve_staking = &mut ctx.accounts.reserve_staking;
+  reserve_staking.authority = args.authority;
+  reserve_staking.stake_pool_program = ctx.accounts.stake_pool_program.key();
+  reserve_staking.stake_pool = ctx.accounts.stake_pool.key();
+  reserve_staking.pool_tokens = ctx.accounts.pool_tokens.key();
+  reserve_staking.max_staked_percentage = args.max_staked_percentage;
+  reserve_staking.bump_seed = *ctx.bumps.get("reserve_staking").unwrap();
+  reserve_staking.pool_tokens_bump_seed = *ctx.bumps.get("pool_tokens").unwrap();
+
+  Ok(())
+}
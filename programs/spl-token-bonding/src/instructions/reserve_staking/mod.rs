@@ -0,0 +1,15 @@
+pub mod add_reserve_yield_v0;
+pub mod claim_reserve_yield_v0;
+pub mod initialize_reserve_stake_v0;
+pub mod initialize_reserve_staking_v0;
+pub mod rebalance_reserve_staking_v0;
+pub mod reserve_staking_account_common;
+pub mod update_reserve_staking_v0;
+
+pub use add_reserve_yield_v0::*;
+pub use claim_reserve_yield_v0::*;
+pub use initialize_reserve_stake_v0::*;
+pub use initialize_reserve_staking_v0::*;
+pub use rebalance_reserve_staking_v0::*;
+pub use reserve_staking_account_common::*;
+pub use update_reserve_staking_v0::*;
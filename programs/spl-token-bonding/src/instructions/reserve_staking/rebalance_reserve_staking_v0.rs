@@ -0,0 +1,109 @@
+use super::reserve_staking_account_common::*;
+use crate::{
+  error::ErrorCode,
+  stake_pool::{pool_tokens_for_at_least, staking_target},
+  state::*,
+  util::OrArithError,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+/// Permissionless crank that stakes a native token bonding's idle reserves, or unstakes to refill
+/// its buffer in sol storage after sells have drawn it down. Losses to slashing and pool fees are
+/// burned from the bonding's base storage first
+#[derive(Accounts)]
+pub struct RebalanceReserveStakingV0<'info> {
+  pub common: ReserveStakingCommonV0<'info>,
+  #[account(
+    mut,
+    has_one = base_storage,
+    constraint = token_bonding.reserve_stake.is_some() @ ErrorCode::ReserveStakeNotInitialized
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  #[account(mut)]
+  pub base_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    seeds = ["wrapped-sol-authority".as_bytes()],
+    bump = common.state.mint_authority_bump_seed
+  )]
+  /// CHECK: Authority, used in CPIs
+  pub mint_authority: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<RebalanceReserveStakingV0>) -> Result<()> {
+  let common = &mut ctx.accounts.common;
+  let token_bonding = &mut ctx.accounts.token_bonding;
+  let base_storage = &mut ctx.accounts.base_storage;
+
+  let totals = fresh_stake_pool_totals(common)?;
+  recognise_losses(common, token_bonding, base_storage, &totals)?;
+
+  let mut stake = token_bonding.reserve_stake.unwrap();
+  // Wound down bondings unstake everything, so they can close once every holder has redeemed
+  let target = if token_bonding.wind_down {
+    0
+  } else {
+    staking_target(
+      base_storage.amount,
+      common.reserve_staking.max_staked_percentage,
+    )?
+  };
+  let staked = stake.staked_lamports;
+
+  if staked < target {
+    // Sol storage is a system account, keep it rent exempt
+    let idle = common
+      .sol_storage
+      .lamports()
+      .saturating_sub(Rent::get()?.minimum_balance(0));
+    let lamports = (target - staked).min(idle);
+    if lamports == 0 {
+      return Ok(());
+    }
+
+    let pool_tokens_before = common.pool_tokens.amount;
+    deposit_reserves(common, lamports)?;
+    common.pool_tokens.reload()?;
+    stake.pool_tokens = stake
+      .pool_tokens
+      .checked_add(common.pool_tokens.amount - pool_tokens_before)
+      .or_arith_error()?;
+    stake.staked_lamports = staked.checked_add(lamports).or_arith_error()?;
+    token_bonding.reserve_stake = Some(stake);
+  } else if staked > target || (target == 0 && stake.pool_tokens > 0) {
+    let pool_tokens = if target == 0 {
+      stake.pool_tokens
+    } else {
+      pool_tokens_for_at_least(staked - target, &totals)
+        .or_arith_error()?
+        .min(stake.pool_tokens)
+    };
+    let (received, _) = unstake(common, &mut stake, pool_tokens, &totals)?;
+    stake.staked_lamports = staked.saturating_sub(received);
+
+    // Only a full unstake can receive more than was staked. The rest is yield
+    let surplus = received.saturating_sub(staked);
+    if surplus > 0 {
+      stake.total_yield_lamports = stake
+        .total_yield_lamports
+        .checked_add(surplus)
+        .or_arith_error()?;
+    }
+    token_bonding.reserve_stake = Some(stake);
+    if surplus > 0 {
+      add_to_reserves(
+        common,
+        &ctx.accounts.mint_authority,
+        token_bonding,
+        base_storage,
+        surplus,
+      )?;
+    }
+  } else {
+    return Ok(());
+  }
+
+  // Deposit and withdrawal fees show up as a loss against the pool's new totals
+  let totals = fresh_stake_pool_totals(common)?;
+  recognise_losses(common, token_bonding, base_storage, &totals)
+}
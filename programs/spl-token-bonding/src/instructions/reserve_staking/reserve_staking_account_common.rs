@@ -0,0 +1,350 @@
+use crate::{
+  error::ErrorCode,
+  stake_pool::{
+    deposit_sol, pool_tokens_for_at_least, pool_tokens_value, stake_pool_totals, withdraw_sol,
+    StakePoolInstructionAccounts, StakePoolTotals,
+  },
+  state::*,
+  util::OrArithError,
+};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::invoke_signed, stake, sysvar},
+};
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ReserveStakingCommonV0<'info> {
+  #[account(
+    has_one = sol_storage,
+    has_one = wrapped_sol_mint
+  )]
+  pub state: Box<Account<'info, ProgramStateV0>>,
+  #[account(
+    mut,
+    seeds = [b"reserve-staking"],
+    bump = reserve_staking.bump_seed,
+    has_one = stake_pool_program,
+    has_one = stake_pool,
+    has_one = pool_tokens
+  )]
+  pub reserve_staking: Box<Account<'info, ReserveStakingV0>>,
+  #[account(mut)]
+  pub wrapped_sol_mint: Box<Account<'info, Mint>>,
+  #[account(mut)]
+  pub sol_storage: SystemAccount<'info>,
+  #[account(mut)]
+  pub pool_tokens: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Checked against reserve staking, and parsed by stake_pool_totals
+  #[account(mut)]
+  pub stake_pool: AccountInfo<'info>,
+  /// CHECK: Checked by the stake pool program
+  pub stake_pool_withdraw_authority: AccountInfo<'info>,
+  /// CHECK: Checked by the stake pool program
+  #[account(mut)]
+  pub reserve_stake: AccountInfo<'info>,
+  /// CHECK: Checked by the stake pool program
+  #[account(mut)]
+  pub manager_fee_account: AccountInfo<'info>,
+  /// CHECK: Checked by the stake pool program
+  #[account(mut)]
+  pub pool_mint: AccountInfo<'info>,
+  /// CHECK: Checked against reserve staking
+  #[account(executable)]
+  pub stake_pool_program: AccountInfo<'info>,
+  pub clock: Sysvar<'info, Clock>,
+  /// CHECK: Stake history sysvar, needed to withdraw from the pool's reserve stake
+  #[account(address = sysvar::stake_history::id())]
+  pub stake_history: AccountInfo<'info>,
+  /// CHECK: Native stake program
+  #[account(address = stake::program::id())]
+  pub stake_program: AccountInfo<'info>,
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+// Accounts in ReserveStakingCommonV0
+pub const RESERVE_STAKING_ACCOUNTS: usize = 16;
+
+/// Reads ReserveStakingCommonV0 off the front of `accounts`, in the same order, for sells that
+/// take it as remaining accounts. None if there are no accounts left
+pub fn take_reserve_staking<'a, 'info>(
+  accounts: &'a [AccountInfo<'info>],
+) -> Result<(
+  Option<ReserveStakingCommonV0<'info>>,
+  &'a [AccountInfo<'info>],
+)> {
+  if accounts.is_empty() {
+    return Ok((None, accounts));
+  }
+  if accounts.len() < RESERVE_STAKING_ACCOUNTS {
+    return Err(error!(ErrorCode::InvalidStakePool));
+  }
+
+  let (accounts, rest) = accounts.split_at(RESERVE_STAKING_ACCOUNTS);
+  // State and reserve staking are both created at fixed seeds, so the owner and discriminator
+  // checks in Account::try_from are enough to know they are the real ones
+  let common = ReserveStakingCommonV0 {
+    state: Box::new(Account::try_from(&accounts[0])?),
+    reserve_staking: Box::new(Account::try_from(&accounts[1])?),
+    wrapped_sol_mint: Box::new(Account::try_from(&accounts[2])?),
+    sol_storage: SystemAccount::try_from(&accounts[3])?,
+    pool_tokens: Box::new(Account::try_from(&accounts[4])?),
+    stake_pool: accounts[5].clone(),
+    stake_pool_withdraw_authority: accounts[6].clone(),
+    reserve_stake: accounts[7].clone(),
+    manager_fee_account: accounts[8].clone(),
+    pool_mint: accounts[9].clone(),
+    stake_pool_program: accounts[10].clone(),
+    clock: Sysvar::from_account_info(&accounts[11])?,
+    stake_history: accounts[12].clone(),
+    stake_program: accounts[13].clone(),
+    token_program: Program::try_from(&accounts[14])?,
+    system_program: Program::try_from(&accounts[15])?,
+  };
+  if common.state.sol_storage != common.sol_storage.key()
+    || common.state.wrapped_sol_mint != common.wrapped_sol_mint.key()
+    || common.reserve_staking.stake_pool_program != common.stake_pool_program.key()
+    || common.reserve_staking.stake_pool != common.stake_pool.key()
+    || common.reserve_staking.pool_tokens != common.pool_tokens.key()
+    || common.stake_history.key() != sysvar::stake_history::id()
+    || common.stake_program.key() != stake::program::id()
+  {
+    return Err(error!(ErrorCode::InvalidStakePool));
+  }
+
+  Ok((Some(common), rest))
+}
+
+/// The stake pool's totals, which must be up to date. A stale pool undervalues pool tokens, or
+/// overvalues them after a slashing
+pub fn fresh_stake_pool_totals(common: &ReserveStakingCommonV0) -> Result<StakePoolTotals> {
+  let totals = stake_pool_totals(&common.stake_pool, &common.stake_pool_program.key())?;
+  if totals.last_update_epoch != common.clock.epoch {
+    return Err(error!(ErrorCode::StakePoolNotUpdated));
+  }
+
+  Ok(totals)
+}
+
+fn instruction_accounts(common: &ReserveStakingCommonV0) -> StakePoolInstructionAccounts {
+  StakePoolInstructionAccounts {
+    stake_pool_program: common.stake_pool_program.key(),
+    stake_pool: common.stake_pool.key(),
+    withdraw_authority: common.stake_pool_withdraw_authority.key(),
+    reserve_stake: common.reserve_stake.key(),
+    manager_fee_account: common.manager_fee_account.key(),
+    pool_mint: common.pool_mint.key(),
+    token_program: common.token_program.key(),
+  }
+}
+
+/// Move `lamports` from sol storage into the stake pool
+pub fn deposit_reserves(common: &ReserveStakingCommonV0, lamports: u64) -> Result<()> {
+  invoke_signed(
+    &deposit_sol(
+      &instruction_accounts(common),
+      &common.sol_storage.key(),
+      &common.pool_tokens.key(),
+      lamports,
+    ),
+    &[
+      common.stake_pool.clone(),
+      common.stake_pool_withdraw_authority.clone(),
+      common.reserve_stake.clone(),
+      common.sol_storage.to_account_info().clone(),
+      common.pool_tokens.to_account_info().clone(),
+      common.manager_fee_account.clone(),
+      common.pool_mint.clone(),
+      common.system_program.to_account_info().clone(),
+      common.token_program.to_account_info().clone(),
+      common.stake_pool_program.clone(),
+    ],
+    &[&[
+      "sol-storage".as_bytes(),
+      &[common.state.sol_storage_bump_seed],
+    ]],
+  )?;
+
+  Ok(())
+}
+
+/// Burn `pool_tokens` for lamports back into sol storage. Returns the lamports received, which is
+/// after any withdrawal fee
+pub fn withdraw_reserves(common: &ReserveStakingCommonV0, pool_tokens: u64) -> Result<u64> {
+  let lamports_before = common.sol_storage.lamports();
+  invoke_signed(
+    &withdraw_sol(
+      &instruction_accounts(common),
+      &common.sol_storage.key(),
+      &common.pool_tokens.key(),
+      &common.sol_storage.key(),
+      pool_tokens,
+    ),
+    &[
+      common.stake_pool.clone(),
+      common.stake_pool_withdraw_authority.clone(),
+      common.sol_storage.to_account_info().clone(),
+      common.pool_tokens.to_account_info().clone(),
+      common.reserve_stake.clone(),
+      common.manager_fee_account.clone(),
+      common.pool_mint.clone(),
+      common.clock.to_account_info().clone(),
+      common.stake_history.clone(),
+      common.stake_program.clone(),
+      common.token_program.to_account_info().clone(),
+      common.stake_pool_program.clone(),
+    ],
+    &[&[
+      "sol-storage".as_bytes(),
+      &[common.state.sol_storage_bump_seed],
+    ]],
+  )?;
+
+  Ok(
+    common
+      .sol_storage
+      .lamports()
+      .saturating_sub(lamports_before),
+  )
+}
+
+fn burn_from_base_storage<'info>(
+  common: &ReserveStakingCommonV0<'info>,
+  token_bonding: &Account<'info, TokenBondingV0>,
+  base_storage: &mut Account<'info, TokenAccount>,
+  amount: u64,
+) -> Result<()> {
+  token::burn(
+    CpiContext::new_with_signer(
+      common.token_program.to_account_info().clone(),
+      Burn {
+        mint: common.wrapped_sol_mint.to_account_info().clone(),
+        from: base_storage.to_account_info().clone(),
+        authority: token_bonding.to_account_info().clone(),
+      },
+      &[&[
+        b"token-bonding",
+        token_bonding.target_mint.as_ref(),
+        &token_bonding.index.to_le_bytes(),
+        &[token_bonding.bump_seed],
+      ]],
+    ),
+    amount,
+  )?;
+  base_storage.reload()
+}
+
+/// Burns whatever the bonding's stake has lost to slashing or fees from its base storage, so its
+/// reserves only count lamports that still exist
+pub fn recognise_losses<'info>(
+  common: &ReserveStakingCommonV0<'info>,
+  token_bonding: &mut Account<'info, TokenBondingV0>,
+  base_storage: &mut Account<'info, TokenAccount>,
+  totals: &StakePoolTotals,
+) -> Result<()> {
+  let mut stake = match token_bonding.reserve_stake {
+    Some(stake) => stake,
+    None => return Ok(()),
+  };
+  let value = pool_tokens_value(stake.pool_tokens, totals).or_arith_error()?;
+  let loss = stake.staked_lamports.saturating_sub(value);
+  if loss == 0 {
+    return Ok(());
+  }
+
+  msg!("Burning {} lost in the stake pool from reserves", loss);
+  burn_from_base_storage(common, token_bonding, base_storage, loss)?;
+  stake.staked_lamports -= loss;
+  stake.total_loss_lamports = stake
+    .total_loss_lamports
+    .checked_add(loss)
+    .or_arith_error()?;
+  token_bonding.reserve_stake = Some(stake);
+  token_bonding.reserve_balance_from_bonding = token_bonding
+    .reserve_balance_from_bonding
+    .saturating_sub(loss);
+
+  Ok(())
+}
+
+/// Burns `pool_tokens` of the stake's pool tokens for lamports into sol storage. Returns the
+/// lamports received and what the pool tokens were worth. The difference is the withdrawal fee
+pub fn unstake(
+  common: &ReserveStakingCommonV0,
+  stake: &mut ReserveStakeV0,
+  pool_tokens: u64,
+  totals: &StakePoolTotals,
+) -> Result<(u64, u64)> {
+  if pool_tokens == 0 {
+    return Ok((0, 0));
+  }
+
+  let value = pool_tokens_value(pool_tokens, totals).or_arith_error()?;
+  let received = withdraw_reserves(common, pool_tokens)?;
+  stake.pool_tokens = stake
+    .pool_tokens
+    .checked_sub(pool_tokens)
+    .or_arith_error()?;
+
+  Ok((received, value))
+}
+
+/// Mints `lamports` already in sol storage or the stake pool into base storage as wrapped sol
+pub fn add_to_reserves<'info>(
+  common: &ReserveStakingCommonV0<'info>,
+  mint_authority: &AccountInfo<'info>,
+  token_bonding: &mut Account<'info, TokenBondingV0>,
+  base_storage: &mut Account<'info, TokenAccount>,
+  lamports: u64,
+) -> Result<()> {
+  token::mint_to(
+    CpiContext::new_with_signer(
+      common.token_program.to_account_info().clone(),
+      MintTo {
+        mint: common.wrapped_sol_mint.to_account_info().clone(),
+        to: base_storage.to_account_info().clone(),
+        authority: mint_authority.clone(),
+      },
+      &[&[
+        b"wrapped-sol-authority",
+        &[common.state.mint_authority_bump_seed],
+      ]],
+    ),
+    lamports,
+  )?;
+  token_bonding.reserve_balance_from_bonding = token_bonding
+    .reserve_balance_from_bonding
+    .checked_add(lamports)
+    .or_arith_error()?;
+  base_storage.reload()
+}
+
+/// Unstakes at least `lamports` of the bonding's stake so a sell can be paid out of sol storage.
+/// The seller pays the withdrawal fee, which is burned from base storage and returned so it can
+/// come out of what the seller reclaims
+pub fn unstake_for_sell<'info>(
+  common: &ReserveStakingCommonV0<'info>,
+  token_bonding: &mut Account<'info, TokenBondingV0>,
+  base_storage: &mut Account<'info, TokenAccount>,
+  lamports: u64,
+) -> Result<u64> {
+  let totals = fresh_stake_pool_totals(common)?;
+  let mut stake = token_bonding
+    .reserve_stake
+    .ok_or_else(|| error!(ErrorCode::ReservesNotLiquid))?;
+  let pool_tokens = pool_tokens_for_at_least(lamports, &totals)
+    .or_arith_error()?
+    .min(stake.pool_tokens);
+  let (received, value) = unstake(common, &mut stake, pool_tokens, &totals)?;
+  msg!("Unstaked {} for the sell, worth {}", received, value);
+  stake.staked_lamports = stake.staked_lamports.saturating_sub(value);
+  token_bonding.reserve_stake = Some(stake);
+
+  let fee = value.saturating_sub(received);
+  if fee > 0 {
+    burn_from_base_storage(common, token_bonding, base_storage, fee)?;
+  }
+
+  Ok(fee)
+}
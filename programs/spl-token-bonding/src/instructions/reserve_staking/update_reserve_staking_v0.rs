@@ -0,0 +1,37 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateReserveStakingV0Args {
+  pub new_authority: Pubkey,
+  /// Lowering this only unstakes on the next rebalance_reserve_staking_v0
+  pub max_staked_percentage: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateReserveStakingV0Args)]
+pub struct UpdateReserveStakingV0<'info> {
+  #[account(
+    mut,
+    seeds = [b"reserve-staking"],
+    bump = reserve_staking.bump_seed,
+    has_one = authority
+  )]
+  pub reserve_staking: Box<Account<'info, ReserveStakingV0>>,
+  pub authority: Signer<'info>,
+}
+
+pub fn handler(
+  ctx: Context<UpdateReserveStakingV0>,
+  args: UpdateReserveStakingV0Args,
+) -> Result<()> {
+  if args.max_staked_percentage > MAX_STAKED_PERCENTAGE {
+    return Err(error!(ErrorCode::InvalidMaxStakedPercentage));
+  }
+
+  let reserve_staking = &mut ctx.accounts.reserve_staking;
+  reserve_staking.authority = args.new_authority;
+  reserve_staking.max_staked_percentage = args.max_staked_percentage;
+
+  Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::state::*;
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::invoke, system_instruction},
+  Discriminator,
+};
+
+#[derive(Accounts)]
+pub struct ResizeTokenBondingV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(mut, owner = crate::id())]
+  /// CHECK: Only the buffer is grown, so the bonding is never deserialized or written back. The discriminator is checked by hand instead
+  pub token_bonding: UncheckedAccount<'info>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ResizeTokenBondingV0>) -> Result<()> {
+  let token_bonding = ctx.accounts.token_bonding.to_account_info();
+  if token_bonding.try_borrow_data()?.get(..8) != Some(&TokenBondingV0::discriminator()[..]) {
+    return Err(error!(
+      anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    ));
+  }
+
+  let old_size = token_bonding.data_len();
+  if old_size >= TOKEN_BONDING_SIZE {
+    return Ok(());
+  }
+
+  let lamports_diff = Rent::get()?
+    .minimum_balance(TOKEN_BONDING_SIZE)
+    .saturating_sub(token_bonding.lamports());
+  msg!(
+    "Resizing from {} to {} with lamports {}",
+    old_size,
+    TOKEN_BONDING_SIZE,
+    lamports_diff
+  );
+  if lamports_diff > 0 {
+    invoke(
+      &system_instruction::transfer(
+        &ctx.accounts.payer.key(),
+        &token_bonding.key(),
+        lamports_diff,
+      ),
+      &[
+        ctx.accounts.payer.to_account_info(),
+        token_bonding.clone(),
+        ctx.accounts.system_program.to_account_info(),
+      ],
+    )?;
+  }
+
+  token_bonding.realloc(TOKEN_BONDING_SIZE, true)?;
+
+  Ok(())
+}
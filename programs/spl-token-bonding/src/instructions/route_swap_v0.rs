@@ -0,0 +1,400 @@
+use super::{
+  buy::{
+    buy_account_common::*,
+    buy_arg_common::{BuyV1Args, BuyWithBaseV0Args},
+    buy_wrapped_sol_v0::{buy_wrapped_sol, BuyWrappedSolV0, BuyWrappedSolV0Args},
+    common::{buy_shared_logic, mint_to_dest, BuyAmount},
+  },
+  sell::{
+    common::{burn_and_pay_sell_royalties, emit_sell_event, sell_shared_logic, SellAmount},
+    sell_account_common::*,
+    sell_arg_common::SellV2Args,
+    sell_wrapped_sol_v0::{sell_wrapped_sol, SellWrappedSolV0, SellWrappedSolV0Args},
+  },
+};
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::token::{self, Mint, Token, Transfer};
+
+// Each hop is passed as remaining accounts, starting with these in order:
+//   token_bonding, curve, base_mint, target_mint, base_storage,
+//   base_royalties, target_royalties, destination
+// Royalty accounts are the buy royalties when the hop buys and the sell royalties when it sells.
+// The destination receives the hop output and is the source of the next hop. They are followed by
+// the hop's extra accounts, which are the remaining accounts of the buy or sell: an optional price
+// oracle, then when buying the buyer's wallet purchase and the buy gate holding accounts, if the
+// token bonding has them.
+pub const ROUTE_HOP_ACCOUNTS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RouteHopV0 {
+  // Number of accounts passed after the hop's ROUTE_HOP_ACCOUNTS
+  pub extra_accounts: u8,
+  // Merkle proof that the source authority is on the hop's allowlist. Only read when the hop buys
+  pub allowlist_proof: Option<Vec<[u8; 32]>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RouteSwapV0Args {
+  // Mint going into the first hop. Use the wrapped sol mint to route from native sol
+  pub input_mint: Pubkey,
+  pub amount: u64,
+  // Minimum amount the last hop must output, after royalties on every hop.
+  pub minimum_output_amount: u64,
+  pub hops: Vec<RouteHopV0>,
+}
+
+#[derive(Accounts)]
+pub struct RouteSwapV0<'info> {
+  #[account(
+    has_one = sol_storage,
+    has_one = wrapped_sol_mint
+  )]
+  pub state: Box<Account<'info, ProgramStateV0>>,
+  #[account(mut, constraint = wrapped_sol_mint.mint_authority.unwrap() == mint_authority.key())]
+  pub wrapped_sol_mint: Account<'info, Mint>,
+  /// CHECK: Checked by cpi to spl token
+  pub mint_authority: AccountInfo<'info>,
+  #[account(mut)]
+  pub sol_storage: SystemAccount<'info>,
+  #[account(mut)]
+  /// CHECK: Token account of the input mint, checked by cpi to spl token. Unused when routing from native sol
+  pub source: AccountInfo<'info>,
+  // Pays for the first hop when routing from native sol. Later native sol hops are paid by the
+  // previous hop's destination, which must then sign
+  #[account(mut)]
+  pub source_authority: Signer<'info>,
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+fn assert_hop(
+  token_bonding: &TokenBondingV0,
+  hop: &[AccountInfo],
+  base_royalties: Pubkey,
+  target_royalties: Pubkey,
+) -> Result<()> {
+  if token_bonding.curve != hop[1].key()
+    || token_bonding.base_mint != hop[2].key()
+    || token_bonding.target_mint != hop[3].key()
+    || token_bonding.base_storage != hop[4].key()
+    || base_royalties != hop[5].key()
+    || target_royalties != hop[6].key()
+  {
+    return Err(error!(ErrorCode::InvalidRoute));
+  }
+
+  Ok(())
+}
+
+/// Splits the remaining accounts into each hop's accounts, which must use all of them
+pub fn route_hop_accounts<'a, T>(accounts: &'a [T], hops: &[RouteHopV0]) -> Option<Vec<&'a [T]>> {
+  let mut rest = accounts;
+  let mut hop_accounts = Vec::with_capacity(hops.len());
+  for hop in hops {
+    let len = ROUTE_HOP_ACCOUNTS + hop.extra_accounts as usize;
+    if rest.len() < len {
+      return None;
+    }
+    let (accounts, next) = rest.split_at(len);
+    hop_accounts.push(accounts);
+    rest = next;
+  }
+
+  if hop_accounts.is_empty() || !rest.is_empty() {
+    return None;
+  }
+
+  Some(hop_accounts)
+}
+
+fn route_buy<'info>(
+  accounts: &RouteSwapV0<'info>,
+  hop: &[AccountInfo<'info>],
+  hop_args: &RouteHopV0,
+  source: &AccountInfo<'info>,
+  base_amount: u64,
+  program_id: &Pubkey,
+) -> Result<u64> {
+  let token_bonding: Box<Account<'info, TokenBondingV0>> = Box::new(Account::try_from(&hop[0])?);
+  assert_hop(
+    &token_bonding,
+    hop,
+    token_bonding.buy_base_royalties,
+    token_bonding.buy_target_royalties,
+  )?;
+  let mut common = BuyCommonV0 {
+    token_bonding,
+    curve: Box::new(Account::try_from(&hop[1])?),
+    base_mint: Box::new(Account::try_from(&hop[2])?),
+    target_mint: Box::new(Account::try_from(&hop[3])?),
+    base_storage: Box::new(Account::try_from(&hop[4])?),
+    buy_base_royalties: hop[5].clone(),
+    destination: Box::new(Account::try_from(&hop[7])?),
+    buy_target_royalties: hop[6].clone(),
+    token_program: accounts.token_program.clone(),
+    clock: accounts.clock.clone(),
+  };
+
+  let BuyAmount {
+    total_amount,
+    price,
+    base_royalties,
+    target_royalties,
+  } = buy_shared_logic(
+    &mut common,
+    &BuyV1Args {
+      buy_with_base: Some(BuyWithBaseV0Args {
+        base_amount,
+        minimum_target_amount: 0,
+      }),
+      buy_target_amount: None,
+      allowlist_proof: hop_args.allowlist_proof.clone(),
+    },
+    &accounts.source_authority.key(),
+    &hop[ROUTE_HOP_ACCOUNTS..],
+  )?;
+  mint_to_dest(
+    total_amount,
+    target_royalties,
+    &common,
+    &common.destination.to_account_info(),
+  )?;
+
+  let token_program = accounts.token_program.to_account_info();
+  let source_authority = accounts.source_authority.to_account_info();
+  if common.base_mint.key() == accounts.state.wrapped_sol_mint {
+    let source: Signer<'info> = Signer::try_from(source)?;
+    if base_royalties > 0 {
+      invoke(
+        &system_instruction::transfer(
+          &source.key(),
+          &common.buy_base_royalties.key(),
+          base_royalties,
+        ),
+        &[
+          source.to_account_info(),
+          common.buy_base_royalties.clone(),
+          accounts.system_program.to_account_info(),
+        ],
+      )?;
+    }
+
+    buy_wrapped_sol(
+      &BuyWrappedSolV0 {
+        state: accounts.state.clone(),
+        wrapped_sol_mint: accounts.wrapped_sol_mint.clone(),
+        mint_authority: accounts.mint_authority.clone(),
+        sol_storage: accounts.sol_storage.clone(),
+        source,
+        destination: common.base_storage.clone(),
+        token_program: accounts.token_program.clone(),
+        system_program: accounts.system_program.clone(),
+      },
+      &BuyWrappedSolV0Args { amount: price },
+    )?;
+  } else {
+    let payments = [
+      (common.buy_base_royalties.clone(), base_royalties),
+      (common.base_storage.to_account_info(), price),
+    ];
+    for (to, amount) in payments {
+      if amount > 0 {
+        token::transfer(
+          CpiContext::new(
+            token_program.clone(),
+            Transfer {
+              from: source.clone(),
+              to,
+              authority: source_authority.clone(),
+            },
+          ),
+          amount,
+        )?;
+      }
+    }
+  }
+
+  common.token_bonding.exit(program_id)?;
+
+  Ok(total_amount - target_royalties)
+}
+
+fn route_sell<'info>(
+  accounts: &RouteSwapV0<'info>,
+  hop: &[AccountInfo<'info>],
+  source: &AccountInfo<'info>,
+  target_amount: u64,
+  program_id: &Pubkey,
+) -> Result<u64> {
+  let token_bonding: Box<Account<'info, TokenBondingV0>> = Box::new(Account::try_from(&hop[0])?);
+  assert_hop(
+    &token_bonding,
+    hop,
+    token_bonding.sell_base_royalties,
+    token_bonding.sell_target_royalties,
+  )?;
+  let mut common = SellCommonV0 {
+    token_bonding,
+    curve: Box::new(Account::try_from(&hop[1])?),
+    base_mint: Box::new(Account::try_from(&hop[2])?),
+    target_mint: Box::new(Account::try_from(&hop[3])?),
+    base_storage: Box::new(Account::try_from(&hop[4])?),
+    sell_base_royalties: hop[5].clone(),
+    source: Box::new(Account::try_from(source)?),
+    source_authority: accounts.source_authority.clone(),
+    sell_target_royalties: hop[6].clone(),
+    token_program: accounts.token_program.clone(),
+    clock: accounts.clock.clone(),
+  };
+
+  let amount = sell_shared_logic(
+    &mut common,
+    &SellV2Args {
+      target_amount,
+      minimum_price: 0,
+      sell_for_base: None,
+    },
+    &hop[ROUTE_HOP_ACCOUNTS..],
+  )?;
+  let SellAmount {
+    total_amount,
+    reclaimed,
+    base_royalties,
+    target_royalties,
+  } = amount;
+  burn_and_pay_sell_royalties(total_amount, target_royalties, &common)?;
+
+  let token_bonding = &common.token_bonding;
+  let bonding_seeds: &[&[&[u8]]] = &[&[
+    b"token-bonding",
+    token_bonding.target_mint.as_ref(),
+    &token_bonding.index.to_le_bytes(),
+    &[token_bonding.bump_seed],
+  ]];
+  if common.base_mint.key() == accounts.state.wrapped_sol_mint {
+    let mut payouts = vec![(hop[7].clone(), reclaimed)];
+    if base_royalties > 0 && common.sell_base_royalties.lamports() > 0 {
+      payouts.push((common.sell_base_royalties.clone(), base_royalties));
+    }
+    for (to, amount) in payouts {
+      sell_wrapped_sol(
+        &SellWrappedSolV0 {
+          state: accounts.state.clone(),
+          wrapped_sol_mint: accounts.wrapped_sol_mint.clone(),
+          sol_storage: accounts.sol_storage.clone(),
+          source: common.base_storage.clone(),
+          owner: token_bonding.to_account_info(),
+          destination: SystemAccount::try_from(&to)?,
+          token_program: accounts.token_program.clone(),
+          system_program: accounts.system_program.clone(),
+        },
+        &SellWrappedSolV0Args { amount, all: false },
+        Some(bonding_seeds),
+      )?;
+    }
+  } else {
+    let payouts = [
+      (hop[7].clone(), reclaimed),
+      (common.sell_base_royalties.clone(), base_royalties),
+    ];
+    for (to, amount) in payouts {
+      if amount > 0 {
+        token::transfer(
+          CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+              from: common.base_storage.to_account_info(),
+              to,
+              authority: token_bonding.to_account_info(),
+            },
+            bonding_seeds,
+          ),
+          amount,
+        )?;
+      }
+    }
+  }
+
+  emit_sell_event(&mut common, &amount)?;
+  common.token_bonding.exit(program_id)?;
+
+  Ok(reclaimed)
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, RouteSwapV0<'info>>,
+  args: RouteSwapV0Args,
+) -> Result<()> {
+  let hops = route_hop_accounts(ctx.remaining_accounts, &args.hops)
+    .ok_or_else(|| error!(ErrorCode::InvalidRoute))?;
+
+  // Each hop buys if it is funded by its base mint, or sells if it is funded by its target mint
+  let mut mint = args.input_mint;
+  let mut amount = args.amount;
+  let mut source = if mint == ctx.accounts.state.wrapped_sol_mint {
+    ctx.accounts.source_authority.to_account_info()
+  } else {
+    ctx.accounts.source.clone()
+  };
+  for (hop, hop_args) in hops.into_iter().zip(args.hops.iter()) {
+    let token_bonding: Account<TokenBondingV0> = Account::try_from(&hop[0])?;
+    if token_bonding.base_mint == mint {
+      amount = route_buy(ctx.accounts, hop, hop_args, &source, amount, ctx.program_id)?;
+      mint = token_bonding.target_mint;
+    } else if token_bonding.target_mint == mint {
+      amount = route_sell(ctx.accounts, hop, &source, amount, ctx.program_id)?;
+      mint = token_bonding.base_mint;
+    } else {
+      return Err(error!(ErrorCode::InvalidRoute));
+    }
+    msg!("Routed through {}, {} out", token_bonding.key(), amount);
+    source = hop[7].clone();
+  }
+
+  if amount < args.minimum_output_amount {
+    msg!(
+      "Route output {} is less than the minimum {}",
+      amount,
+      args.minimum_output_amount
+    );
+    return Err(error!(ErrorCode::RouteOutputTooLow));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn hop(extra_accounts: u8) -> RouteHopV0 {
+    RouteHopV0 {
+      extra_accounts,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_route_hop_accounts_follow_extra_accounts() {
+    let accounts: Vec<usize> = (0..27).collect();
+    let hops = route_hop_accounts(&accounts, &[hop(0), hop(2), hop(1)]).unwrap();
+    assert_eq!(hops.len(), 3);
+    assert_eq!(hops[0], &accounts[0..8]);
+    assert_eq!(hops[1], &accounts[8..18]);
+    assert_eq!(hops[2], &accounts[18..27]);
+  }
+
+  #[test]
+  fn test_route_hop_accounts_must_use_every_account() {
+    let accounts: Vec<usize> = (0..17).collect();
+    assert!(route_hop_accounts(&accounts, &[hop(0), hop(0)]).is_none());
+    assert!(route_hop_accounts(&accounts, &[hop(0), hop(2)]).is_none());
+    assert!(route_hop_accounts(&accounts, &[hop(0), hop(1)]).is_some());
+    assert!(route_hop_accounts::<usize>(&[], &[]).is_none());
+  }
+}
@@ -1,110 +1,151 @@
-use super::{sell_account_common::SellCommonV0, sell_arg_common::SellV0Args};
-use crate::{curve::Curve, error::ErrorCode, util::*};
+use super::{sell_account_common::SellCommonV0, sell_arg_common::SellV2Args};
+use crate::{
+  error::ErrorCode,
+  instructions::reserve_staking::reserve_staking_account_common::{
+    recognise_losses, take_reserve_staking, unstake_for_sell,
+  },
+  oracle::{record_observation, take_price_oracle},
+  quote::{liquid_reserves, sell_amounts, QuoteState},
+  stake_pool::stake_pool_totals,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Burn, Transfer};
 
-pub struct SellAmount {
+pub use crate::quote::SellAmount;
+
+#[event]
+pub struct SellEventV0 {
+  pub token_bonding: Pubkey,
+  pub seller: Pubkey,
+  /// Target tokens sold, including target royalties
+  pub target_amount: u64,
+  /// Base tokens paid to the seller
   pub reclaimed: u64,
   pub base_royalties: u64,
   pub target_royalties: u64,
+  /// Base storage amount after the sell
+  pub reserves: u64,
+  /// Target supply after the sell
+  pub supply: u64,
+  pub unix_timestamp: i64,
 }
 
-pub fn sell_shared_logic(common: &mut SellCommonV0, args: &SellV0Args) -> Result<SellAmount> {
-  let token_bonding = &mut common.token_bonding;
-  let curve = &common.curve;
-  let base_mint = &common.base_mint;
-  let base_storage = &common.base_storage;
-  let target_mint = &common.target_mint;
-  let clock = &common.clock;
-
-  let amount = args.target_amount;
-  let base_amount_u64 = if token_bonding.ignore_external_reserve_changes {
-    token_bonding.reserve_balance_from_bonding
-  } else {
-    base_storage.amount
-  };
-  let base_amount = precise_supply_amt(base_amount_u64, base_mint);
-  let target_supply_u64 = if token_bonding.ignore_external_supply_changes {
-    token_bonding.supply_from_bonding
-  } else {
-    target_mint.supply
-  };
-  let target_supply = precise_supply_amt(target_supply_u64, target_mint);
+/// `remaining_accounts` holds, in order, the token bonding's PriceOracleV0 to record an
+/// observation, then the ReserveStakingCommonV0 accounts if the bonding stakes its reserves.
+/// Those let the sell unstake when it pays out more than is unstaked, and burn any staking
+/// losses before the sell is priced
+pub fn sell_shared_logic<'info>(
+  common: &mut SellCommonV0<'info>,
+  args: &SellV2Args,
+  remaining_accounts: &[AccountInfo<'info>],
+) -> Result<SellAmount> {
+  let (price_oracle, remaining_accounts) =
+    take_price_oracle(&common.token_bonding.key(), remaining_accounts)?;
+  let (reserve_staking, _) = take_reserve_staking(remaining_accounts)?;
+  if let Some(reserve_staking) = &reserve_staking {
+    let totals = stake_pool_totals(
+      &reserve_staking.stake_pool,
+      &reserve_staking.stake_pool_program.key(),
+    )?;
+    // Losses only show once the pool is updated for the epoch
+    if totals.last_update_epoch == common.clock.epoch {
+      recognise_losses(
+        reserve_staking,
+        &mut common.token_bonding,
+        &mut common.base_storage,
+        &totals,
+      )?;
+    }
+  }
 
   msg!(
     "Current reserves {} and supply {}",
-    base_storage.amount,
-    target_mint.supply
+    common.base_storage.amount,
+    common.target_mint.supply
   );
 
-  // Not yet initialized since reserve_balance_from_bonding is a new feature
-  if !token_bonding.sell_frozen
-    && target_mint.supply > 0
-    && token_bonding.reserve_balance_from_bonding == 0
-    && token_bonding.go_live_unix_time < 1646092800_i64
-  {
-    token_bonding.reserve_balance_from_bonding = base_storage.amount;
-    token_bonding.supply_from_bonding = target_mint.supply;
-  }
+  let state = QuoteState::from_accounts(
+    &common.base_mint,
+    &common.target_mint,
+    &common.base_storage,
+    &common.clock,
+  );
 
-  if token_bonding.go_live_unix_time > clock.unix_timestamp {
-    return Err(error!(ErrorCode::NotLiveYet));
-  }
+  let mut amount = sell_amounts(&mut common.token_bonding, &common.curve, &state, args)?;
 
-  if token_bonding.sell_frozen {
-    return Err(error!(ErrorCode::SellDisabled));
+  if let Some(mut price_oracle) = price_oracle {
+    record_observation(
+      &mut price_oracle,
+      &common.token_bonding.price_accumulator.unwrap_or_default(),
+      state.unix_timestamp,
+    );
+    price_oracle.exit(&crate::id())?;
   }
 
-  let base_royalties_percent = token_bonding.sell_base_royalty_percentage;
-  let target_royalties_percent = token_bonding.sell_target_royalty_percentage;
+  // Base royalties stay in storage when the royalties account is closed
+  let paid_base_royalties = if common.sell_base_royalties.lamports() > 0 {
+    amount.base_royalties
+  } else {
+    0
+  };
 
-  let target_royalties = get_percent(amount, target_royalties_percent)?;
-  let amount_minus_royalties_prec = precise_supply_amt(
-    amount.checked_sub(target_royalties).or_arith_error()?,
-    target_mint,
-  );
-  let reclaimed_prec = curve
-    .definition
-    .price(
-      clock
-        .unix_timestamp
-        .checked_sub(token_bonding.go_live_unix_time)
-        .unwrap(),
-      &base_amount,
-      &target_supply,
-      &amount_minus_royalties_prec,
-      true,
-    )
-    .or_arith_error()?;
-  let reclaimed_with_royalties = to_mint_amount(&reclaimed_prec, base_mint, false);
-  let base_royalties = get_percent(reclaimed_with_royalties, base_royalties_percent)?;
-  let reclaimed = reclaimed_with_royalties
-    .checked_sub(base_royalties)
-    .or_arith_error()?;
-
-  token_bonding.supply_from_bonding = token_bonding
-    .supply_from_bonding
-    .checked_sub(amount)
-    .or_arith_error()?;
-  token_bonding.reserve_balance_from_bonding = token_bonding
-    .reserve_balance_from_bonding
-    .checked_sub(reclaimed)
-    .or_arith_error()?;
-
-  if reclaimed < args.minimum_price {
-    msg!(
-      "Err: Minimum price was {}, reclaimed was {}",
-      args.minimum_price,
-      reclaimed
-    );
-    return Err(error!(ErrorCode::PriceTooLow));
+  let payout = amount.reclaimed + paid_base_royalties;
+  let liquid = liquid_reserves(&common.token_bonding, common.base_storage.amount);
+  if payout > liquid {
+    let reserve_staking = reserve_staking
+      .as_ref()
+      .ok_or_else(|| error!(ErrorCode::ReservesNotLiquid))?;
+    let fee = unstake_for_sell(
+      reserve_staking,
+      &mut common.token_bonding,
+      &mut common.base_storage,
+      payout - liquid,
+    )?;
+    // The seller pays the withdrawal fee out of what they reclaim
+    let minimum_price = args
+      .sell_for_base
+      .as_ref()
+      .map_or(args.minimum_price, |sell_for_base| {
+        sell_for_base.base_amount
+      });
+    if amount.reclaimed < minimum_price.saturating_add(fee) {
+      msg!(
+        "Err: Minimum price was {}, reclaimed was {} before a {} unstake fee",
+        minimum_price,
+        amount.reclaimed,
+        fee
+      );
+      return Err(error!(ErrorCode::PriceTooLow));
+    }
+    amount.reclaimed -= fee;
+    if amount.reclaimed + paid_base_royalties
+      > liquid_reserves(&common.token_bonding, common.base_storage.amount)
+    {
+      return Err(error!(ErrorCode::ReservesNotLiquid));
+    }
   }
 
-  Ok(SellAmount {
-    reclaimed,
-    base_royalties,
-    target_royalties,
-  })
+  Ok(amount)
+}
+
+/// Called once the sell has burned and paid out, so reserves and supply are read back from
+/// the accounts rather than inferred from which payouts the caller made
+pub fn emit_sell_event(common: &mut SellCommonV0, amount: &SellAmount) -> Result<()> {
+  common.base_storage.reload()?;
+  common.target_mint.reload()?;
+  emit!(SellEventV0 {
+    token_bonding: common.token_bonding.key(),
+    seller: common.source_authority.key(),
+    target_amount: amount.total_amount,
+    reclaimed: amount.reclaimed,
+    base_royalties: amount.base_royalties,
+    target_royalties: amount.target_royalties,
+    reserves: common.base_storage.amount,
+    supply: common.target_mint.supply,
+    unix_timestamp: common.clock.unix_timestamp,
+  });
+
+  Ok(())
 }
 
 pub fn burn_and_pay_sell_royalties(
@@ -1,10 +1,12 @@
 pub mod common;
+pub mod quote_sell_v0;
 pub mod sell_account_common;
 pub mod sell_arg_common;
 pub mod sell_native_v0;
 pub mod sell_v1;
 pub mod sell_wrapped_sol_v0;
 
+pub use quote_sell_v0::*;
 pub use sell_account_common::*;
 pub use sell_arg_common::*;
 pub use sell_native_v0::*;
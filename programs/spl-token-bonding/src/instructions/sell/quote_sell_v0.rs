@@ -0,0 +1,67 @@
+use super::sell_arg_common::SellV2Args;
+use crate::{
+  quote::{quote_sell, QuoteState, QuoteV0, SellAmount},
+  state::*,
+};
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+// Same accounts as SellCommonV0, but nothing is writable and the source authority need not sign
+#[derive(Accounts)]
+pub struct QuoteSellV0<'info> {
+  #[account(
+    has_one = base_mint,
+    has_one = target_mint,
+    has_one = base_storage,
+    has_one = curve,
+    has_one = sell_base_royalties,
+    has_one = sell_target_royalties,
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub curve: Box<Account<'info, CurveV0>>,
+  pub base_mint: Box<Account<'info, Mint>>,
+  pub target_mint: Box<Account<'info, Mint>>,
+  pub base_storage: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Token account could have been closed. Not used when quoting
+  pub sell_base_royalties: AccountInfo<'info>,
+  /// CHECK: Not used when quoting
+  pub source: AccountInfo<'info>,
+  /// CHECK: Not used when quoting
+  pub source_authority: AccountInfo<'info>,
+  /// CHECK: Token account could have been closed. Not used when quoting
+  pub sell_target_royalties: AccountInfo<'info>,
+  pub token_program: Program<'info, Token>,
+  pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<QuoteSellV0>, args: SellV2Args) -> Result<()> {
+  let state = QuoteState::from_accounts(
+    &ctx.accounts.base_mint,
+    &ctx.accounts.target_mint,
+    &ctx.accounts.base_storage,
+    &ctx.accounts.clock,
+  );
+  let SellAmount {
+    total_amount,
+    reclaimed,
+    base_royalties,
+    target_royalties,
+  } = quote_sell(
+    &ctx.accounts.token_bonding,
+    &ctx.accounts.curve,
+    &state,
+    &args,
+  )?;
+
+  set_return_data(
+    &QuoteV0 {
+      price: reclaimed,
+      total_amount,
+      base_royalties,
+      target_royalties,
+    }
+    .try_to_vec()?,
+  );
+
+  Ok(())
+}
@@ -9,3 +9,30 @@ pub struct SellV0Args {
   // Minimum price to receive for this amount. Allows users to account and fail-fast for slippage.
   pub minimum_price: u64,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SellForBaseV0Args {
+  // Exact amount of base to receive, after base royalties
+  pub base_amount: u64,
+  // Maximum number of target tokens to sell, including target royalties. Allows users to account and fail-fast for slippage.
+  pub maximum_target_amount: u64,
+}
+
+// Taken by sell_v2 and sell_native_v1. SellV0Args stays as is so existing clients keep working
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SellV2Args {
+  pub target_amount: u64,
+  pub minimum_price: u64,
+  // Sell however many target tokens it takes to receive an exact base amount. When set, target_amount and minimum_price are ignored
+  pub sell_for_base: Option<SellForBaseV0Args>,
+}
+
+impl From<SellV0Args> for SellV2Args {
+  fn from(args: SellV0Args) -> Self {
+    SellV2Args {
+      target_amount: args.target_amount,
+      minimum_price: args.minimum_price,
+      sell_for_base: None,
+    }
+  }
+}
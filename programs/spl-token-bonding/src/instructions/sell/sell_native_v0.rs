@@ -1,7 +1,7 @@
 use super::{
-  common::{burn_and_pay_sell_royalties, sell_shared_logic, SellAmount},
+  common::{burn_and_pay_sell_royalties, emit_sell_event, sell_shared_logic, SellAmount},
   sell_account_common::*,
-  sell_arg_common::SellV0Args,
+  sell_arg_common::SellV2Args,
   sell_wrapped_sol_v0::{sell_wrapped_sol, SellWrappedSolV0, SellWrappedSolV0Args},
 };
 use crate::state::*;
@@ -29,14 +29,17 @@ pub struct SellNativeV0<'info> {
   pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<SellNativeV0>, args: SellV0Args) -> Result<()> {
-  let amount = args.target_amount;
-
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SellNativeV0<'info>>,
+  args: SellV2Args,
+) -> Result<()> {
+  let amount = sell_shared_logic(&mut ctx.accounts.common, &args, ctx.remaining_accounts)?;
   let SellAmount {
+    total_amount,
     reclaimed,
     base_royalties,
     target_royalties,
-  } = sell_shared_logic(&mut ctx.accounts.common, &args)?;
+  } = amount;
 
   // msg!(
   //   "Total reclaimed is {}, with {} to base royalties, {} to target royalties",
@@ -45,7 +48,7 @@ pub fn handler(ctx: Context<SellNativeV0>, args: SellV0Args) -> Result<()> {
   //   target_royalties
   // );
 
-  burn_and_pay_sell_royalties(amount, target_royalties, &ctx.accounts.common)?;
+  burn_and_pay_sell_royalties(total_amount, target_royalties, &ctx.accounts.common)?;
 
   let base_storage_account = &ctx.accounts.common.base_storage.clone();
   let destination = &ctx.accounts.destination;
@@ -106,5 +109,7 @@ pub fn handler(ctx: Context<SellNativeV0>, args: SellV0Args) -> Result<()> {
     )?;
   }
 
+  emit_sell_event(&mut ctx.accounts.common, &amount)?;
+
   Ok(())
 }
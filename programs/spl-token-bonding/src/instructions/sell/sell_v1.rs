@@ -1,7 +1,7 @@
 use super::{
-  common::{burn_and_pay_sell_royalties, sell_shared_logic, SellAmount},
+  common::{burn_and_pay_sell_royalties, emit_sell_event, sell_shared_logic, SellAmount},
   sell_account_common::*,
-  sell_arg_common::SellV0Args,
+  sell_arg_common::SellV2Args,
 };
 use crate::state::*;
 use anchor_lang::prelude::*;
@@ -20,12 +20,17 @@ pub struct SellV1<'info> {
   pub destination: Box<Account<'info, TokenAccount>>,
 }
 
-pub fn handler(ctx: Context<SellV1>, args: SellV0Args) -> Result<()> {
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SellV1<'info>>,
+  args: SellV2Args,
+) -> Result<()> {
+  let amount = sell_shared_logic(&mut ctx.accounts.common, &args, ctx.remaining_accounts)?;
   let SellAmount {
+    total_amount,
     reclaimed,
     base_royalties,
     target_royalties,
-  } = sell_shared_logic(&mut ctx.accounts.common, &args)?;
+  } = amount;
 
   msg!(
     "Total reclaimed is {}, with {} to base royalties, {} to target royalties",
@@ -34,7 +39,7 @@ pub fn handler(ctx: Context<SellV1>, args: SellV0Args) -> Result<()> {
     target_royalties
   );
 
-  burn_and_pay_sell_royalties(args.target_amount, target_royalties, &ctx.accounts.common)?;
+  burn_and_pay_sell_royalties(total_amount, target_royalties, &ctx.accounts.common)?;
 
   let token_program = ctx.accounts.common.token_program.to_account_info();
   let base_storage_account = ctx.accounts.common.base_storage.to_account_info();
@@ -90,5 +95,7 @@ pub fn handler(ctx: Context<SellV1>, args: SellV0Args) -> Result<()> {
     )?;
   }
 
+  emit_sell_event(&mut ctx.accounts.common, &amount)?;
+
   Ok(())
 }
@@ -1,4 +1,4 @@
-use crate::state::*;
+use crate::{error::ErrorCode, state::*};
 use anchor_lang::{
   prelude::*,
   solana_program::{program::invoke_signed, system_instruction},
@@ -48,6 +48,10 @@ pub fn sell_wrapped_sol(
   } else {
     args.amount
   };
+  // Lamports in the stake pool cannot be paid out until they are unstaked
+  if amount > accounts.sol_storage.lamports() {
+    return Err(error!(ErrorCode::ReservesNotLiquid));
+  }
 
   invoke_signed(
     &system_instruction::transfer(
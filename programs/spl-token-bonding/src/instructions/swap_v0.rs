@@ -0,0 +1,135 @@
+use super::{
+  buy::{
+    buy_account_common::*,
+    buy_arg_common::{BuyV1Args, BuyWithBaseV0Args},
+    common::{buy_shared_logic, mint_to_dest, BuyAmount},
+  },
+  sell::{
+    common::{burn_and_pay_sell_royalties, emit_sell_event, sell_shared_logic, SellAmount},
+    sell_account_common::*,
+    sell_arg_common::SellV2Args,
+  },
+};
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SwapV0Args {
+  // Amount of the sell bonding's target token to sell
+  pub target_amount: u64,
+  // Minimum amount of the buy bonding's target token to receive, after royalties on both legs.
+  pub minimum_target_amount: u64,
+  // Merkle proof that the buyer is on the buy bonding's allowlist, if it has an active one
+  pub allowlist_proof: Option<Vec<[u8; 32]>>,
+}
+
+#[derive(Accounts)]
+pub struct SwapV0<'info> {
+  pub sell: SellCommonV0<'info>,
+  pub buy: BuyCommonV0<'info>,
+  // This endpoint is only for non wrapped sol
+  #[account(
+    constraint = state.wrapped_sol_mint != sell.base_mint.key(),
+    constraint = sell.base_mint.key() == buy.base_mint.key() @ ErrorCode::InvalidMint,
+    constraint = sell.token_bonding.key() != buy.token_bonding.key()
+  )]
+  pub state: Box<Account<'info, ProgramStateV0>>,
+}
+
+pub fn handler(ctx: Context<SwapV0>, args: SwapV0Args) -> Result<()> {
+  let sell_amount = sell_shared_logic(
+    &mut ctx.accounts.sell,
+    &SellV2Args {
+      target_amount: args.target_amount,
+      minimum_price: 0,
+      sell_for_base: None,
+    },
+    &[],
+  )?;
+  let SellAmount {
+    total_amount: sell_total_amount,
+    reclaimed,
+    base_royalties: sell_base_royalties,
+    target_royalties: sell_target_royalties,
+  } = sell_amount;
+  burn_and_pay_sell_royalties(sell_total_amount, sell_target_royalties, &ctx.accounts.sell)?;
+
+  // The reclaimed base tokens fund the buy, so slippage is only checked once, on the final output
+  let BuyAmount {
+    total_amount: buy_total_amount,
+    price,
+    base_royalties: buy_base_royalties,
+    target_royalties: buy_target_royalties,
+  } = buy_shared_logic(
+    &mut ctx.accounts.buy,
+    &BuyV1Args {
+      buy_with_base: Some(BuyWithBaseV0Args {
+        base_amount: reclaimed,
+        minimum_target_amount: args.minimum_target_amount,
+      }),
+      buy_target_amount: None,
+      allowlist_proof: args.allowlist_proof,
+    },
+    &ctx.accounts.sell.source_authority.key(),
+    ctx.remaining_accounts,
+  )?;
+  mint_to_dest(
+    buy_total_amount,
+    buy_target_royalties,
+    &ctx.accounts.buy,
+    &ctx.accounts.buy.destination.to_account_info(),
+  )?;
+
+  msg!(
+    "Swapped {} for {}, through {} base with {} and {} to base royalties",
+    sell_total_amount,
+    buy_total_amount - buy_target_royalties,
+    reclaimed,
+    sell_base_royalties,
+    buy_base_royalties
+  );
+
+  let token_program = ctx.accounts.sell.token_program.to_account_info();
+  let base_storage_account = ctx.accounts.sell.base_storage.to_account_info();
+  let target_mint = ctx.accounts.sell.target_mint.to_account_info();
+  let token_bonding = &ctx.accounts.sell.token_bonding;
+  let bonding_seeds: &[&[&[u8]]] = &[&[
+    b"token-bonding",
+    target_mint.key.as_ref(),
+    &token_bonding.index.to_le_bytes(),
+    &[token_bonding.bump_seed],
+  ]];
+
+  let payouts = [
+    (
+      ctx.accounts.sell.sell_base_royalties.to_account_info(),
+      sell_base_royalties,
+    ),
+    (
+      ctx.accounts.buy.buy_base_royalties.to_account_info(),
+      buy_base_royalties,
+    ),
+    (ctx.accounts.buy.base_storage.to_account_info(), price),
+  ];
+  for (to, amount) in payouts {
+    if amount > 0 {
+      token::transfer(
+        CpiContext::new_with_signer(
+          token_program.clone(),
+          Transfer {
+            from: base_storage_account.clone(),
+            to,
+            authority: token_bonding.to_account_info(),
+          },
+          bonding_seeds,
+        ),
+        amount,
+      )?;
+    }
+  }
+
+  emit_sell_event(&mut ctx.accounts.sell, &sell_amount)?;
+
+  Ok(())
+}
@@ -1,9 +1,13 @@
 pub mod transfer_reserves_account_common;
 pub mod transfer_reserves_arg_common;
 pub mod transfer_reserves_native_v0;
+pub mod transfer_reserves_native_v1;
 pub mod transfer_reserves_v0;
+pub mod transfer_reserves_v1;
 
 pub use transfer_reserves_account_common::*;
 pub use transfer_reserves_arg_common::*;
 pub use transfer_reserves_native_v0::*;
+pub use transfer_reserves_native_v1::*;
 pub use transfer_reserves_v0::*;
+pub use transfer_reserves_v1::*;
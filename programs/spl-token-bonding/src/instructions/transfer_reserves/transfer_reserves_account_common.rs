@@ -1,6 +1,11 @@
 // File has weird name because https://github.com/project-serum/anchor/issues/1499
 // TODO: Rename to account.rs
-use crate::{error::ErrorCode, state::*};
+use crate::{
+  error::ErrorCode,
+  quote::{liquid_reserves, solvency, QuoteState},
+  state::*,
+  util::OrArithError,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
@@ -19,3 +24,63 @@ pub struct TransferReservesV0Common<'info> {
   pub base_storage: Box<Account<'info, TokenAccount>>,
   pub token_program: Program<'info, Token>,
 }
+
+/// v0 cannot see the curve or the supply, so it only serves bondings that price against
+/// base storage and supply as they are
+pub fn verify_v0_transfer(token_bonding: &TokenBondingV0) -> Result<()> {
+  if token_bonding.ignore_external_reserve_changes
+    || token_bonding.ignore_external_supply_changes
+    || token_bonding.wind_down
+  {
+    return Err(error!(ErrorCode::TransferReservesV1Only));
+  }
+
+  Ok(())
+}
+
+/// Staked lamports stay in base storage until a rebalance or a sell unstakes them
+pub fn verify_liquid(common: &TransferReservesV0Common, amount: u64) -> Result<()> {
+  if amount > liquid_reserves(&common.token_bonding, common.base_storage.amount) {
+    return Err(error!(ErrorCode::ReservesNotLiquid));
+  }
+
+  Ok(())
+}
+
+/// Reserves can only be taken out while what is left still buys back the outstanding supply.
+/// `reserves` is what base storage can pay out before the transfer
+pub fn verify_solvent_after_transfer(
+  common: &TransferReservesV0Common,
+  curve: &CurveV0,
+  target_mint: &Mint,
+  amount: u64,
+  reserves: u64,
+) -> Result<()> {
+  let state = QuoteState {
+    base_decimals: common.base_mint.decimals,
+    target_decimals: target_mint.decimals,
+    base_storage_amount: common
+      .base_storage
+      .amount
+      .checked_sub(amount)
+      .or_arith_error()?,
+    target_supply: target_mint.supply,
+    unix_timestamp: Clock::get()?.unix_timestamp,
+  };
+  let solvency = solvency(
+    &common.token_bonding,
+    curve,
+    &state,
+    reserves.checked_sub(amount).or_arith_error()?,
+  )?;
+  if !solvency.is_solvent() {
+    msg!(
+      "Transfer would leave {} in reserves, less than the {} required",
+      solvency.reserves,
+      solvency.required_reserves
+    );
+    return Err(error!(ErrorCode::ReservesInsolvent));
+  }
+
+  Ok(())
+}
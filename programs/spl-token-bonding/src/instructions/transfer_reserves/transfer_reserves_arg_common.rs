@@ -6,3 +6,14 @@ use anchor_lang::prelude::*;
 pub struct TransferReservesV0Args {
   pub amount: u64,
 }
+
+#[event]
+pub struct TransferReservesEventV0 {
+  pub token_bonding: Pubkey,
+  pub reserve_authority: Pubkey,
+  pub destination: Pubkey,
+  pub amount: u64,
+  /// Base storage amount after the transfer
+  pub reserves: u64,
+  pub unix_timestamp: i64,
+}
@@ -1,5 +1,6 @@
 use super::{
-  transfer_reserves_account_common::*, transfer_reserves_arg_common::TransferReservesV0Args,
+  transfer_reserves_account_common::*,
+  transfer_reserves_arg_common::{TransferReservesEventV0, TransferReservesV0Args},
 };
 use crate::{
   instructions::sell::sell_wrapped_sol_v0::{
@@ -36,8 +37,15 @@ pub struct TransferReservesNativeV0<'info> {
   pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<TransferReservesNativeV0>, args: TransferReservesV0Args) -> Result<()> {
-  let token_bonding = &mut ctx.accounts.common.token_bonding;
+/// Unwraps `amount` out of base storage, `sell_accounts` sells from base storage as the bonding
+pub fn transfer_reserves_native(
+  common: &TransferReservesV0Common,
+  sell_accounts: &SellWrappedSolV0,
+  amount: u64,
+) -> Result<()> {
+  verify_liquid(common, amount)?;
+
+  let token_bonding = &common.token_bonding;
   let bonding_seeds: &[&[&[u8]]] = &[&[
     b"token-bonding",
     token_bonding.target_mint.as_ref(),
@@ -46,22 +54,38 @@ pub fn handler(ctx: Context<TransferReservesNativeV0>, args: TransferReservesV0A
   ]];
 
   sell_wrapped_sol(
+    sell_accounts,
+    &SellWrappedSolV0Args { amount, all: false },
+    Some(bonding_seeds),
+  )?;
+
+  emit!(TransferReservesEventV0 {
+    token_bonding: common.token_bonding.key(),
+    reserve_authority: common.reserve_authority.key(),
+    destination: sell_accounts.destination.key(),
+    amount,
+    reserves: common.base_storage.amount.saturating_sub(amount),
+    unix_timestamp: Clock::get()?.unix_timestamp,
+  });
+
+  Ok(())
+}
+
+pub fn handler(ctx: Context<TransferReservesNativeV0>, args: TransferReservesV0Args) -> Result<()> {
+  verify_v0_transfer(&ctx.accounts.common.token_bonding)?;
+
+  transfer_reserves_native(
+    &ctx.accounts.common,
     &SellWrappedSolV0 {
       state: ctx.accounts.state.clone(),
       wrapped_sol_mint: ctx.accounts.wrapped_sol_mint.clone(),
       sol_storage: ctx.accounts.sol_storage.clone(),
       source: ctx.accounts.common.base_storage.clone(),
-      owner: token_bonding.to_account_info(),
-      destination: SystemAccount::try_from(&ctx.accounts.destination.to_account_info())?,
+      owner: ctx.accounts.common.token_bonding.to_account_info(),
+      destination: ctx.accounts.destination.clone(),
       token_program: ctx.accounts.common.token_program.clone(),
       system_program: ctx.accounts.system_program.clone(),
     },
-    &SellWrappedSolV0Args {
-      amount: args.amount,
-      all: false,
-    },
-    Some(bonding_seeds),
-  )?;
-
-  Ok(())
+    args.amount,
+  )
 }
@@ -0,0 +1,79 @@
+use super::{
+  transfer_reserves_account_common::*, transfer_reserves_arg_common::TransferReservesV0Args,
+  transfer_reserves_native_v0::transfer_reserves_native,
+};
+use crate::{
+  error::ErrorCode,
+  instructions::sell::sell_wrapped_sol_v0::SellWrappedSolV0,
+  quote::{liquid_reserves, supply_amount},
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+// Same accounts as TransferReservesNativeV0, with the curve and target mint needed to check
+// solvency
+#[derive(Accounts)]
+#[instruction(args: TransferReservesV0Args)]
+pub struct TransferReservesNativeV1<'info> {
+  pub common: TransferReservesV0Common<'info>,
+  #[account(mut)]
+  pub destination: SystemAccount<'info>,
+
+  #[account(
+    has_one = sol_storage,
+    has_one = wrapped_sol_mint
+  )]
+  pub state: Box<Account<'info, ProgramStateV0>>,
+  #[account(
+    mut,
+    constraint = wrapped_sol_mint.mint_authority.unwrap() == mint_authority.key(),
+    constraint = wrapped_sol_mint.key() == common.base_mint.key()
+  )]
+  pub wrapped_sol_mint: Account<'info, Mint>,
+  /// CHECK: Used in cpi
+  pub mint_authority: AccountInfo<'info>,
+  #[account(mut)]
+  pub sol_storage: SystemAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+  #[account(
+    constraint = curve.key() == common.token_bonding.curve
+  )]
+  pub curve: Box<Account<'info, CurveV0>>,
+  #[account(
+    constraint = target_mint.key() == common.token_bonding.target_mint,
+    // Wound down reserves belong to the holders, until the last of them has redeemed
+    constraint = !common.token_bonding.wind_down || supply_amount(&common.token_bonding, target_mint.supply) == 0 @ ErrorCode::WindingDown
+  )]
+  pub target_mint: Box<Account<'info, Mint>>,
+}
+
+pub fn handler(ctx: Context<TransferReservesNativeV1>, args: TransferReservesV0Args) -> Result<()> {
+  // The bonding's staked lamports cannot be paid out to sellers without unstaking
+  verify_solvent_after_transfer(
+    &ctx.accounts.common,
+    &ctx.accounts.curve,
+    &ctx.accounts.target_mint,
+    args.amount,
+    liquid_reserves(
+      &ctx.accounts.common.token_bonding,
+      ctx.accounts.common.base_storage.amount,
+    ),
+  )?;
+
+  transfer_reserves_native(
+    &ctx.accounts.common,
+    &SellWrappedSolV0 {
+      state: ctx.accounts.state.clone(),
+      wrapped_sol_mint: ctx.accounts.wrapped_sol_mint.clone(),
+      sol_storage: ctx.accounts.sol_storage.clone(),
+      source: ctx.accounts.common.base_storage.clone(),
+      owner: ctx.accounts.common.token_bonding.to_account_info(),
+      destination: ctx.accounts.destination.clone(),
+      token_program: ctx.accounts.common.token_program.clone(),
+      system_program: ctx.accounts.system_program.clone(),
+    },
+    args.amount,
+  )
+}
@@ -1,5 +1,6 @@
 use super::{
-  transfer_reserves_account_common::*, transfer_reserves_arg_common::TransferReservesV0Args,
+  transfer_reserves_account_common::*,
+  transfer_reserves_arg_common::{TransferReservesEventV0, TransferReservesV0Args},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, TokenAccount, Transfer};
@@ -12,8 +13,14 @@ pub struct TransferReservesV0<'info> {
   pub destination: Box<Account<'info, TokenAccount>>,
 }
 
-pub fn handler(ctx: Context<TransferReservesV0>, args: TransferReservesV0Args) -> Result<()> {
-  let token_bonding = &mut ctx.accounts.common.token_bonding;
+pub fn transfer_reserves<'info>(
+  common: &TransferReservesV0Common<'info>,
+  destination: &Account<'info, TokenAccount>,
+  amount: u64,
+) -> Result<()> {
+  verify_liquid(common, amount)?;
+
+  let token_bonding = &common.token_bonding;
   let bonding_seeds: &[&[&[u8]]] = &[&[
     b"token-bonding",
     token_bonding.target_mint.as_ref(),
@@ -23,16 +30,31 @@ pub fn handler(ctx: Context<TransferReservesV0>, args: TransferReservesV0Args) -
 
   token::transfer(
     CpiContext::new_with_signer(
-      ctx.accounts.common.token_program.to_account_info().clone(),
+      common.token_program.to_account_info().clone(),
       Transfer {
-        from: ctx.accounts.common.base_storage.to_account_info().clone(),
-        to: ctx.accounts.destination.to_account_info().clone(),
+        from: common.base_storage.to_account_info().clone(),
+        to: destination.to_account_info().clone(),
         authority: token_bonding.to_account_info().clone(),
       },
       bonding_seeds,
     ),
-    args.amount,
+    amount,
   )?;
 
+  emit!(TransferReservesEventV0 {
+    token_bonding: common.token_bonding.key(),
+    reserve_authority: common.reserve_authority.key(),
+    destination: destination.key(),
+    amount,
+    reserves: common.base_storage.amount.saturating_sub(amount),
+    unix_timestamp: Clock::get()?.unix_timestamp,
+  });
+
   Ok(())
 }
+
+pub fn handler(ctx: Context<TransferReservesV0>, args: TransferReservesV0Args) -> Result<()> {
+  verify_v0_transfer(&ctx.accounts.common.token_bonding)?;
+
+  transfer_reserves(&ctx.accounts.common, &ctx.accounts.destination, args.amount)
+}
@@ -0,0 +1,45 @@
+use super::{
+  transfer_reserves_account_common::*, transfer_reserves_arg_common::TransferReservesV0Args,
+  transfer_reserves_v0::transfer_reserves,
+};
+use crate::{
+  error::ErrorCode,
+  quote::{liquid_reserves, supply_amount},
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+// Same accounts as TransferReservesV0, with the curve and target mint needed to check solvency
+#[derive(Accounts)]
+#[instruction(args: TransferReservesV0Args)]
+pub struct TransferReservesV1<'info> {
+  pub common: TransferReservesV0Common<'info>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+  #[account(
+    constraint = curve.key() == common.token_bonding.curve
+  )]
+  pub curve: Box<Account<'info, CurveV0>>,
+  #[account(
+    constraint = target_mint.key() == common.token_bonding.target_mint,
+    // Wound down reserves belong to the holders, until the last of them has redeemed
+    constraint = !common.token_bonding.wind_down || supply_amount(&common.token_bonding, target_mint.supply) == 0 @ ErrorCode::WindingDown
+  )]
+  pub target_mint: Box<Account<'info, Mint>>,
+}
+
+pub fn handler(ctx: Context<TransferReservesV1>, args: TransferReservesV0Args) -> Result<()> {
+  verify_solvent_after_transfer(
+    &ctx.accounts.common,
+    &ctx.accounts.curve,
+    &ctx.accounts.target_mint,
+    args.amount,
+    liquid_reserves(
+      &ctx.accounts.common.token_bonding,
+      ctx.accounts.common.base_storage.amount,
+    ),
+  )?;
+
+  transfer_reserves(&ctx.accounts.common, &ctx.accounts.destination, args.amount)
+}
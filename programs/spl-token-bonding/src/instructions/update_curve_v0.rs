@@ -6,6 +6,23 @@ pub struct UpdateCurveV0Args {
   pub curve_authority: Option<Pubkey>,
 }
 
+// Taken by update_curve_v1
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateCurveV1Args {
+  pub curve_authority: Option<Pubkey>,
+  // Left unchanged when None. Can be raised but never lowered or removed once set
+  pub curve_update_delay_secs: Option<i64>,
+}
+
+impl From<UpdateCurveV0Args> for UpdateCurveV1Args {
+  fn from(args: UpdateCurveV0Args) -> Self {
+    UpdateCurveV1Args {
+      curve_authority: args.curve_authority,
+      curve_update_delay_secs: None,
+    }
+  }
+}
+
 #[derive(Accounts)]
 #[instruction(args: UpdateCurveV0Args)]
 pub struct UpdateCurveV0<'info> {
@@ -18,11 +35,67 @@ pub struct UpdateCurveV0<'info> {
   pub curve: Box<Account<'info, CurveV0>>,
 }
 
-pub fn handler(ctx: Context<UpdateCurveV0>, args: UpdateCurveV0Args) -> Result<()> {
+#[event]
+pub struct CurveUpdateEventV0 {
+  pub token_bonding: Pubkey,
+  pub previous_curve: Pubkey,
+  pub curve: Pubkey,
+  pub curve_authority: Option<Pubkey>,
+  pub unix_timestamp: i64,
+}
+
+pub fn curve_update_delay_is_valid(current: Option<i64>, new: Option<i64>) -> bool {
+  match (current, new) {
+    (_, Some(new)) if new < 0 => false,
+    (Some(current), Some(new)) => new >= current,
+    (Some(_), None) => false,
+    (None, _) => true,
+  }
+}
+
+pub fn handler(ctx: Context<UpdateCurveV0>, args: UpdateCurveV1Args) -> Result<()> {
   let bonding = &mut ctx.accounts.token_bonding;
+  let curve_update_delay_secs = args
+    .curve_update_delay_secs
+    .or(bonding.curve_update_delay_secs);
+  if !curve_update_delay_is_valid(bonding.curve_update_delay_secs, curve_update_delay_secs) {
+    return Err(error!(ErrorCode::InvalidCurveUpdateDelay));
+  }
+  if bonding.curve_locked && ctx.accounts.curve.key() != bonding.curve {
+    return Err(error!(ErrorCode::CurveLocked));
+  }
+  // Timelocked curves only change through propose_curve_update_v0
+  if bonding.curve_update_delay_secs.is_some() && ctx.accounts.curve.key() != bonding.curve {
+    return Err(error!(ErrorCode::CurveUpdateTimelocked));
+  }
+
+  emit!(CurveUpdateEventV0 {
+    token_bonding: bonding.key(),
+    previous_curve: bonding.curve,
+    curve: ctx.accounts.curve.key(),
+    curve_authority: args.curve_authority,
+    unix_timestamp: Clock::get()?.unix_timestamp,
+  });
 
   bonding.curve_authority = args.curve_authority;
+  bonding.curve_update_delay_secs = curve_update_delay_secs;
   bonding.curve = ctx.accounts.curve.key();
 
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_curve_update_delay_only_grows() {
+    assert!(curve_update_delay_is_valid(None, None));
+    assert!(curve_update_delay_is_valid(None, Some(0)));
+    assert!(curve_update_delay_is_valid(Some(100), Some(100)));
+    assert!(curve_update_delay_is_valid(Some(100), Some(200)));
+    assert!(!curve_update_delay_is_valid(Some(100), Some(99)));
+    assert!(!curve_update_delay_is_valid(Some(100), None));
+    assert!(!curve_update_delay_is_valid(None, Some(-1)));
+  }
+}
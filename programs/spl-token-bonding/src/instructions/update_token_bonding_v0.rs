@@ -1,4 +1,8 @@
-use crate::{error::ErrorCode, state::*, util::verify_empty_or_mint};
+use crate::{
+  error::ErrorCode,
+  state::*,
+  util::{verify_empty_or_mint, verify_price_impact_fees, verify_royalty_change_limits},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
@@ -14,6 +18,67 @@ pub struct UpdateTokenBondingV0Args {
   pub buy_frozen: bool,
 }
 
+// Taken by update_token_bonding_v1. The settings after buy_frozen are left unchanged when None,
+// so updating royalties doesn't need to resend every gate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateTokenBondingV1Args {
+  pub general_authority: Option<Pubkey>,
+  /// Percentage of purchases that go to the founder
+  /// Percentage Value is (founder_reward_percentage / u32.MAX_VALUE) * 100
+  pub buy_base_royalty_percentage: u32,
+  pub buy_target_royalty_percentage: u32,
+  pub sell_base_royalty_percentage: u32,
+  pub sell_target_royalty_percentage: u32,
+  pub buy_frozen: bool,
+  pub allowlist: Option<AllowlistUpdateV0>,
+  pub buy_gate: Option<BuyGateUpdateV0>,
+  pub price_impact_fees: Option<PriceImpactFeesUpdateV0>,
+  // Limits can only be tightened, so there is nothing to clear
+  pub royalty_change_limits: Option<RoyaltyChangeLimitsV0>,
+  pub caps: Option<CapsUpdateV0>,
+}
+
+// The wrappers below let a Some(..) update clear a setting by holding None
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AllowlistUpdateV0 {
+  // Merkle root of the wallets allowed to buy during the allowlist phase. None disables the allowlist
+  pub root: Option<[u8; 32]>,
+  pub end_unix_time: Option<i64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BuyGateUpdateV0 {
+  pub buy_gate: Option<BuyGateV0>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PriceImpactFeesUpdateV0 {
+  pub price_impact_fees: Option<PriceImpactFeesV0>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CapsUpdateV0 {
+  pub mint_cap: Option<u64>,
+  pub purchase_cap: Option<u64>,
+  pub wallet_purchase_cap: Option<u64>,
+  pub wallet_purchase_window_secs: Option<i64>,
+}
+
+impl From<UpdateTokenBondingV0Args> for UpdateTokenBondingV1Args {
+  fn from(args: UpdateTokenBondingV0Args) -> Self {
+    UpdateTokenBondingV1Args {
+      general_authority: args.general_authority,
+      buy_base_royalty_percentage: args.buy_base_royalty_percentage,
+      buy_target_royalty_percentage: args.buy_target_royalty_percentage,
+      sell_base_royalty_percentage: args.sell_base_royalty_percentage,
+      sell_target_royalty_percentage: args.sell_target_royalty_percentage,
+      buy_frozen: args.buy_frozen,
+      ..Default::default()
+    }
+  }
+}
+
 #[derive(Accounts)]
 #[instruction(args: UpdateTokenBondingV0Args)]
 pub struct UpdateTokenBondingV0<'info> {
@@ -38,8 +103,136 @@ pub struct UpdateTokenBondingV0<'info> {
   pub sell_target_royalties: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<UpdateTokenBondingV0>, args: UpdateTokenBondingV0Args) -> Result<()> {
+#[event]
+pub struct TokenBondingUpdateEventV0 {
+  pub token_bonding: Pubkey,
+  pub general_authority: Option<Pubkey>,
+  pub buy_base_royalty_percentage: u32,
+  pub buy_target_royalty_percentage: u32,
+  pub sell_base_royalty_percentage: u32,
+  pub sell_target_royalty_percentage: u32,
+  pub buy_base_royalties: Pubkey,
+  pub buy_target_royalties: Pubkey,
+  pub sell_base_royalties: Pubkey,
+  pub sell_target_royalties: Pubkey,
+  pub buy_frozen: bool,
+  pub unix_timestamp: i64,
+}
+
+/// Everything that royalties_locked freezes
+#[derive(PartialEq, Eq, Debug)]
+pub struct RoyaltyConfig {
+  pub percentages: [u32; 4],
+  pub accounts: [Pubkey; 4],
+  pub vesting_destination: Option<Pubkey>,
+  pub price_impact_fees: Option<PriceImpactFeesV0>,
+}
+
+impl RoyaltyConfig {
+  /// The percentages royalty_change_limits apply to, in RoyaltyChangePeriodV0 order. Price
+  /// impact fees count through the royalties they charge at full impact
+  pub fn limited_percentages(&self) -> [u32; 6] {
+    let [buy_base, buy_target, sell_base, sell_target] = self.percentages;
+    let max_impact_royalty = self
+      .price_impact_fees
+      .map(|fees| fees.max_royalty_percentage)
+      .unwrap_or(0);
+    [
+      buy_base,
+      buy_target,
+      sell_base,
+      sell_target,
+      buy_base.max(max_impact_royalty),
+      sell_base.max(max_impact_royalty),
+    ]
+  }
+}
+
+/// Everything that caps_locked freezes. The allowlist and buy gate are not included, they only
+/// restrict who can buy during a launch and are expected to be lifted afterwards
+#[derive(PartialEq, Eq, Debug)]
+pub struct CapsConfig {
+  pub mint_cap: Option<u64>,
+  pub purchase_cap: Option<u64>,
+  pub wallet_purchase_cap: Option<u64>,
+  pub wallet_purchase_window_secs: Option<i64>,
+}
+
+pub fn caps_config(bonding: &TokenBondingV0) -> CapsConfig {
+  CapsConfig {
+    mint_cap: bonding.mint_cap,
+    purchase_cap: bonding.purchase_cap,
+    wallet_purchase_cap: bonding.wallet_purchase_cap,
+    wallet_purchase_window_secs: bonding.wallet_purchase_window_secs,
+  }
+}
+
+/// Lowering either impact threshold charges the higher royalties on smaller trades, which the
+/// limited percentages would not see
+pub fn price_impact_fees_steepened(
+  current: &Option<PriceImpactFeesV0>,
+  new: &Option<PriceImpactFeesV0>,
+) -> bool {
+  match (current, new) {
+    (Some(current), Some(new)) => {
+      new.min_impact_percentage < current.min_impact_percentage
+        || new.max_impact_percentage < current.max_impact_percentage
+    }
+    _ => false,
+  }
+}
+
+pub fn royalty_config(bonding: &TokenBondingV0) -> RoyaltyConfig {
+  RoyaltyConfig {
+    percentages: [
+      bonding.buy_base_royalty_percentage,
+      bonding.buy_target_royalty_percentage,
+      bonding.sell_base_royalty_percentage,
+      bonding.sell_target_royalty_percentage,
+    ],
+    accounts: [
+      bonding.buy_base_royalties,
+      bonding.buy_target_royalties,
+      bonding.sell_base_royalties,
+      bonding.sell_target_royalties,
+    ],
+    vesting_destination: bonding.royalty_vesting.as_ref().map(|v| v.destination),
+    price_impact_fees: bonding.price_impact_fees,
+  }
+}
+
+/// Whether royalties can move from `current` to `new` at `unix_time`, rolling the period over
+/// first if it has ended
+pub fn royalty_change_is_allowed(
+  limits: &RoyaltyChangeLimitsV0,
+  period: &mut RoyaltyChangePeriodV0,
+  current: [u32; 6],
+  new: [u32; 6],
+  unix_time: i64,
+) -> bool {
+  if unix_time >= period.start_unix_time.saturating_add(limits.period_secs) {
+    *period = RoyaltyChangePeriodV0 {
+      start_unix_time: unix_time,
+      start_percentages: current,
+    };
+  }
+
+  current
+    .iter()
+    .zip(new.iter())
+    .zip(period.start_percentages.iter())
+    .all(|((current, new), start)| {
+      new <= current
+        || (*new <= limits.max_royalty_percentage
+          && (*new as u64) <= *start as u64 + limits.max_change_percentage as u64)
+    })
+}
+
+pub fn handler(ctx: Context<UpdateTokenBondingV0>, args: UpdateTokenBondingV1Args) -> Result<()> {
+  let unix_timestamp = Clock::get()?.unix_timestamp;
   let bonding = &mut ctx.accounts.token_bonding;
+  let previous_royalty_config = royalty_config(bonding);
+  let previous_caps_config = caps_config(bonding);
 
   verify_empty_or_mint(&ctx.accounts.buy_base_royalties, &bonding.base_mint)?;
   verify_empty_or_mint(&ctx.accounts.sell_base_royalties, &bonding.base_mint)?;
@@ -52,10 +245,363 @@ pub fn handler(ctx: Context<UpdateTokenBondingV0>, args: UpdateTokenBondingV0Arg
   bonding.sell_target_royalty_percentage = args.sell_target_royalty_percentage;
   bonding.general_authority = args.general_authority;
   bonding.buy_frozen = args.buy_frozen;
-  bonding.buy_target_royalties = ctx.accounts.buy_target_royalties.key();
+  if let Some(allowlist) = args.allowlist {
+    bonding.allowlist_root = allowlist.root;
+    bonding.allowlist_end_unix_time = allowlist.end_unix_time;
+  }
+  if let Some(update) = args.buy_gate {
+    bonding.buy_gate = update.buy_gate;
+  }
+  if let Some(caps) = args.caps {
+    bonding.mint_cap = caps.mint_cap;
+    bonding.purchase_cap = caps.purchase_cap;
+    bonding.wallet_purchase_cap = caps.wallet_purchase_cap;
+    bonding.wallet_purchase_window_secs = caps.wallet_purchase_window_secs;
+  }
+  if let Some(update) = args.price_impact_fees {
+    verify_price_impact_fees(&update.price_impact_fees)?;
+    bonding.price_impact_fees = update.price_impact_fees;
+  }
+  // While royalties vest, buys keep minting into the escrow and the new account receives claims
+  match &mut bonding.royalty_vesting {
+    Some(royalty_vesting) => {
+      if ctx.accounts.buy_target_royalties.key() != royalty_vesting.escrow {
+        royalty_vesting.destination = ctx.accounts.buy_target_royalties.key();
+      }
+    }
+    None => bonding.buy_target_royalties = ctx.accounts.buy_target_royalties.key(),
+  }
   bonding.buy_base_royalties = ctx.accounts.buy_base_royalties.key();
   bonding.sell_base_royalties = ctx.accounts.sell_base_royalties.key();
   bonding.sell_target_royalties = ctx.accounts.sell_target_royalties.key();
 
+  if bonding.royalties_locked && royalty_config(bonding) != previous_royalty_config {
+    return Err(error!(ErrorCode::RoyaltiesLocked));
+  }
+  if bonding.caps_locked && caps_config(bonding) != previous_caps_config {
+    return Err(error!(ErrorCode::CapsLocked));
+  }
+
+  let royalty_change_limits = args.royalty_change_limits.or(bonding.royalty_change_limits);
+  verify_royalty_change_limits(&bonding.royalty_change_limits, &royalty_change_limits)?;
+  let new_royalty_config = royalty_config(bonding);
+  match bonding.royalty_change_limits {
+    Some(limits) => {
+      let allowed = royalty_change_is_allowed(
+        &limits,
+        bonding
+          .royalty_change_period
+          .get_or_insert(RoyaltyChangePeriodV0 {
+            start_unix_time: unix_timestamp,
+            start_percentages: previous_royalty_config.limited_percentages(),
+          }),
+        previous_royalty_config.limited_percentages(),
+        new_royalty_config.limited_percentages(),
+        unix_timestamp,
+      );
+      if !allowed
+        || price_impact_fees_steepened(
+          &previous_royalty_config.price_impact_fees,
+          &new_royalty_config.price_impact_fees,
+        )
+      {
+        return Err(error!(ErrorCode::RoyaltyChangeTooLarge));
+      }
+    }
+    None => {
+      // The first period starts when limits are turned on
+      bonding.royalty_change_period = royalty_change_limits.map(|_| RoyaltyChangePeriodV0 {
+        start_unix_time: unix_timestamp,
+        start_percentages: new_royalty_config.limited_percentages(),
+      })
+    }
+  }
+  bonding.royalty_change_limits = royalty_change_limits;
+
+  emit!(TokenBondingUpdateEventV0 {
+    token_bonding: bonding.key(),
+    general_authority: bonding.general_authority,
+    buy_base_royalty_percentage: bonding.buy_base_royalty_percentage,
+    buy_target_royalty_percentage: bonding.buy_target_royalty_percentage,
+    sell_base_royalty_percentage: bonding.sell_base_royalty_percentage,
+    sell_target_royalty_percentage: bonding.sell_target_royalty_percentage,
+    buy_base_royalties: bonding.buy_base_royalties,
+    buy_target_royalties: bonding.buy_target_royalties,
+    sell_base_royalties: bonding.sell_base_royalties,
+    sell_target_royalties: bonding.sell_target_royalties,
+    buy_frozen: bonding.buy_frozen,
+    unix_timestamp,
+  });
+
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const LIMITS: RoyaltyChangeLimitsV0 = RoyaltyChangeLimitsV0 {
+    max_change_percentage: 100,
+    period_secs: 1000,
+    max_royalty_percentage: 1000,
+  };
+
+  // Limited percentages of flat royalties, without price impact fees
+  fn limited(percentages: [u32; 4]) -> [u32; 6] {
+    RoyaltyConfig {
+      percentages,
+      accounts: [Pubkey::default(); 4],
+      vesting_destination: None,
+      price_impact_fees: None,
+    }
+    .limited_percentages()
+  }
+
+  fn period(start_percentages: [u32; 6]) -> RoyaltyChangePeriodV0 {
+    RoyaltyChangePeriodV0 {
+      start_unix_time: 0,
+      start_percentages,
+    }
+  }
+
+  #[test]
+  fn test_royalty_change_limits_only_tighten() {
+    let tighter = RoyaltyChangeLimitsV0 {
+      max_change_percentage: 50,
+      period_secs: 2000,
+      max_royalty_percentage: 500,
+    };
+    assert!(verify_royalty_change_limits(&None, &Some(LIMITS)).is_ok());
+    assert!(verify_royalty_change_limits(&Some(LIMITS), &Some(tighter)).is_ok());
+    assert!(verify_royalty_change_limits(&Some(tighter), &Some(LIMITS)).is_err());
+    assert!(verify_royalty_change_limits(&Some(LIMITS), &None).is_err());
+    let no_period = RoyaltyChangeLimitsV0 {
+      period_secs: 0,
+      ..LIMITS
+    };
+    assert!(verify_royalty_change_limits(&None, &Some(no_period)).is_err());
+  }
+
+  #[test]
+  fn test_royalty_change_within_period() {
+    let mut p = period(limited([0; 4]));
+    assert!(royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([0; 4]),
+      limited([100, 0, 50, 0]),
+      10
+    ));
+    // Raises are measured from the start of the period, not the last update
+    assert!(!royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([100, 0, 50, 0]),
+      limited([101, 0, 50, 0]),
+      20
+    ));
+    assert!(royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([100, 0, 50, 0]),
+      limited([100, 0, 100, 0]),
+      20
+    ));
+    // Decreases are never limited
+    assert!(royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([100, 0, 50, 0]),
+      limited([0; 4]),
+      20
+    ));
+  }
+
+  #[test]
+  fn test_royalty_change_period_rolls_over() {
+    let mut p = period(limited([0; 4]));
+    assert!(!royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([100, 0, 0, 0]),
+      limited([200, 0, 0, 0]),
+      999
+    ));
+    assert!(royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([100, 0, 0, 0]),
+      limited([200, 0, 0, 0]),
+      1000
+    ));
+    assert_eq!(p.start_unix_time, 1000);
+    assert_eq!(p.start_percentages, limited([100, 0, 0, 0]));
+  }
+
+  #[test]
+  fn test_royalty_change_max_percentage() {
+    let mut p = period(limited([950; 4]));
+    assert!(royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([950; 4]),
+      limited([1000; 4]),
+      10
+    ));
+    assert!(!royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([950; 4]),
+      limited([1001; 4]),
+      10
+    ));
+    // Royalties set above the max before the limits applied can still be lowered
+    let mut p = period(limited([u32::MAX; 4]));
+    assert!(royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      limited([u32::MAX; 4]),
+      limited([u32::MAX - 1; 4]),
+      10
+    ));
+  }
+
+  #[test]
+  fn test_enabling_price_impact_fees_is_limited() {
+    let flat = RoyaltyConfig {
+      percentages: [100, 0, 50, 0],
+      accounts: [Pubkey::default(); 4],
+      vesting_destination: None,
+      price_impact_fees: None,
+    };
+    let with_fees = |max_royalty_percentage| RoyaltyConfig {
+      price_impact_fees: Some(PriceImpactFeesV0 {
+        min_impact_percentage: 0,
+        max_impact_percentage: 100,
+        max_royalty_percentage,
+      }),
+      ..flat
+    };
+
+    // Large sells would jump from 50 to 1000
+    let mut p = period(flat.limited_percentages());
+    assert!(!royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      flat.limited_percentages(),
+      with_fees(1000).limited_percentages(),
+      10
+    ));
+    // Within the change limit for buys, but not for sells
+    assert!(!royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      flat.limited_percentages(),
+      with_fees(200).limited_percentages(),
+      10
+    ));
+    assert!(royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      flat.limited_percentages(),
+      with_fees(150).limited_percentages(),
+      10
+    ));
+    // Raising the impact royalty again in the same period is measured from the start
+    assert!(!royalty_change_is_allowed(
+      &LIMITS,
+      &mut p,
+      with_fees(150).limited_percentages(),
+      with_fees(151).limited_percentages(),
+      20
+    ));
+  }
+
+  #[test]
+  fn test_price_impact_fees_steepened() {
+    let fees = PriceImpactFeesV0 {
+      min_impact_percentage: 10,
+      max_impact_percentage: 100,
+      max_royalty_percentage: 1000,
+    };
+    assert!(!price_impact_fees_steepened(&None, &Some(fees)));
+    assert!(!price_impact_fees_steepened(&Some(fees), &None));
+    assert!(!price_impact_fees_steepened(
+      &Some(fees),
+      &Some(PriceImpactFeesV0 {
+        min_impact_percentage: 20,
+        ..fees
+      })
+    ));
+    assert!(price_impact_fees_steepened(
+      &Some(fees),
+      &Some(PriceImpactFeesV0 {
+        min_impact_percentage: 5,
+        ..fees
+      })
+    ));
+    assert!(price_impact_fees_steepened(
+      &Some(fees),
+      &Some(PriceImpactFeesV0 {
+        max_impact_percentage: 50,
+        ..fees
+      })
+    ));
+  }
+
+  #[test]
+  fn test_royalty_config_tracks_royalty_fields() {
+    let bonding = TokenBondingV0::default();
+    let mut updated = TokenBondingV0::default();
+    assert_eq!(royalty_config(&bonding), royalty_config(&updated));
+
+    // Non royalty fields are not frozen by royalties_locked
+    updated.buy_frozen = true;
+    updated.allowlist_root = Some([1; 32]);
+    assert_eq!(royalty_config(&bonding), royalty_config(&updated));
+
+    updated.sell_target_royalty_percentage = 1;
+    assert_ne!(royalty_config(&bonding), royalty_config(&updated));
+
+    let updated = TokenBondingV0 {
+      royalty_vesting: Some(RoyaltyVestingV0::default()),
+      ..Default::default()
+    };
+    assert_ne!(royalty_config(&bonding), royalty_config(&updated));
+  }
+
+  #[test]
+  fn test_caps_config_tracks_cap_fields() {
+    let bonding = TokenBondingV0::default();
+
+    // Launch gates are not frozen by caps_locked
+    let updated = TokenBondingV0 {
+      allowlist_root: Some([1; 32]),
+      allowlist_end_unix_time: Some(1),
+      buy_gate: Some(BuyGateV0::default()),
+      ..Default::default()
+    };
+    assert_eq!(caps_config(&bonding), caps_config(&updated));
+
+    let updated = TokenBondingV0 {
+      mint_cap: Some(1),
+      ..Default::default()
+    };
+    assert_ne!(caps_config(&bonding), caps_config(&updated));
+    let updated = TokenBondingV0 {
+      purchase_cap: Some(1),
+      ..Default::default()
+    };
+    assert_ne!(caps_config(&bonding), caps_config(&updated));
+    let updated = TokenBondingV0 {
+      wallet_purchase_cap: Some(1),
+      ..Default::default()
+    };
+    assert_ne!(caps_config(&bonding), caps_config(&updated));
+    let updated = TokenBondingV0 {
+      wallet_purchase_window_secs: Some(1),
+      ..Default::default()
+    };
+    assert_ne!(caps_config(&bonding), caps_config(&updated));
+  }
+}
@@ -0,0 +1,23 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+/// Permanently winds down the token bonding so holders can exit fairly. Buys and reserve
+/// transfers stop, and sells redeem a pro-rata share of base storage. Once the whole supply is
+/// gone, close_token_bonding_v0 sweeps whatever is left to the general authority
+#[derive(Accounts)]
+pub struct WindDownTokenBondingV0<'info> {
+  #[account(
+    mut,
+    constraint = token_bonding.general_authority.ok_or(error!(ErrorCode::NoAuthority))? == general_authority.key()
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  pub general_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<WindDownTokenBondingV0>) -> Result<()> {
+  let bonding = &mut ctx.accounts.token_bonding;
+  bonding.wind_down = true;
+  bonding.buy_frozen = true;
+
+  Ok(())
+}
@@ -5,8 +5,11 @@ use anchor_lang::prelude::*;
 pub mod curve;
 pub mod error;
 pub mod instructions;
+pub mod oracle;
 pub mod precise_number;
+pub mod quote;
 pub mod signed_precise_number;
+pub mod stake_pool;
 pub mod state;
 pub mod uint;
 pub mod util;
@@ -27,6 +30,36 @@ pub mod spl_token_bonding {
     instructions::initialize_sol_storage_v0::handler(ctx, args)
   }
 
+  pub fn initialize_reserve_staking_v0(
+    ctx: Context<InitializeReserveStakingV0>,
+    args: InitializeReserveStakingV0Args,
+  ) -> Result<()> {
+    instructions::reserve_staking::initialize_reserve_staking_v0::handler(ctx, args)
+  }
+
+  pub fn update_reserve_staking_v0(
+    ctx: Context<UpdateReserveStakingV0>,
+    args: UpdateReserveStakingV0Args,
+  ) -> Result<()> {
+    instructions::reserve_staking::update_reserve_staking_v0::handler(ctx, args)
+  }
+
+  pub fn initialize_reserve_stake_v0(ctx: Context<InitializeReserveStakeV0>) -> Result<()> {
+    instructions::reserve_staking::initialize_reserve_stake_v0::handler(ctx)
+  }
+
+  pub fn rebalance_reserve_staking_v0(ctx: Context<RebalanceReserveStakingV0>) -> Result<()> {
+    instructions::reserve_staking::rebalance_reserve_staking_v0::handler(ctx)
+  }
+
+  pub fn claim_reserve_yield_v0(ctx: Context<ClaimReserveYieldV0>) -> Result<()> {
+    instructions::reserve_staking::claim_reserve_yield_v0::handler(ctx)
+  }
+
+  pub fn add_reserve_yield_v0(ctx: Context<AddReserveYieldV0>) -> Result<()> {
+    instructions::reserve_staking::add_reserve_yield_v0::handler(ctx)
+  }
+
   pub fn buy_wrapped_sol_v0(
     ctx: Context<BuyWrappedSolV0>,
     args: BuyWrappedSolV0Args,
@@ -48,11 +81,21 @@ pub mod spl_token_bonding {
   pub fn initialize_token_bonding_v0(
     ctx: Context<InitializeTokenBondingV0>,
     args: InitializeTokenBondingV0Args,
+  ) -> Result<()> {
+    instructions::initialize_token_bonding_v0::handler(ctx, args.into())
+  }
+
+  // Same accounts as initialize_token_bonding_v0, with the v1 args
+  pub fn initialize_token_bonding_v1(
+    ctx: Context<InitializeTokenBondingV0>,
+    args: InitializeTokenBondingV1Args,
   ) -> Result<()> {
     instructions::initialize_token_bonding_v0::handler(ctx, args)
   }
 
-  pub fn close_token_bonding_v0(ctx: Context<CloseTokenBondingV0>) -> Result<()> {
+  pub fn close_token_bonding_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseTokenBondingV0<'info>>,
+  ) -> Result<()> {
     instructions::close_token_bonding_v0::handler(ctx)
   }
 
@@ -70,6 +113,20 @@ pub mod spl_token_bonding {
     instructions::transfer_reserves::transfer_reserves_native_v0::handler(ctx, args)
   }
 
+  pub fn transfer_reserves_v1(
+    ctx: Context<TransferReservesV1>,
+    args: TransferReservesV0Args,
+  ) -> Result<()> {
+    instructions::transfer_reserves::transfer_reserves_v1::handler(ctx, args)
+  }
+
+  pub fn transfer_reserves_native_v1(
+    ctx: Context<TransferReservesNativeV1>,
+    args: TransferReservesV0Args,
+  ) -> Result<()> {
+    instructions::transfer_reserves::transfer_reserves_native_v1::handler(ctx, args)
+  }
+
   pub fn update_reserve_authority_v0(
     ctx: Context<UpdateReserveAuthorityV0>,
     args: UpdateReserveAuthorityV0Args,
@@ -78,29 +135,179 @@ pub mod spl_token_bonding {
   }
 
   pub fn update_curve_v0(ctx: Context<UpdateCurveV0>, args: UpdateCurveV0Args) -> Result<()> {
+    instructions::update_curve_v0::handler(ctx, args.into())
+  }
+
+  // Same accounts as update_curve_v0, with the v1 args
+  pub fn update_curve_v1(ctx: Context<UpdateCurveV0>, args: UpdateCurveV1Args) -> Result<()> {
     instructions::update_curve_v0::handler(ctx, args)
   }
 
+  pub fn propose_curve_update_v0(
+    ctx: Context<ProposeCurveUpdateV0>,
+    args: ProposeCurveUpdateV0Args,
+  ) -> Result<()> {
+    instructions::propose_curve_update_v0::handler(ctx, args)
+  }
+
+  pub fn apply_curve_update_v0(ctx: Context<ApplyCurveUpdateV0>) -> Result<()> {
+    instructions::apply_curve_update_v0::handler(ctx)
+  }
+
+  pub fn cancel_curve_update_v0(ctx: Context<CancelCurveUpdateV0>) -> Result<()> {
+    instructions::cancel_curve_update_v0::handler(ctx)
+  }
+
+  pub fn lock_token_bonding_v0(
+    ctx: Context<LockTokenBondingV0>,
+    args: LockTokenBondingV0Args,
+  ) -> Result<()> {
+    instructions::lock_token_bonding_v0::handler(ctx, args)
+  }
+
+  pub fn lock_curve_v0(ctx: Context<LockCurveV0>) -> Result<()> {
+    instructions::lock_curve_v0::handler(ctx)
+  }
+
+  pub fn wind_down_token_bonding_v0(ctx: Context<WindDownTokenBondingV0>) -> Result<()> {
+    instructions::wind_down_token_bonding_v0::handler(ctx)
+  }
+
+  pub fn initialize_price_oracle_v0(
+    ctx: Context<InitializePriceOracleV0>,
+    args: InitializePriceOracleV0Args,
+  ) -> Result<()> {
+    instructions::initialize_price_oracle_v0::handler(ctx, args)
+  }
+
+  pub fn record_price_observation_v0(ctx: Context<RecordPriceObservationV0>) -> Result<()> {
+    instructions::record_price_observation_v0::handler(ctx)
+  }
+
+  pub fn get_twap_v0(ctx: Context<GetTwapV0>, args: GetTwapV0Args) -> Result<()> {
+    instructions::get_twap_v0::handler(ctx, args)
+  }
+
   pub fn update_token_bonding_v0(
     ctx: Context<UpdateTokenBondingV0>,
     args: UpdateTokenBondingV0Args,
+  ) -> Result<()> {
+    instructions::update_token_bonding_v0::handler(ctx, args.into())
+  }
+
+  // Same accounts as update_token_bonding_v0, with the v1 args
+  pub fn update_token_bonding_v1(
+    ctx: Context<UpdateTokenBondingV0>,
+    args: UpdateTokenBondingV1Args,
   ) -> Result<()> {
     instructions::update_token_bonding_v0::handler(ctx, args)
   }
 
   pub fn buy_v1(ctx: Context<BuyV1>, args: BuyV0Args) -> Result<()> {
-    instructions::buy::buy_v1::handler(ctx, args)
+    instructions::buy::buy_v1::handler(ctx, args.into())
   }
 
   pub fn buy_native_v0(ctx: Context<BuyNativeV0>, args: BuyV0Args) -> Result<()> {
+    instructions::buy::buy_native_v0::handler(ctx, args.into())
+  }
+
+  // Same accounts as buy_v1, with the v1 args
+  pub fn buy_v2(ctx: Context<BuyV1>, args: BuyV1Args) -> Result<()> {
+    instructions::buy::buy_v1::handler(ctx, args)
+  }
+
+  // Same accounts as buy_native_v0, with the v1 args
+  pub fn buy_native_v1(ctx: Context<BuyNativeV0>, args: BuyV1Args) -> Result<()> {
     instructions::buy::buy_native_v0::handler(ctx, args)
   }
 
-  pub fn sell_v1(ctx: Context<SellV1>, args: SellV0Args) -> Result<()> {
+  pub fn sell_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellV1<'info>>,
+    args: SellV0Args,
+  ) -> Result<()> {
+    instructions::sell::sell_v1::handler(ctx, args.into())
+  }
+
+  pub fn sell_native_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNativeV0<'info>>,
+    args: SellV0Args,
+  ) -> Result<()> {
+    instructions::sell::sell_native_v0::handler(ctx, args.into())
+  }
+
+  // Same accounts as sell_v1, with the v2 args
+  pub fn sell_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellV1<'info>>,
+    args: SellV2Args,
+  ) -> Result<()> {
     instructions::sell::sell_v1::handler(ctx, args)
   }
 
-  pub fn sell_native_v0(ctx: Context<SellNativeV0>, args: SellV0Args) -> Result<()> {
+  // Same accounts as sell_native_v0, with the v2 args
+  pub fn sell_native_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellNativeV0<'info>>,
+    args: SellV2Args,
+  ) -> Result<()> {
     instructions::sell::sell_native_v0::handler(ctx, args)
   }
+
+  pub fn quote_buy_v0(ctx: Context<QuoteBuyV0>, args: BuyV0Args) -> Result<()> {
+    instructions::buy::quote_buy_v0::handler(ctx, args)
+  }
+
+  pub fn quote_sell_v0(ctx: Context<QuoteSellV0>, args: SellV2Args) -> Result<()> {
+    instructions::sell::quote_sell_v0::handler(ctx, args)
+  }
+
+  pub fn quote_spot_price_v0(ctx: Context<QuoteSpotPriceV0>) -> Result<()> {
+    instructions::quote_spot_price_v0::handler(ctx)
+  }
+
+  pub fn quote_solvency_v0(ctx: Context<QuoteSolvencyV0>) -> Result<()> {
+    instructions::quote_solvency_v0::handler(ctx)
+  }
+
+  pub fn swap_v0(ctx: Context<SwapV0>, args: SwapV0Args) -> Result<()> {
+    instructions::swap_v0::handler(ctx, args)
+  }
+
+  pub fn route_swap_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, RouteSwapV0<'info>>,
+    args: RouteSwapV0Args,
+  ) -> Result<()> {
+    instructions::route_swap_v0::handler(ctx, args)
+  }
+
+  pub fn initialize_wallet_purchase_v0(ctx: Context<InitializeWalletPurchaseV0>) -> Result<()> {
+    instructions::initialize_wallet_purchase_v0::handler(ctx)
+  }
+
+  pub fn resize_token_bonding_v0(ctx: Context<ResizeTokenBondingV0>) -> Result<()> {
+    instructions::resize_token_bonding_v0::handler(ctx)
+  }
+
+  pub fn initialize_royalty_vesting_v0(
+    ctx: Context<InitializeRoyaltyVestingV0>,
+    args: InitializeRoyaltyVestingV0Args,
+  ) -> Result<()> {
+    instructions::initialize_royalty_vesting_v0::handler(ctx, args)
+  }
+
+  pub fn claim_vested_royalties_v0(ctx: Context<ClaimVestedRoyaltiesV0>) -> Result<()> {
+    instructions::claim_vested_royalties_v0::handler(ctx)
+  }
+
+  pub fn initialize_royalty_split_v0(
+    ctx: Context<InitializeRoyaltySplitV0>,
+    args: InitializeRoyaltySplitV0Args,
+  ) -> Result<()> {
+    instructions::initialize_royalty_split_v0::handler(ctx, args)
+  }
+
+  pub fn claim_royalty_split_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimRoyaltySplitV0<'info>>,
+    args: ClaimRoyaltySplitV0Args,
+  ) -> Result<()> {
+    instructions::claim_royalty_split_v0::handler(ctx, args)
+  }
 }
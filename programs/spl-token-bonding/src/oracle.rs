@@ -0,0 +1,223 @@
+//! Time weighted average prices. Every buy and sell adds the token bonding's price to its price
+//! accumulator, and price oracles checkpoint the accumulator so averages can be taken over a window.
+
+use anchor_lang::{prelude::*, Discriminator};
+use std::convert::TryFrom;
+
+use crate::{
+  error::ErrorCode,
+  state::{PriceAccumulatorV0, PriceObservationV0, PriceOracleV0, PRICE_ORACLE_OBSERVATIONS},
+};
+
+/// Returned by get_twap_v0 via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq)]
+pub struct TwapV0 {
+  /// Average base tokens per whole target token between the two times
+  pub price: u64,
+  pub start_unix_time: i64,
+  pub end_unix_time: i64,
+}
+
+/// Base tokens per whole target token paid in a trade, or None for an empty trade
+pub fn trade_price(base_amount: u64, target_amount: u64, target_decimals: u8) -> Option<u64> {
+  if target_amount == 0 {
+    return None;
+  }
+
+  let price = (base_amount as u128)
+    .checked_mul(10_u128.checked_pow(target_decimals as u32)?)?
+    .checked_div(target_amount as u128)?;
+  Some(u64::try_from(price).unwrap_or(u64::MAX))
+}
+
+/// The accumulator's cumulative price as of `unix_time`, extending the last price to then
+pub fn cumulative_price_at(accumulator: &PriceAccumulatorV0, unix_time: i64) -> u128 {
+  let elapsed = unix_time.saturating_sub(accumulator.last_unix_time).max(0) as u128;
+  accumulator
+    .cumulative_price
+    .wrapping_add((accumulator.last_price as u128).wrapping_mul(elapsed))
+}
+
+/// Close out the previous price up to `unix_time` and start accumulating `price`
+pub fn accumulate_price(accumulator: &mut PriceAccumulatorV0, price: u64, unix_time: i64) {
+  accumulator.cumulative_price = cumulative_price_at(accumulator, unix_time);
+  accumulator.last_unix_time = accumulator.last_unix_time.max(unix_time);
+  accumulator.last_price = price;
+}
+
+/// Checkpoint the accumulator, unless the last observation is more recent than the observation
+/// interval. Returns whether an observation was written
+pub fn record_observation(
+  oracle: &mut PriceOracleV0,
+  accumulator: &PriceAccumulatorV0,
+  unix_time: i64,
+) -> bool {
+  if let Some(latest) = latest_observation(oracle) {
+    if unix_time
+      < latest
+        .unix_time
+        .saturating_add(oracle.observation_interval_secs.max(1))
+    {
+      return false;
+    }
+  }
+
+  let observation = PriceObservationV0 {
+    unix_time,
+    cumulative_price: cumulative_price_at(accumulator, unix_time),
+  };
+  let index = oracle.next_index as usize;
+  if index < oracle.observations.len() {
+    oracle.observations[index] = observation;
+  } else {
+    oracle.observations.push(observation);
+  }
+  oracle.next_index = ((index + 1) % PRICE_ORACLE_OBSERVATIONS) as u16;
+
+  true
+}
+
+fn latest_observation(oracle: &PriceOracleV0) -> Option<&PriceObservationV0> {
+  let len = oracle.observations.len();
+  if len == 0 {
+    return None;
+  }
+
+  oracle
+    .observations
+    .get((oracle.next_index as usize + len - 1) % len)
+}
+
+/// Buys and sells take the token bonding's price oracle as an optional first remaining account.
+/// Returns the oracle, if it was passed, and the rest of the remaining accounts
+pub fn take_price_oracle<'a, 'info>(
+  token_bonding: &Pubkey,
+  remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(
+  Option<Account<'info, PriceOracleV0>>,
+  &'a [AccountInfo<'info>],
+)> {
+  match remaining_accounts.split_first() {
+    Some((first, rest))
+      if *first.owner == crate::id()
+        && first.try_borrow_data()?.get(..8) == Some(&PriceOracleV0::discriminator()[..]) =>
+    {
+      let price_oracle: Account<PriceOracleV0> = Account::try_from(first)?;
+      if price_oracle.token_bonding != *token_bonding {
+        return Err(error!(ErrorCode::InvalidPriceOracle));
+      }
+      Ok((Some(price_oracle), rest))
+    }
+    _ => Ok((None, remaining_accounts)),
+  }
+}
+
+/// Average price over at least the last `window_secs`, measured from the newest observation at
+/// least that old
+pub fn twap(
+  oracle: &PriceOracleV0,
+  accumulator: &PriceAccumulatorV0,
+  unix_time: i64,
+  window_secs: i64,
+) -> Result<TwapV0> {
+  let start_unix_time = unix_time.saturating_sub(window_secs);
+  let start = oracle
+    .observations
+    .iter()
+    .filter(|o| o.unix_time <= start_unix_time)
+    .max_by_key(|o| o.unix_time)
+    .ok_or_else(|| error!(ErrorCode::PriceOracleWindowTooLong))?;
+  let elapsed = unix_time.saturating_sub(start.unix_time);
+  if elapsed <= 0 {
+    return Err(error!(ErrorCode::PriceOracleWindowTooLong));
+  }
+
+  let price = cumulative_price_at(accumulator, unix_time).wrapping_sub(start.cumulative_price)
+    / elapsed as u128;
+  Ok(TwapV0 {
+    price: u64::try_from(price).unwrap_or(u64::MAX),
+    start_unix_time: start.unix_time,
+    end_unix_time: unix_time,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn oracle(observation_interval_secs: i64) -> PriceOracleV0 {
+    PriceOracleV0 {
+      observation_interval_secs,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_trade_price() {
+    assert_eq!(
+      trade_price(2_000_000_000, 1_000_000, 6),
+      Some(2_000_000_000)
+    );
+    assert_eq!(trade_price(1, 3, 0), Some(0));
+    assert_eq!(trade_price(1, 0, 6), None);
+    assert_eq!(trade_price(u64::MAX, 1, 9), Some(u64::MAX));
+  }
+
+  #[test]
+  fn test_twap_weights_prices_by_time() {
+    let mut accumulator = PriceAccumulatorV0::default();
+    let mut oracle = oracle(1);
+    accumulate_price(&mut accumulator, 100, 1000);
+    assert!(record_observation(&mut oracle, &accumulator, 1000));
+
+    // 100 for 90 seconds, then a one second spike to 10,000
+    accumulate_price(&mut accumulator, 10_000, 1090);
+    accumulate_price(&mut accumulator, 100, 1091);
+    assert_eq!(
+      twap(&oracle, &accumulator, 1100, 100).unwrap(),
+      TwapV0 {
+        price: 199,
+        start_unix_time: 1000,
+        end_unix_time: 1100,
+      }
+    );
+
+    // The window reaches back further than the history
+    assert!(twap(&oracle, &accumulator, 1100, 101).is_err());
+    assert!(twap(&oracle, &accumulator, 1000, 0).is_err());
+  }
+
+  #[test]
+  fn test_twap_uses_newest_observation_before_window() {
+    let mut accumulator = PriceAccumulatorV0::default();
+    let mut oracle = oracle(10);
+    accumulate_price(&mut accumulator, 100, 0);
+    assert!(record_observation(&mut oracle, &accumulator, 0));
+    accumulate_price(&mut accumulator, 200, 50);
+    assert!(record_observation(&mut oracle, &accumulator, 50));
+
+    assert_eq!(twap(&oracle, &accumulator, 100, 50).unwrap().price, 200);
+    assert_eq!(twap(&oracle, &accumulator, 100, 60).unwrap().price, 150);
+  }
+
+  #[test]
+  fn test_record_observation_respects_interval_and_wraps() {
+    let accumulator = PriceAccumulatorV0::default();
+    let mut oracle = oracle(10);
+    assert!(record_observation(&mut oracle, &accumulator, 0));
+    assert!(!record_observation(&mut oracle, &accumulator, 9));
+    assert!(record_observation(&mut oracle, &accumulator, 10));
+
+    for i in 2..(PRICE_ORACLE_OBSERVATIONS as i64 + 5) {
+      assert!(record_observation(&mut oracle, &accumulator, i * 10));
+    }
+    assert_eq!(oracle.observations.len(), PRICE_ORACLE_OBSERVATIONS);
+    assert_eq!(oracle.next_index, 5);
+    assert_eq!(
+      latest_observation(&oracle).unwrap().unix_time,
+      (PRICE_ORACLE_OBSERVATIONS as i64 + 4) * 10
+    );
+    // The oldest observations were overwritten
+    assert!(oracle.observations.iter().all(|o| o.unix_time >= 50));
+  }
+}
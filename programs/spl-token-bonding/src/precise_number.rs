@@ -472,6 +472,14 @@ impl PreciseNumber {
     x.exp()
   }
 
+  /// Square root, exact to the last decimal place. Unlike pow(HALF) this does not go through log and exp
+  pub fn sqrt(&self) -> Option<Self> {
+    // sqrt(x ONE) = sqrt(x) ONE when x is stored as x ONE
+    Some(Self {
+      value: self.value.checked_mul(one())?.integer_sqrt(),
+    })
+  }
+
   pub fn print(&self) {
     let whole = self.floor().unwrap().to_imprecise().unwrap();
     let decimals = self
@@ -512,6 +520,20 @@ mod tests {
     assert!(squared.almost_eq(&expected, precision));
   }
 
+  #[test]
+  fn test_sqrt() {
+    let precision = InnerUint::from(10_u128); // correct to at least 17 decimal places
+    let two = PreciseNumber::new(2).unwrap();
+    let expected = PreciseNumber::new(1414213562373095048)
+      .unwrap()
+      .checked_div(&PreciseNumber::new(1000000000000000000).unwrap())
+      .unwrap();
+    assert!(two.sqrt().unwrap().almost_eq(&expected, precision));
+
+    let nine = PreciseNumber::new(9).unwrap();
+    assert!(nine.sqrt().unwrap().eq(&PreciseNumber::new(3).unwrap()));
+  }
+
   #[test]
   fn test_log() {
     let precision = InnerUint::from(5_000_000_000_u128); // correct to at least 9 decimal places
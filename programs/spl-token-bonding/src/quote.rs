@@ -0,0 +1,1428 @@
+//! Buy and sell pricing without any accounts. Build with the `no-entrypoint` feature to quote
+//! off chain with exactly the same math the program uses.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::{
+  curve::*,
+  error::ErrorCode,
+  instructions::{buy::BuyV1Args, sell::SellV2Args},
+  oracle::{accumulate_price, trade_price},
+  precise_number::PreciseNumber,
+  state::{CurveV0, PriceImpactFeesV0, TokenBondingV0},
+  util::*,
+};
+use std::convert::*;
+
+pub struct BuyAmount {
+  pub price: u64,
+  pub total_amount: u64,
+  pub base_royalties: u64,
+  pub target_royalties: u64,
+}
+
+pub struct SellAmount {
+  pub total_amount: u64,
+  pub reclaimed: u64,
+  pub base_royalties: u64,
+  pub target_royalties: u64,
+}
+
+/// The on chain state, other than the token bonding and curve, that a buy or sell is priced from
+#[derive(Clone, Debug, Default)]
+pub struct QuoteState {
+  pub base_decimals: u8,
+  pub target_decimals: u8,
+  /// Amount held in base storage
+  pub base_storage_amount: u64,
+  /// Supply of the target mint
+  pub target_supply: u64,
+  pub unix_timestamp: i64,
+}
+
+impl QuoteState {
+  pub fn from_accounts(
+    base_mint: &Mint,
+    target_mint: &Mint,
+    base_storage: &TokenAccount,
+    clock: &Clock,
+  ) -> Self {
+    QuoteState {
+      base_decimals: base_mint.decimals,
+      target_decimals: target_mint.decimals,
+      base_storage_amount: base_storage.amount,
+      target_supply: target_mint.supply,
+      unix_timestamp: clock.unix_timestamp,
+    }
+  }
+}
+
+/// Returned by the quote instructions via return data. For sells, price is what the seller receives and
+/// total_amount is the amount sold, including target royalties
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct QuoteV0 {
+  pub price: u64,
+  pub total_amount: u64,
+  pub base_royalties: u64,
+  pub target_royalties: u64,
+}
+
+/// Returned by quote_spot_price_v0 via return data. Base tokens per whole target token for the next
+/// token bought or sold, before royalties
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq)]
+pub struct SpotPriceV0 {
+  pub buy_price: u64,
+  pub sell_price: u64,
+}
+
+/// Marginal prices as of `state`, without modifying the token bonding
+pub fn quote_spot_price(
+  token_bonding: &TokenBondingV0,
+  curve: &CurveV0,
+  state: &QuoteState,
+) -> Result<SpotPriceV0> {
+  let mut token_bonding = token_bonding.clone();
+  init_balances_from_bonding(&mut token_bonding, state);
+  let (base_amount, target_supply) = reserves_and_supply(&token_bonding, state);
+  // Before go live, quote the price at launch
+  let time_offset = state
+    .unix_timestamp
+    .saturating_sub(token_bonding.go_live_unix_time)
+    .max(0);
+  let spot_price = |sell: bool| -> Result<u64> {
+    let price = curve
+      .definition
+      .spot_price(time_offset, &base_amount, &target_supply, sell)
+      .or_arith_error()?;
+    Ok(to_decimals_amount(&price, state.base_decimals, false))
+  };
+
+  let sell_price = if token_bonding.wind_down {
+    // Sells redeem pro-rata, see redeem_amounts
+    trade_price(
+      state.base_storage_amount,
+      supply_amount(&token_bonding, state.target_supply),
+      state.target_decimals,
+    )
+    .unwrap_or(0)
+  } else {
+    spot_price(true)?
+  };
+
+  Ok(SpotPriceV0 {
+    buy_price: spot_price(false)?,
+    sell_price,
+  })
+}
+
+/// Returned by quote_solvency_v0 via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq)]
+pub struct SolvencyV0 {
+  /// Amount that can be paid out of base storage. For native bondings, only what sol storage holds
+  /// unstaked
+  pub reserves: u64,
+  /// What the curve would pay out to buy back all outstanding supply
+  pub required_reserves: u64,
+  /// reserves / required_reserves in basis points, or u64::MAX when nothing is required
+  pub solvency_ratio_bps: u64,
+}
+
+impl SolvencyV0 {
+  pub fn is_solvent(&self) -> bool {
+    self.reserves >= self.required_reserves
+  }
+}
+
+/// Whether `reserves` cover buying back all outstanding supply as of `state`. The curve is still
+/// priced from the full base storage amount in `state`
+pub fn solvency(
+  token_bonding: &TokenBondingV0,
+  curve: &CurveV0,
+  state: &QuoteState,
+  reserves: u64,
+) -> Result<SolvencyV0> {
+  let mut token_bonding = token_bonding.clone();
+  init_balances_from_bonding(&mut token_bonding, state);
+  let (base_amount, target_supply) = reserves_and_supply(&token_bonding, state);
+  let time_offset = state
+    .unix_timestamp
+    .saturating_sub(token_bonding.go_live_unix_time)
+    .max(0);
+  let required_reserves = required_reserves(
+    curve,
+    state,
+    time_offset,
+    &base_amount,
+    &target_supply,
+    supply_amount(&token_bonding, state.target_supply),
+  )?;
+
+  Ok(SolvencyV0 {
+    reserves,
+    required_reserves,
+    solvency_ratio_bps: if required_reserves == 0 {
+      u64::MAX
+    } else {
+      u64::try_from(reserves as u128 * 10_000 / required_reserves as u128).unwrap_or(u64::MAX)
+    },
+  })
+}
+
+// What selling every outstanding token back to the curve pays out. Supply padded onto the curve
+// is never sold back, and the curve cannot buy back more than it thinks it issued
+fn required_reserves(
+  curve: &CurveV0,
+  state: &QuoteState,
+  time_offset: i64,
+  base_amount: &PreciseNumber,
+  target_supply: &PreciseNumber,
+  supply: u64,
+) -> Result<u64> {
+  let outstanding = supply.min(state.target_supply);
+  if outstanding == 0 {
+    return Ok(0);
+  }
+
+  let required = curve
+    .definition
+    .price(
+      time_offset,
+      base_amount,
+      target_supply,
+      &precise_decimals_amt(outstanding, state.target_decimals),
+      true,
+    )
+    .or_arith_error()?;
+  Ok(to_decimals_amount(&required, state.base_decimals, false))
+}
+
+/// Price a buy as of `state`, without modifying the token bonding
+pub fn quote_buy(
+  token_bonding: &TokenBondingV0,
+  curve: &CurveV0,
+  state: &QuoteState,
+  args: &BuyV1Args,
+) -> Result<BuyAmount> {
+  buy_amounts(&mut token_bonding.clone(), curve, state, args)
+}
+
+/// Price a sell as of `state`, without modifying the token bonding
+pub fn quote_sell(
+  token_bonding: &TokenBondingV0,
+  curve: &CurveV0,
+  state: &QuoteState,
+  args: &SellV2Args,
+) -> Result<SellAmount> {
+  sell_amounts(&mut token_bonding.clone(), curve, state, args)
+}
+
+// Not yet initialized since reserve_balance_from_bonding is a new feature
+fn init_balances_from_bonding(token_bonding: &mut TokenBondingV0, state: &QuoteState) {
+  if !token_bonding.sell_frozen
+    && state.target_supply > 0
+    && token_bonding.reserve_balance_from_bonding == 0
+    && token_bonding.go_live_unix_time < 1646092800_i64
+  {
+    token_bonding.reserve_balance_from_bonding = state.base_storage_amount;
+    token_bonding.supply_from_bonding = state.target_supply;
+  }
+}
+
+/// Outstanding supply of a token bonding, as its curve and redemptions see it. `target_supply`
+/// is the supply of the target mint
+pub fn supply_amount(token_bonding: &TokenBondingV0, target_supply: u64) -> u64 {
+  if token_bonding.ignore_external_supply_changes {
+    token_bonding.supply_from_bonding
+  } else {
+    target_supply
+  }
+}
+
+/// Base storage that can be paid out without unstaking. Staked lamports still back the
+/// reserves, but cannot leave until they are unstaked
+pub fn liquid_reserves(token_bonding: &TokenBondingV0, base_storage_amount: u64) -> u64 {
+  base_storage_amount.saturating_sub(
+    token_bonding
+      .reserve_stake
+      .map_or(0, |stake| stake.staked_lamports),
+  )
+}
+
+fn reserves_and_supply(
+  token_bonding: &TokenBondingV0,
+  state: &QuoteState,
+) -> (PreciseNumber, PreciseNumber) {
+  let base_amount_u64 = if token_bonding.ignore_external_reserve_changes {
+    token_bonding.reserve_balance_from_bonding
+  } else {
+    state.base_storage_amount
+  };
+
+  (
+    precise_decimals_amt(base_amount_u64, state.base_decimals),
+    precise_decimals_amt(
+      supply_amount(token_bonding, state.target_supply),
+      state.target_decimals,
+    ),
+  )
+}
+
+/// The base royalty percentage for trading `amount` of a `supply`, raised from the flat
+/// `royalty_percentage` by price impact fees
+pub fn impact_royalty_percentage(
+  price_impact_fees: &Option<PriceImpactFeesV0>,
+  royalty_percentage: u32,
+  amount: u64,
+  supply: u64,
+) -> u32 {
+  let fees = match price_impact_fees {
+    Some(fees) if fees.max_royalty_percentage > royalty_percentage => fees,
+    _ => return royalty_percentage,
+  };
+
+  let impact = if supply == 0 {
+    u32::MAX as u128
+  } else {
+    (amount as u128 * u32::MAX as u128 / supply as u128).min(u32::MAX as u128)
+  };
+  let min_impact = fees.min_impact_percentage as u128;
+  let max_impact = fees.max_impact_percentage as u128;
+  if impact <= min_impact {
+    return royalty_percentage;
+  }
+  if impact >= max_impact {
+    return fees.max_royalty_percentage;
+  }
+
+  let extra = (fees.max_royalty_percentage - royalty_percentage) as u128 * (impact - min_impact)
+    / (max_impact - min_impact);
+  royalty_percentage + extra as u32
+}
+
+/// Price a buy and update the token bonding's balances from bonding, as the buy instructions do
+pub fn buy_amounts(
+  token_bonding: &mut TokenBondingV0,
+  curve: &CurveV0,
+  state: &QuoteState,
+  args: &BuyV1Args,
+) -> Result<BuyAmount> {
+  init_balances_from_bonding(token_bonding, state);
+  let (base_amount, target_supply) = reserves_and_supply(token_bonding, state);
+
+  if token_bonding.go_live_unix_time > state.unix_timestamp {
+    return Err(error!(ErrorCode::NotLiveYet));
+  }
+
+  if token_bonding.buy_frozen || token_bonding.wind_down {
+    return Err(error!(ErrorCode::BuyFrozen));
+  }
+
+  if token_bonding.freeze_buy_unix_time.is_some()
+    && token_bonding.freeze_buy_unix_time.unwrap() < state.unix_timestamp
+  {
+    return Err(error!(ErrorCode::BuyFrozen));
+  }
+
+  let base_royalties_percent = token_bonding.buy_base_royalty_percentage;
+  let target_royalties_percent = token_bonding.buy_target_royalty_percentage;
+  let time_offset = state
+    .unix_timestamp
+    .checked_sub(token_bonding.go_live_unix_time)
+    .unwrap();
+
+  let price: u64;
+  let total_amount: u64;
+  let base_royalties: u64;
+  let target_royalties: u64;
+  if args.buy_target_amount.is_some() {
+    let buy_target_amount = args.buy_target_amount.clone().unwrap();
+
+    total_amount = buy_target_amount.target_amount;
+    let amount_prec = precise_decimals_amt(total_amount, state.target_decimals);
+    let price_prec = curve
+      .definition
+      .price(
+        time_offset,
+        &base_amount,
+        &target_supply,
+        &amount_prec,
+        false,
+      )
+      .or_arith_error()?;
+
+    price = to_decimals_amount(&price_prec, state.base_decimals, true);
+    base_royalties = get_percent(
+      price,
+      impact_royalty_percentage(
+        &token_bonding.price_impact_fees,
+        base_royalties_percent,
+        total_amount,
+        supply_amount(token_bonding, state.target_supply).saturating_add(total_amount),
+      ),
+    )?;
+    target_royalties = get_percent(total_amount, target_royalties_percent)?;
+
+    if price.checked_add(base_royalties).unwrap() > buy_target_amount.maximum_price {
+      msg!(
+        "Price {} too high for max price {}",
+        price + base_royalties,
+        buy_target_amount.maximum_price
+      );
+      return Err(error!(ErrorCode::PriceTooHigh));
+    }
+  } else {
+    let buy_with_base = args.buy_with_base.clone().unwrap();
+    let total_price = buy_with_base.base_amount;
+    let buy_with_royalties = |royalties_percent: u32| -> Result<(u64, u64, u64)> {
+      let base_royalties = get_percent(total_price, royalties_percent)?;
+      let price_prec = precise_decimals_amt(
+        total_price.checked_sub(base_royalties).or_arith_error()?,
+        state.base_decimals,
+      );
+
+      let amount_prec = curve
+        .definition
+        .expected_target_amount(time_offset, &base_amount, &target_supply, &price_prec)
+        // No closed form for this curve, invert the price numerically
+        .or_else(|| {
+          solve_target_amount(
+            &curve.definition,
+            time_offset,
+            &base_amount,
+            &target_supply,
+            &price_prec,
+          )
+        })
+        .or_arith_error()?;
+
+      Ok((
+        base_royalties,
+        to_decimals_amount(&price_prec, state.base_decimals, false),
+        to_decimals_amount(&amount_prec, state.target_decimals, false),
+      ))
+    };
+
+    // Price impact is measured on what the flat royalty would have bought
+    let (flat_base_royalties, flat_price, flat_total_amount) =
+      buy_with_royalties(base_royalties_percent)?;
+    let impact_percent = impact_royalty_percentage(
+      &token_bonding.price_impact_fees,
+      base_royalties_percent,
+      flat_total_amount,
+      supply_amount(token_bonding, state.target_supply).saturating_add(flat_total_amount),
+    );
+    let (royalties, buy_price, amount) = if impact_percent == base_royalties_percent {
+      (flat_base_royalties, flat_price, flat_total_amount)
+    } else {
+      buy_with_royalties(impact_percent)?
+    };
+    base_royalties = royalties;
+    price = buy_price;
+    total_amount = amount;
+
+    target_royalties = get_percent(total_amount, target_royalties_percent)?;
+
+    let target_amount_minus_royalties = total_amount.checked_sub(target_royalties).unwrap();
+    if target_amount_minus_royalties < buy_with_base.minimum_target_amount {
+      msg!(
+        "{} less than minimum tokens {}",
+        target_amount_minus_royalties,
+        buy_with_base.minimum_target_amount
+      );
+      return Err(error!(ErrorCode::PriceTooHigh));
+    }
+  }
+
+  if token_bonding.mint_cap.is_some()
+    && state.target_supply.checked_add(total_amount).unwrap() > token_bonding.mint_cap.unwrap()
+  {
+    msg!(
+      "Mint cap is {} {} {}",
+      token_bonding.mint_cap.unwrap(),
+      state.target_supply,
+      total_amount
+    );
+    return Err(error!(ErrorCode::PassedMintCap));
+  }
+
+  if token_bonding.purchase_cap.is_some() && total_amount > token_bonding.purchase_cap.unwrap() {
+    return Err(error!(ErrorCode::OverPurchaseCap));
+  }
+
+  token_bonding.supply_from_bonding = token_bonding
+    .supply_from_bonding
+    .checked_add(total_amount)
+    .or_arith_error()?;
+
+  token_bonding.reserve_balance_from_bonding = token_bonding
+    .reserve_balance_from_bonding
+    .checked_add(price)
+    .or_arith_error()?;
+  if let Some(trade_price) = trade_price(price, total_amount, state.target_decimals) {
+    accumulate_price(
+      token_bonding
+        .price_accumulator
+        .get_or_insert_with(Default::default),
+      trade_price,
+      state.unix_timestamp,
+    );
+  }
+
+  Ok(BuyAmount {
+    price,
+    base_royalties,
+    target_royalties,
+    total_amount,
+  })
+}
+
+/// Price a sell and update the token bonding's balances from bonding, as the sell instructions do
+pub fn sell_amounts(
+  token_bonding: &mut TokenBondingV0,
+  curve: &CurveV0,
+  state: &QuoteState,
+  args: &SellV2Args,
+) -> Result<SellAmount> {
+  // Sells read the reserves before initializing the balances from bonding
+  let (base_amount, target_supply) = reserves_and_supply(token_bonding, state);
+  init_balances_from_bonding(token_bonding, state);
+
+  if token_bonding.go_live_unix_time > state.unix_timestamp {
+    return Err(error!(ErrorCode::NotLiveYet));
+  }
+
+  // Holders can always exit a wound down bonding, even if sells were frozen
+  if token_bonding.wind_down {
+    return redeem_amounts(token_bonding, state, args);
+  }
+
+  if token_bonding.sell_frozen {
+    return Err(error!(ErrorCode::SellDisabled));
+  }
+
+  let base_royalties_percent = token_bonding.sell_base_royalty_percentage;
+  let target_royalties_percent = token_bonding.sell_target_royalty_percentage;
+  let time_offset = state
+    .unix_timestamp
+    .checked_sub(token_bonding.go_live_unix_time)
+    .unwrap();
+
+  let supply = supply_amount(token_bonding, state.target_supply);
+  let (amount, minimum_price, base_royalties_percent) = match &args.sell_for_base {
+    Some(sell_for_base) => {
+      // Reclaim enough that base_amount is left after base royalties, then sell enough that the
+      // amount left after target royalties reclaims it
+      let sell_with_royalties = |royalties_percent: u32| -> Result<u64> {
+        let reclaimed_with_royalties = gross_up(sell_for_base.base_amount, royalties_percent)?;
+        let amount_minus_royalties_prec = solve_sell_amount(
+          &curve.definition,
+          time_offset,
+          &base_amount,
+          &target_supply,
+          &precise_decimals_amt(reclaimed_with_royalties, state.base_decimals),
+        )
+        .or_arith_error()?;
+        gross_up(
+          to_decimals_amount(&amount_minus_royalties_prec, state.target_decimals, true),
+          target_royalties_percent,
+        )
+      };
+
+      // Price impact is measured on what the flat royalty would have sold
+      let flat_amount = sell_with_royalties(base_royalties_percent)?;
+      let impact_percent = impact_royalty_percentage(
+        &token_bonding.price_impact_fees,
+        base_royalties_percent,
+        flat_amount,
+        supply,
+      );
+      let amount = if impact_percent == base_royalties_percent {
+        flat_amount
+      } else {
+        sell_with_royalties(impact_percent)?
+      };
+
+      if amount > sell_for_base.maximum_target_amount {
+        msg!(
+          "Selling for {} requires {}, more than maximum {}",
+          sell_for_base.base_amount,
+          amount,
+          sell_for_base.maximum_target_amount
+        );
+        return Err(error!(ErrorCode::SellAmountTooHigh));
+      }
+
+      (amount, sell_for_base.base_amount, impact_percent)
+    }
+    None => (
+      args.target_amount,
+      args.minimum_price,
+      impact_royalty_percentage(
+        &token_bonding.price_impact_fees,
+        base_royalties_percent,
+        args.target_amount,
+        supply,
+      ),
+    ),
+  };
+
+  let target_royalties = get_percent(amount, target_royalties_percent)?;
+  let amount_minus_royalties_prec = precise_decimals_amt(
+    amount.checked_sub(target_royalties).or_arith_error()?,
+    state.target_decimals,
+  );
+  let reclaimed_prec = curve
+    .definition
+    .price(
+      time_offset,
+      &base_amount,
+      &target_supply,
+      &amount_minus_royalties_prec,
+      true,
+    )
+    .or_arith_error()?;
+  let mut reclaimed_with_royalties =
+    to_decimals_amount(&reclaimed_prec, state.base_decimals, false);
+
+  // When the curve prices from more reserves than base storage holds, storage may not cover everyone
+  // selling back. Split what is there pro-rata so early sellers cannot leave later ones with nothing
+  if token_bonding.ignore_external_reserve_changes {
+    let required = required_reserves(
+      curve,
+      state,
+      time_offset,
+      &base_amount,
+      &target_supply,
+      supply,
+    )?;
+    if state.base_storage_amount < required {
+      // Shared with the same supply the curve prices from, not everything on the mint
+      let pro_rata = (state.base_storage_amount as u128
+        * amount.checked_sub(target_royalties).or_arith_error()? as u128)
+        .checked_div(supply as u128)
+        .and_then(|pro_rata| u64::try_from(pro_rata).ok())
+        .or_arith_error()?;
+      reclaimed_with_royalties = reclaimed_with_royalties.min(pro_rata);
+    }
+  }
+
+  let base_royalties = get_percent(reclaimed_with_royalties, base_royalties_percent)?;
+  let mut reclaimed = reclaimed_with_royalties
+    .checked_sub(base_royalties)
+    .or_arith_error()?;
+
+  if reclaimed < minimum_price {
+    msg!(
+      "Err: Minimum price was {}, reclaimed was {}",
+      minimum_price,
+      reclaimed
+    );
+    return Err(error!(ErrorCode::PriceTooLow));
+  }
+
+  // Pay out exactly what was asked for. Anything over from rounding up the amount sold stays in the reserves
+  if args.sell_for_base.is_some() {
+    reclaimed = minimum_price;
+  }
+
+  token_bonding.supply_from_bonding = token_bonding
+    .supply_from_bonding
+    .checked_sub(amount)
+    .or_arith_error()?;
+  token_bonding.reserve_balance_from_bonding = token_bonding
+    .reserve_balance_from_bonding
+    .checked_sub(reclaimed)
+    .or_arith_error()?;
+  if let Some(trade_price) = trade_price(
+    reclaimed_with_royalties,
+    amount.saturating_sub(target_royalties),
+    state.target_decimals,
+  ) {
+    accumulate_price(
+      token_bonding
+        .price_accumulator
+        .get_or_insert_with(Default::default),
+      trade_price,
+      state.unix_timestamp,
+    );
+  }
+
+  Ok(SellAmount {
+    total_amount: amount,
+    reclaimed,
+    base_royalties,
+    target_royalties,
+  })
+}
+
+/// Redeem for a pro-rata share of what is actually in base storage. The curve, royalties and
+/// balances from bonding no longer apply
+fn redeem_amounts(
+  token_bonding: &mut TokenBondingV0,
+  state: &QuoteState,
+  args: &SellV2Args,
+) -> Result<SellAmount> {
+  // Other bondings on the same mint have their own reserves, so only this bonding's supply
+  // shares in its base storage
+  let reserves = state.base_storage_amount as u128;
+  let supply = supply_amount(token_bonding, state.target_supply) as u128;
+  if supply == 0 {
+    return Err(error!(ErrorCode::ArithmeticError));
+  }
+
+  let (amount, reclaimed) = match &args.sell_for_base {
+    Some(sell_for_base) => {
+      if reserves == 0 || sell_for_base.base_amount as u128 > reserves {
+        return Err(error!(ErrorCode::ArithmeticError));
+      }
+      // Round the amount burned up so redemptions never take more than their share
+      let amount =
+        u64::try_from((sell_for_base.base_amount as u128 * supply + reserves - 1) / reserves)
+          .ok()
+          .or_arith_error()?;
+      if amount > sell_for_base.maximum_target_amount {
+        msg!(
+          "Redeeming {} requires {}, more than maximum {}",
+          sell_for_base.base_amount,
+          amount,
+          sell_for_base.maximum_target_amount
+        );
+        return Err(error!(ErrorCode::SellAmountTooHigh));
+      }
+      (amount, sell_for_base.base_amount)
+    }
+    None => {
+      if args.target_amount as u128 > supply {
+        return Err(error!(ErrorCode::MintSupplyTooLow));
+      }
+      let reclaimed = u64::try_from(args.target_amount as u128 * reserves / supply)
+        .ok()
+        .or_arith_error()?;
+      if reclaimed < args.minimum_price {
+        msg!(
+          "Err: Minimum price was {}, reclaimed was {}",
+          args.minimum_price,
+          reclaimed
+        );
+        return Err(error!(ErrorCode::PriceTooLow));
+      }
+      (args.target_amount, reclaimed)
+    }
+  };
+
+  token_bonding.supply_from_bonding = token_bonding.supply_from_bonding.saturating_sub(amount);
+  token_bonding.reserve_balance_from_bonding = token_bonding
+    .reserve_balance_from_bonding
+    .saturating_sub(reclaimed);
+  if let Some(trade_price) = trade_price(reclaimed, amount, state.target_decimals) {
+    accumulate_price(
+      token_bonding
+        .price_accumulator
+        .get_or_insert_with(Default::default),
+      trade_price,
+      state.unix_timestamp,
+    );
+  }
+
+  Ok(SellAmount {
+    total_amount: amount,
+    reclaimed,
+    base_royalties: 0,
+    target_royalties: 0,
+  })
+}
+
+// Smallest amount that leaves at least `net` once get_percent(amount, percent) is taken out of it
+fn gross_up(net: u64, percent: u32) -> Result<u64> {
+  let max = u32::MAX as u128;
+  let remaining = max.checked_sub(percent as u128).or_arith_error()?;
+  let mut gross = u64::try_from(
+    (net as u128)
+      .checked_mul(max)
+      .or_arith_error()?
+      .checked_add(remaining.checked_sub(1).or_arith_error()?)
+      .or_arith_error()?
+      .checked_div(remaining)
+      .or_arith_error()?,
+  )
+  .ok()
+  .or_arith_error()?;
+
+  // get_percent rounds down, which can leave room to take one less
+  while gross > net && gross - 1 - get_percent(gross - 1, percent)? >= net {
+    gross -= 1;
+  }
+
+  Ok(gross)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    instructions::{
+      buy::{BuyTargetAmountV0Args, BuyWithBaseV0Args},
+      sell::SellForBaseV0Args,
+    },
+    state::{PiecewiseCurve, PrimitiveCurve, ReserveStakeV0, TimeCurveV0},
+  };
+
+  const GO_LIVE: i64 = 1650000000;
+  const FIVE_PERCENT: u32 = u32::MAX / 20;
+  const TEN_PERCENT: u32 = u32::MAX / 10;
+
+  fn curve(curve: PrimitiveCurve) -> CurveV0 {
+    CurveV0 {
+      definition: PiecewiseCurve::TimeV0 {
+        curves: vec![TimeCurveV0 {
+          offset: 0,
+          curve,
+          buy_transition_fees: None,
+          sell_transition_fees: None,
+        }],
+      },
+    }
+  }
+
+  // Fixed price of 2
+  fn fixed_price() -> CurveV0 {
+    curve(PrimitiveCurve::ExponentialCurveV0 {
+      c: 0,
+      b: 2_000_000_000_000,
+      pow: 0,
+      frac: 1,
+    })
+  }
+
+  fn constant_product() -> CurveV0 {
+    curve(PrimitiveCurve::ExponentialCurveV0 {
+      c: 1_000_000_000_000,
+      b: 0,
+      pow: 1,
+      frac: 1,
+    })
+  }
+
+  // Balances from bonding match the on chain reserves and supply, as they do for any bonding created since they
+  // were introduced
+  fn token_bonding(reserve_balance_from_bonding: u64, supply_from_bonding: u64) -> TokenBondingV0 {
+    TokenBondingV0 {
+      go_live_unix_time: GO_LIVE,
+      buy_base_royalty_percentage: FIVE_PERCENT,
+      buy_target_royalty_percentage: TEN_PERCENT,
+      sell_base_royalty_percentage: FIVE_PERCENT,
+      sell_target_royalty_percentage: TEN_PERCENT,
+      reserve_balance_from_bonding,
+      supply_from_bonding,
+      ..TokenBondingV0::default()
+    }
+  }
+
+  // Base has 9 decimals, target has 6
+  fn state(base_storage_amount: u64, target_supply: u64) -> QuoteState {
+    QuoteState {
+      base_decimals: 9,
+      target_decimals: 6,
+      base_storage_amount,
+      target_supply,
+      unix_timestamp: GO_LIVE + 100,
+    }
+  }
+
+  fn buy_target(target_amount: u64) -> BuyV1Args {
+    BuyV1Args {
+      buy_target_amount: Some(BuyTargetAmountV0Args {
+        target_amount,
+        maximum_price: u64::MAX,
+      }),
+      buy_with_base: None,
+      allowlist_proof: None,
+    }
+  }
+
+  fn buy_base(base_amount: u64) -> BuyV1Args {
+    BuyV1Args {
+      buy_target_amount: None,
+      buy_with_base: Some(BuyWithBaseV0Args {
+        base_amount,
+        minimum_target_amount: 0,
+      }),
+      allowlist_proof: None,
+    }
+  }
+
+  fn sell(target_amount: u64) -> SellV2Args {
+    SellV2Args {
+      target_amount,
+      minimum_price: 0,
+      sell_for_base: None,
+    }
+  }
+
+  fn sell_for_base(base_amount: u64, maximum_target_amount: u64) -> SellV2Args {
+    SellV2Args {
+      target_amount: 0,
+      minimum_price: 0,
+      sell_for_base: Some(SellForBaseV0Args {
+        base_amount,
+        maximum_target_amount,
+      }),
+    }
+  }
+
+  // Sells exactly enough to receive base_amount, and no less would do
+  fn assert_sell_for_base(
+    token_bonding: &TokenBondingV0,
+    curve: &CurveV0,
+    state: &QuoteState,
+    base_amount: u64,
+  ) -> u64 {
+    let amount = quote_sell(
+      token_bonding,
+      curve,
+      state,
+      &sell_for_base(base_amount, u64::MAX),
+    )
+    .unwrap();
+    assert_eq!(amount.reclaimed, base_amount);
+
+    let exact = quote_sell(token_bonding, curve, state, &sell(amount.total_amount)).unwrap();
+    assert!(exact.reclaimed >= base_amount);
+    assert_eq!(exact.base_royalties, amount.base_royalties);
+    assert_eq!(exact.target_royalties, amount.target_royalties);
+    let less = quote_sell(token_bonding, curve, state, &sell(amount.total_amount - 1)).unwrap();
+    assert!(less.reclaimed < base_amount);
+
+    amount.total_amount
+  }
+
+  fn assert_buy(
+    amount: BuyAmount,
+    price: u64,
+    total_amount: u64,
+    base_royalties: u64,
+    target_royalties: u64,
+  ) {
+    assert_eq!(amount.price, price);
+    assert_eq!(amount.total_amount, total_amount);
+    assert_eq!(amount.base_royalties, base_royalties);
+    assert_eq!(amount.target_royalties, target_royalties);
+  }
+
+  fn assert_sell(amount: SellAmount, reclaimed: u64, base_royalties: u64, target_royalties: u64) {
+    assert_eq!(amount.reclaimed, reclaimed);
+    assert_eq!(amount.base_royalties, base_royalties);
+    assert_eq!(amount.target_royalties, target_royalties);
+  }
+
+  // Golden values are what buy_shared_logic and sell_shared_logic produce for the same accounts
+  #[test]
+  fn test_quote_fixed_price() {
+    let token_bonding = token_bonding(0, 0);
+    let state = state(0, 0);
+
+    // 10 tokens at 2, plus 5% base royalties. 10% of the tokens go to target royalties
+    let amount = quote_buy(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &buy_target(10_000_000),
+    )
+    .unwrap();
+    assert_buy(amount, 20_000_000_000, 10_000_000, 999_999_996, 999_999);
+
+    // 5% of 21 goes to royalties, the rest buys at 2
+    let amount = quote_buy(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &buy_base(21_000_000_000),
+    )
+    .unwrap();
+    assert_buy(amount, 19_950_000_004, 9_975_000, 1_049_999_996, 997_499);
+
+    // 10% of the tokens go to target royalties, the rest sell at 2 less 5% base royalties
+    let token_bonding = self::token_bonding(20_000_000_000, 10_000_000);
+    let state = self::state(20_000_000_000, 10_000_000);
+    let amount = quote_sell(&token_bonding, &fixed_price(), &state, &sell(5_000_000)).unwrap();
+    assert_sell(amount, 8_550_001_902, 450_000_098, 499_999);
+  }
+
+  #[test]
+  fn test_quote_constant_product() {
+    let token_bonding = token_bonding(100_000_000_000, 10_000_000);
+    let state = state(100_000_000_000, 10_000_000);
+
+    // Doubling the supply quadruples the reserves
+    let amount = quote_buy(
+      &token_bonding,
+      &constant_product(),
+      &state,
+      &buy_target(10_000_000),
+    )
+    .unwrap();
+    assert_buy(amount, 300_000_000_000, 10_000_000, 14_999_999_947, 999_999);
+
+    let amount = quote_buy(
+      &token_bonding,
+      &constant_product(),
+      &state,
+      &buy_base(300_000_000_000),
+    )
+    .unwrap();
+    assert_buy(amount, 285_000_000_053, 9_621_416, 14_999_999_947, 962_141);
+
+    let token_bonding = self::token_bonding(400_000_000_000, 20_000_000);
+    let state = self::state(400_000_000_000, 20_000_000);
+    let amount = quote_sell(
+      &token_bonding,
+      &constant_product(),
+      &state,
+      &sell(10_000_000),
+    )
+    .unwrap();
+    assert_sell(amount, 265_050_020_948, 13_950_001_051, 999_999);
+  }
+
+  #[test]
+  fn test_quote_does_not_modify_bonding() {
+    let token_bonding = token_bonding(100_000_000_000, 10_000_000);
+    let state = state(100_000_000_000, 10_000_000);
+    let quote = quote_buy(
+      &token_bonding,
+      &constant_product(),
+      &state,
+      &buy_target(10_000_000),
+    )
+    .unwrap();
+    assert_eq!(token_bonding.reserve_balance_from_bonding, 100_000_000_000);
+    assert_eq!(token_bonding.supply_from_bonding, 10_000_000);
+
+    // The buy instructions price the same and then track the new balances
+    let mut bought = token_bonding.clone();
+    let amount = buy_amounts(
+      &mut bought,
+      &constant_product(),
+      &state,
+      &buy_target(10_000_000),
+    )
+    .unwrap();
+    assert_eq!(amount.price, quote.price);
+    assert_eq!(bought.reserve_balance_from_bonding, 400_000_000_000);
+    assert_eq!(bought.supply_from_bonding, 20_000_000);
+  }
+
+  #[test]
+  fn test_quote_ignores_external_changes() {
+    let token_bonding = TokenBondingV0 {
+      ignore_external_reserve_changes: true,
+      ignore_external_supply_changes: true,
+      ..token_bonding(100_000_000_000, 10_000_000)
+    };
+    // Someone sent extra reserves and minted outside of the curve
+    let state = state(500_000_000_000, 50_000_000);
+
+    let amount = quote_buy(
+      &token_bonding,
+      &constant_product(),
+      &state,
+      &buy_target(10_000_000),
+    )
+    .unwrap();
+    assert_eq!(amount.price, 300_000_000_000);
+  }
+
+  #[test]
+  fn test_quote_errors() {
+    let token_bonding = token_bonding(0, 0);
+    let early = QuoteState {
+      unix_timestamp: GO_LIVE - 1,
+      ..state(0, 0)
+    };
+    let err = quote_buy(&token_bonding, &fixed_price(), &early, &buy_target(1))
+      .err()
+      .unwrap();
+    assert_eq!(err, error!(ErrorCode::NotLiveYet));
+
+    let frozen = TokenBondingV0 {
+      buy_frozen: true,
+      ..token_bonding.clone()
+    };
+    let err = quote_buy(&frozen, &fixed_price(), &state(0, 0), &buy_target(1))
+      .err()
+      .unwrap();
+    assert_eq!(err, error!(ErrorCode::BuyFrozen));
+
+    let mut args = buy_target(10_000_000);
+    args.buy_target_amount.as_mut().unwrap().maximum_price = 20_000_000_000;
+    let err = quote_buy(&token_bonding, &fixed_price(), &state(0, 0), &args)
+      .err()
+      .unwrap();
+    assert_eq!(err, error!(ErrorCode::PriceTooHigh));
+
+    let capped = TokenBondingV0 {
+      mint_cap: Some(5_000_000),
+      ..token_bonding.clone()
+    };
+    let err = quote_buy(
+      &capped,
+      &fixed_price(),
+      &state(0, 0),
+      &buy_target(10_000_000),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err, error!(ErrorCode::PassedMintCap));
+
+    let sell_frozen = TokenBondingV0 {
+      sell_frozen: true,
+      ..self::token_bonding(20_000_000_000, 10_000_000)
+    };
+    let err = quote_sell(
+      &sell_frozen,
+      &fixed_price(),
+      &state(20_000_000_000, 10_000_000),
+      &sell(1),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err, error!(ErrorCode::SellDisabled));
+  }
+
+  #[test]
+  fn test_sell_for_base() {
+    let token_bonding = token_bonding(20_000_000_000, 10_000_000);
+    let state = state(20_000_000_000, 10_000_000);
+    assert_sell_for_base(&token_bonding, &fixed_price(), &state, 5_000_000_000);
+    assert_sell_for_base(&token_bonding, &fixed_price(), &state, 1);
+
+    let token_bonding = self::token_bonding(400_000_000_000, 20_000_000);
+    let state = self::state(400_000_000_000, 20_000_000);
+    let amount = assert_sell_for_base(&token_bonding, &constant_product(), &state, 265_050_020_948);
+    // Same sell as the golden constant product sell, up to rounding
+    assert!((9_999_999..=10_000_000).contains(&amount));
+    assert_sell_for_base(&token_bonding, &constant_product(), &state, 123_456_789_012);
+  }
+
+  #[test]
+  fn test_sell_for_base_errors() {
+    let token_bonding = token_bonding(20_000_000_000, 10_000_000);
+    let state = state(20_000_000_000, 10_000_000);
+
+    let amount = quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(5_000_000_000, u64::MAX),
+    )
+    .unwrap()
+    .total_amount;
+    let err = quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(5_000_000_000, amount - 1),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err, error!(ErrorCode::SellAmountTooHigh));
+
+    // More than the reserves hold
+    let err = quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(20_000_000_000, u64::MAX),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err, error!(ErrorCode::ArithmeticError));
+  }
+
+  fn with_impact_fees(token_bonding: TokenBondingV0) -> TokenBondingV0 {
+    TokenBondingV0 {
+      price_impact_fees: Some(PriceImpactFeesV0 {
+        min_impact_percentage: u32::MAX / 100,
+        max_impact_percentage: u32::MAX / 2,
+        max_royalty_percentage: u32::MAX / 4,
+      }),
+      ..token_bonding
+    }
+  }
+
+  #[test]
+  fn test_wind_down_redeems_pro_rata() {
+    let token_bonding = TokenBondingV0 {
+      wind_down: true,
+      sell_frozen: true,
+      ..token_bonding(1_000_000_000, 3_000_000)
+    };
+    // Reserves were drained below what the curve expects, so holders split what is left
+    let state = state(900_000_000, 3_000_000);
+    assert_eq!(
+      quote_buy(&token_bonding, &fixed_price(), &state, &buy_target(1)).err(),
+      Some(error!(ErrorCode::BuyFrozen))
+    );
+
+    let sold = quote_sell(&token_bonding, &fixed_price(), &state, &sell(1_000_000)).unwrap();
+    assert_eq!(sold.reclaimed, 300_000_000);
+    assert_eq!(sold.total_amount, 1_000_000);
+    assert_eq!(sold.base_royalties, 0);
+    assert_eq!(sold.target_royalties, 0);
+
+    // Rounding favours the remaining holders, and the last holder takes everything
+    let sold = quote_sell(&token_bonding, &fixed_price(), &state, &sell(1)).unwrap();
+    assert_eq!(sold.reclaimed, 300);
+    let sold = quote_sell(&token_bonding, &fixed_price(), &state, &sell(3_000_000)).unwrap();
+    assert_eq!(sold.reclaimed, 900_000_000);
+
+    let sold = quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(300_000_001, u64::MAX),
+    )
+    .unwrap();
+    assert_eq!(sold.total_amount, 1_000_001);
+    assert_eq!(sold.reclaimed, 300_000_001);
+    assert!(quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(300_000_001, 1_000_000)
+    )
+    .is_err());
+    assert!(quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(900_000_001, u64::MAX)
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn test_wind_down_redeems_from_own_supply() {
+    // A marketplace bonding on a mint that another bonding issued most of
+    let token_bonding = TokenBondingV0 {
+      index: 1,
+      wind_down: true,
+      ignore_external_supply_changes: true,
+      ..token_bonding(1_000_000_000, 1_000_000)
+    };
+    let state = state(900_000_000, 3_000_000);
+
+    let sold = quote_sell(&token_bonding, &fixed_price(), &state, &sell(500_000)).unwrap();
+    assert_eq!(sold.reclaimed, 450_000_000);
+    let sold = quote_sell(&token_bonding, &fixed_price(), &state, &sell(1_000_000)).unwrap();
+    assert_eq!(sold.reclaimed, 900_000_000);
+    let sold = quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(450_000_000, u64::MAX),
+    )
+    .unwrap();
+    assert_eq!(sold.total_amount, 500_000);
+
+    // Tokens the other bonding issued cannot redeem here
+    assert_eq!(
+      quote_sell(&token_bonding, &fixed_price(), &state, &sell(1_000_001)).err(),
+      Some(error!(ErrorCode::MintSupplyTooLow))
+    );
+
+    let spot_price = quote_spot_price(&token_bonding, &fixed_price(), &state).unwrap();
+    // 900 base per whole target, not the 300 the mint supply would give
+    assert_eq!(spot_price.sell_price, 900_000_000);
+  }
+
+  fn ignoring_external_changes(token_bonding: TokenBondingV0) -> TokenBondingV0 {
+    TokenBondingV0 {
+      ignore_external_reserve_changes: true,
+      ignore_external_supply_changes: true,
+      ..token_bonding
+    }
+  }
+
+  #[test]
+  fn test_solvency() {
+    let healthy = solvency(
+      &token_bonding(20_000_000_000, 10_000_000),
+      &fixed_price(),
+      &state(20_000_000_000, 10_000_000),
+      20_000_000_000,
+    )
+    .unwrap();
+    assert!(healthy.is_solvent());
+    assert_eq!(healthy.required_reserves, 20_000_000_000);
+    assert_eq!(healthy.solvency_ratio_bps, 10_000);
+
+    // A native bonding that has staked half its own base storage
+    let staked_bonding = TokenBondingV0 {
+      reserve_stake: Some(ReserveStakeV0 {
+        pool_tokens: 9_000_000_000,
+        staked_lamports: 10_000_000_000,
+        ..Default::default()
+      }),
+      ..token_bonding(20_000_000_000, 10_000_000)
+    };
+    assert_eq!(
+      liquid_reserves(&staked_bonding, 20_000_000_000),
+      10_000_000_000
+    );
+    let staked = solvency(
+      &staked_bonding,
+      &fixed_price(),
+      &state(20_000_000_000, 10_000_000),
+      liquid_reserves(&staked_bonding, 20_000_000_000),
+    )
+    .unwrap();
+    assert!(!staked.is_solvent());
+    assert_eq!(staked.required_reserves, 20_000_000_000);
+    assert_eq!(staked.solvency_ratio_bps, 5_000);
+
+    // Half the reserves were transferred out, but the curve still prices from all of them
+    let drained = solvency(
+      &ignoring_external_changes(token_bonding(20_000_000_000, 10_000_000)),
+      &fixed_price(),
+      &state(10_000_000_000, 10_000_000),
+      10_000_000_000,
+    )
+    .unwrap();
+    assert!(!drained.is_solvent());
+    assert_eq!(drained.required_reserves, 20_000_000_000);
+    assert_eq!(drained.solvency_ratio_bps, 5_000);
+
+    // Padded supply is never sold back, so only what buyers paid in is required
+    let padded = solvency(
+      &ignoring_external_changes(token_bonding(3_000_000_000, 1_500_000)),
+      &fixed_price(),
+      &state(2_000_000_000, 1_000_000),
+      2_000_000_000,
+    )
+    .unwrap();
+    assert!(padded.is_solvent());
+    assert_eq!(padded.required_reserves, 2_000_000_000);
+
+    let empty = solvency(&token_bonding(0, 0), &fixed_price(), &state(0, 0), 0).unwrap();
+    assert!(empty.is_solvent());
+    assert_eq!(empty.solvency_ratio_bps, u64::MAX);
+  }
+
+  #[test]
+  fn test_insolvent_sells_are_pro_rata() {
+    let token_bonding = ignoring_external_changes(token_bonding(20_000_000_000, 10_000_000));
+
+    // Storage covers the curve, so sells are priced as usual
+    let state = state(20_000_000_000, 10_000_000);
+    let amount = quote_sell(&token_bonding, &fixed_price(), &state, &sell(5_000_000)).unwrap();
+    assert_sell(amount, 8_550_001_902, 450_000_098, 499_999);
+
+    // With half the reserves gone, the 4,500,001 tokens burned take their share of what is left
+    let state = self::state(10_000_000_000, 10_000_000);
+    let amount = quote_sell(&token_bonding, &fixed_price(), &state, &sell(5_000_000)).unwrap();
+    assert_eq!(amount.target_royalties, 499_999);
+    assert_eq!(amount.reclaimed + amount.base_royalties, 4_500_001_000);
+
+    // Selling for more than the pro-rata share fails
+    let err = quote_sell(
+      &token_bonding,
+      &fixed_price(),
+      &state,
+      &sell_for_base(8_000_000_000, u64::MAX),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err, error!(ErrorCode::PriceTooLow));
+
+    // Another bonding issued most of the mint, only this bonding's supply shares its storage
+    let state = self::state(10_000_000_000, 30_000_000);
+    let amount = quote_sell(&token_bonding, &fixed_price(), &state, &sell(5_000_000)).unwrap();
+    assert_eq!(amount.reclaimed + amount.base_royalties, 4_500_001_000);
+  }
+
+  #[test]
+  fn test_impact_royalty_percentage() {
+    let fees = with_impact_fees(TokenBondingV0::default()).price_impact_fees;
+
+    assert_eq!(
+      impact_royalty_percentage(&None, FIVE_PERCENT, 50, 100),
+      FIVE_PERCENT
+    );
+    // Up to 1% of supply
+    assert_eq!(
+      impact_royalty_percentage(&fees, FIVE_PERCENT, 1, 100),
+      FIVE_PERCENT
+    );
+    // Halfway between 1% and 50% of supply, so halfway between 5% and 25%
+    let halfway = impact_royalty_percentage(&fees, FIVE_PERCENT, 255, 1000);
+    assert!((halfway as i64 - (u32::MAX / 100 * 15) as i64).abs() < 1000);
+    // 50% of supply or more
+    assert_eq!(
+      impact_royalty_percentage(&fees, FIVE_PERCENT, 50, 100),
+      u32::MAX / 4
+    );
+    assert_eq!(
+      impact_royalty_percentage(&fees, FIVE_PERCENT, 1, 0),
+      u32::MAX / 4
+    );
+    // Never lowers a flat royalty above the max
+    assert_eq!(
+      impact_royalty_percentage(&fees, u32::MAX / 2, 50, 100),
+      u32::MAX / 2
+    );
+  }
+
+  #[test]
+  fn test_price_impact_fees() {
+    let flat = token_bonding(20_000_000_000, 10_000_000);
+    let dynamic = with_impact_fees(flat.clone());
+    let state = state(20_000_000_000, 10_000_000);
+
+    // Small trades pay the flat royalty
+    let small_sell = quote_sell(&dynamic, &fixed_price(), &state, &sell(50_000)).unwrap();
+    let flat_small_sell = quote_sell(&flat, &fixed_price(), &state, &sell(50_000)).unwrap();
+    assert_eq!(small_sell.base_royalties, flat_small_sell.base_royalties);
+    let small_buy = quote_buy(&dynamic, &fixed_price(), &state, &buy_base(100_000_000)).unwrap();
+    let flat_small_buy = quote_buy(&flat, &fixed_price(), &state, &buy_base(100_000_000)).unwrap();
+    assert_eq!(small_buy.total_amount, flat_small_buy.total_amount);
+
+    // Selling half the supply pays the max royalty
+    let big_sell = quote_sell(&dynamic, &fixed_price(), &state, &sell(5_000_000)).unwrap();
+    assert_eq!(
+      big_sell.base_royalties,
+      get_percent(big_sell.reclaimed + big_sell.base_royalties, u32::MAX / 4).unwrap()
+    );
+
+    // Buying as much as the supply again is over the max impact
+    let big_buy = quote_buy(&dynamic, &fixed_price(), &state, &buy_target(10_000_000)).unwrap();
+    assert_eq!(
+      big_buy.base_royalties,
+      get_percent(big_buy.price, u32::MAX / 4).unwrap()
+    );
+    let big_buy = quote_buy(&dynamic, &fixed_price(), &state, &buy_base(40_000_000_000)).unwrap();
+    assert_eq!(
+      big_buy.base_royalties,
+      get_percent(40_000_000_000, u32::MAX / 4).unwrap()
+    );
+
+    // Selling for base still pays out exactly, selling more to cover the higher royalty
+    let amount = quote_sell(
+      &dynamic,
+      &fixed_price(),
+      &state,
+      &sell_for_base(9_000_000_000, u64::MAX),
+    )
+    .unwrap();
+    let flat_amount = quote_sell(
+      &flat,
+      &fixed_price(),
+      &state,
+      &sell_for_base(9_000_000_000, u64::MAX),
+    )
+    .unwrap();
+    assert_eq!(amount.reclaimed, 9_000_000_000);
+    assert!(amount.total_amount > flat_amount.total_amount);
+  }
+}
@@ -0,0 +1,237 @@
+//! Just enough of the SPL stake pool program to park idle native reserves in a pool and take them
+//! back out. Instructions are built by hand so the program does not pin a stake pool crate release
+//! (and its solana version) alongside anchor's.
+
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    instruction::{AccountMeta, Instruction},
+    stake, sysvar,
+  },
+};
+use std::convert::TryFrom;
+
+use crate::{error::ErrorCode, util::get_percent};
+
+// StakePool::account_type once a pool is initialized
+const ACCOUNT_TYPE_STAKE_POOL: u8 = 1;
+// Byte offsets into the borsh encoded StakePool account
+const POOL_MINT_OFFSET: usize = 162;
+const TOTAL_LAMPORTS_OFFSET: usize = 258;
+const POOL_TOKEN_SUPPLY_OFFSET: usize = 266;
+const LAST_UPDATE_EPOCH_OFFSET: usize = 274;
+
+const DEPOSIT_SOL_INSTRUCTION: u8 = 14;
+const WITHDRAW_SOL_INSTRUCTION: u8 = 16;
+
+/// What a pool token is worth, as of the pool's last update
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StakePoolTotals {
+  pub pool_mint: Pubkey,
+  pub total_lamports: u64,
+  pub pool_token_supply: u64,
+  pub last_update_epoch: u64,
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+  Some(u64::from_le_bytes(
+    <[u8; 8]>::try_from(data.get(offset..offset + 8)?).ok()?,
+  ))
+}
+
+pub fn stake_pool_totals(
+  stake_pool: &AccountInfo,
+  stake_pool_program: &Pubkey,
+) -> Result<StakePoolTotals> {
+  if stake_pool.owner != stake_pool_program {
+    return Err(error!(ErrorCode::InvalidStakePool));
+  }
+
+  let data = stake_pool.try_borrow_data()?;
+  let parse = || -> Option<StakePoolTotals> {
+    if *data.first()? != ACCOUNT_TYPE_STAKE_POOL {
+      return None;
+    }
+
+    Some(StakePoolTotals {
+      pool_mint: Pubkey::new_from_array(
+        <[u8; 32]>::try_from(data.get(POOL_MINT_OFFSET..POOL_MINT_OFFSET + 32)?).ok()?,
+      ),
+      total_lamports: read_u64(&data, TOTAL_LAMPORTS_OFFSET)?,
+      pool_token_supply: read_u64(&data, POOL_TOKEN_SUPPLY_OFFSET)?,
+      last_update_epoch: read_u64(&data, LAST_UPDATE_EPOCH_OFFSET)?,
+    })
+  };
+
+  parse().ok_or_else(|| error!(ErrorCode::InvalidStakePool))
+}
+
+/// Lamports `pool_tokens` can be withdrawn for, before withdrawal fees
+pub fn pool_tokens_value(pool_tokens: u64, totals: &StakePoolTotals) -> Option<u64> {
+  if totals.pool_token_supply == 0 {
+    return Some(0);
+  }
+
+  u64::try_from(
+    (pool_tokens as u128)
+      .checked_mul(totals.total_lamports as u128)?
+      .checked_div(totals.pool_token_supply as u128)?,
+  )
+  .ok()
+}
+
+/// Pool tokens to burn to withdraw at most `lamports`
+pub fn pool_tokens_for_lamports(lamports: u64, totals: &StakePoolTotals) -> Option<u64> {
+  u64::try_from(
+    (lamports as u128)
+      .checked_mul(totals.pool_token_supply as u128)?
+      .checked_div(totals.total_lamports as u128)?,
+  )
+  .ok()
+}
+
+/// Pool tokens to burn to withdraw at least `lamports`, before withdrawal fees
+pub fn pool_tokens_for_at_least(lamports: u64, totals: &StakePoolTotals) -> Option<u64> {
+  let total_lamports = totals.total_lamports as u128;
+  u64::try_from(
+    (lamports as u128)
+      .checked_mul(totals.pool_token_supply as u128)?
+      .checked_add(total_lamports.checked_sub(1)?)?
+      .checked_div(total_lamports)?,
+  )
+  .ok()
+}
+
+/// Lamports of a bonding's base storage that should sit in the stake pool. The rest stay in sol
+/// storage as a buffer for sells
+pub fn staking_target(base_storage_amount: u64, max_staked_percentage: u32) -> Result<u64> {
+  get_percent(base_storage_amount, max_staked_percentage)
+}
+
+/// Accounts of the stake pool itself, as the stake pool program expects them
+pub struct StakePoolInstructionAccounts {
+  pub stake_pool_program: Pubkey,
+  pub stake_pool: Pubkey,
+  pub withdraw_authority: Pubkey,
+  pub reserve_stake: Pubkey,
+  pub manager_fee_account: Pubkey,
+  pub pool_mint: Pubkey,
+  pub token_program: Pubkey,
+}
+
+fn instruction_data(instruction: u8, amount: u64) -> Vec<u8> {
+  let mut data = vec![instruction];
+  data.extend_from_slice(&amount.to_le_bytes());
+  data
+}
+
+/// Deposit `lamports` from `funding` into the pool's reserve, minting pool tokens to `pool_tokens`.
+/// Any referral fee also goes back to `pool_tokens`
+pub fn deposit_sol(
+  accounts: &StakePoolInstructionAccounts,
+  funding: &Pubkey,
+  pool_tokens: &Pubkey,
+  lamports: u64,
+) -> Instruction {
+  Instruction {
+    program_id: accounts.stake_pool_program,
+    accounts: vec![
+      AccountMeta::new(accounts.stake_pool, false),
+      AccountMeta::new_readonly(accounts.withdraw_authority, false),
+      AccountMeta::new(accounts.reserve_stake, false),
+      AccountMeta::new(*funding, true),
+      AccountMeta::new(*pool_tokens, false),
+      AccountMeta::new(accounts.manager_fee_account, false),
+      AccountMeta::new(*pool_tokens, false),
+      AccountMeta::new(accounts.pool_mint, false),
+      AccountMeta::new_readonly(System::id(), false),
+      AccountMeta::new_readonly(accounts.token_program, false),
+    ],
+    data: instruction_data(DEPOSIT_SOL_INSTRUCTION, lamports),
+  }
+}
+
+/// Burn `pool_tokens_amount` from `pool_tokens`, owned by `authority`, for lamports sent to
+/// `destination`
+pub fn withdraw_sol(
+  accounts: &StakePoolInstructionAccounts,
+  authority: &Pubkey,
+  pool_tokens: &Pubkey,
+  destination: &Pubkey,
+  pool_tokens_amount: u64,
+) -> Instruction {
+  Instruction {
+    program_id: accounts.stake_pool_program,
+    accounts: vec![
+      AccountMeta::new(accounts.stake_pool, false),
+      AccountMeta::new_readonly(accounts.withdraw_authority, false),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*pool_tokens, false),
+      AccountMeta::new(accounts.reserve_stake, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new(accounts.manager_fee_account, false),
+      AccountMeta::new(accounts.pool_mint, false),
+      AccountMeta::new_readonly(sysvar::clock::id(), false),
+      AccountMeta::new_readonly(sysvar::stake_history::id(), false),
+      AccountMeta::new_readonly(stake::program::id(), false),
+      AccountMeta::new_readonly(accounts.token_program, false),
+    ],
+    data: instruction_data(WITHDRAW_SOL_INSTRUCTION, pool_tokens_amount),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn totals(total_lamports: u64, pool_token_supply: u64) -> StakePoolTotals {
+    StakePoolTotals {
+      total_lamports,
+      pool_token_supply,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_pool_token_conversions_round_down() {
+    // Each pool token has earned 5% since the pool started
+    let pool = totals(1_050, 1_000);
+    assert_eq!(pool_tokens_value(100, &pool), Some(105));
+    assert_eq!(pool_tokens_for_lamports(105, &pool), Some(100));
+    assert_eq!(pool_tokens_for_lamports(104, &pool), Some(99));
+    assert_eq!(pool_tokens_value(99, &pool), Some(103));
+
+    let empty = totals(0, 0);
+    assert_eq!(pool_tokens_value(100, &empty), Some(0));
+    assert_eq!(pool_tokens_for_lamports(100, &empty), None);
+  }
+
+  #[test]
+  fn test_pool_tokens_for_at_least_cover_the_lamports() {
+    let pool = totals(1_050, 1_000);
+    assert_eq!(pool_tokens_for_at_least(105, &pool), Some(100));
+    assert_eq!(pool_tokens_for_at_least(104, &pool), Some(100));
+    assert_eq!(pool_tokens_for_at_least(103, &pool), Some(99));
+    for lamports in 0..1_050 {
+      let pool_tokens = pool_tokens_for_at_least(lamports, &pool).unwrap();
+      assert!(pool_tokens_value(pool_tokens, &pool).unwrap() >= lamports);
+    }
+
+    assert_eq!(pool_tokens_for_at_least(100, &totals(0, 0)), None);
+  }
+
+  #[test]
+  fn test_staking_target_keeps_buffer() {
+    assert_eq!(staking_target(1_000, u32::MAX).unwrap(), 1_000);
+    assert_eq!(staking_target(1_000, u32::MAX / 4 * 3).unwrap(), 749);
+    assert_eq!(staking_target(1_000, 0).unwrap(), 0);
+  }
+
+  #[test]
+  fn test_instruction_data() {
+    assert_eq!(
+      instruction_data(DEPOSIT_SOL_INSTRUCTION, 258),
+      vec![14, 2, 1, 0, 0, 0, 0, 0, 0]
+    );
+  }
+}
@@ -41,11 +41,31 @@ pub enum PrimitiveCurve {
     interval: u32,
     d: u128,
   },
+  // b + c ln(1 + g x)
+  // Price keeps rising with supply, but flattens out as supply grows
+  LogarithmicCurveV0 {
+    c: u128,
+    g: u128,
+    b: u128,
+  },
+  // max_price / (1 + e^(-k (x - midpoint)))
+  // Price rises fastest around the midpoint supply, then levels off towards max_price
+  SigmoidCurveV0 {
+    max_price: u128,
+    k: u128,
+    midpoint: u128, // 12 decimal target supply
+  },
+  // Price schedule as (supply, price) points, linearly interpolated between them and flat after the last point
+  PriceTableCurveV0 {
+    points: Vec<PricePointV0>,
+  },
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub enum PiecewiseCurve {
   TimeV0 { curves: Vec<TimeCurveV0> },
+  // Tiered pricing by supply. Each curve prices the tokens from its offset up to the next curve's offset
+  SupplyV0 { curves: Vec<SupplyCurveV0> },
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -56,6 +76,19 @@ pub struct TimeCurveV0 {
   pub sell_transition_fees: Option<TransitionFeeV0>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SupplyCurveV0 {
+  // Target supply this tier starts at. Fixed precision decimal with 12 decimal places, same as curve params
+  pub offset: u128,
+  pub curve: PrimitiveCurve,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PricePointV0 {
+  pub supply: u128, // 12 decimal target supply
+  pub price: u128,
+}
+
 // A fee that slowly decreases over the course of interval. This is used to prevent botting when curves change
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct TransitionFeeV0 {
@@ -92,6 +125,121 @@ impl Default for PiecewiseCurve {
   }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum BuyGateType {
+  Token,
+  Collection,
+}
+
+impl Default for BuyGateType {
+  fn default() -> Self {
+    BuyGateType::Token
+  }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct BuyGateV0 {
+  pub gate_type: BuyGateType,
+  /// The mint to hold for token gates, or the verified Metaplex collection mint for collection gates
+  pub key: Pubkey,
+  pub minimum_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct RoyaltyVestingV0 {
+  /// Token account owned by the token bonding that buy target royalties are minted into
+  pub escrow: Pubkey,
+  /// Where vested royalties are released to
+  pub destination: Pubkey,
+  /// Royalties escrowed before this start vesting at this time instead
+  pub start_unix_time: i64,
+  /// Each deposit releases nothing until this long after it was escrowed
+  pub cliff_secs: i64,
+  /// Each deposit is fully released this long after it was escrowed
+  pub duration_secs: i64,
+  /// Total target royalties minted into the escrow
+  pub total_vesting_amount: u64,
+  /// Escrowed royalties not yet released. Deposits are merged into one schedule, using the
+  /// latest release and end time of any deposit, that releases this linearly from
+  /// release_unix_time to end_unix_time
+  pub unvested_amount: u64,
+  pub release_unix_time: i64,
+  pub end_unix_time: i64,
+  pub claimed_amount: u64,
+  pub escrow_bump_seed: u8,
+}
+
+/// Raises the base royalty on trades that move a large fraction of the supply. Buys are measured
+/// against the supply after the buy, sells against the supply before the sell. All values are
+/// percentages of u32::MAX
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PriceImpactFeesV0 {
+  /// Trades up to this fraction of supply pay only the flat base royalty
+  pub min_impact_percentage: u32,
+  /// Trades of at least this fraction of supply pay max_royalty_percentage
+  pub max_impact_percentage: u32,
+  /// Base royalty at max impact, ramping linearly from the flat base royalty at min impact
+  pub max_royalty_percentage: u32,
+}
+
+/// Protects holders from sudden royalty hikes. Percentages are of u32::MAX
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct RoyaltyChangeLimitsV0 {
+  /// How far each royalty percentage can rise within one period. Decreases are not limited
+  pub max_change_percentage: u32,
+  pub period_secs: i64,
+  /// No royalty percentage can be raised above this
+  pub max_royalty_percentage: u32,
+}
+
+/// Royalty percentages at the start of the current rate limit period, in the order buy base, buy
+/// target, sell base, sell target, then the buy base and sell base royalties paid at full price
+/// impact
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct RoyaltyChangePeriodV0 {
+  pub start_unix_time: i64,
+  pub start_percentages: [u32; 6],
+}
+
+/// A curve change waiting out the bonding's curve update delay. Anyone can apply it with
+/// apply_curve_update_v0 once effective_unix_time has passed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct PendingCurveUpdateV0 {
+  pub curve: Pubkey,
+  pub effective_unix_time: i64,
+}
+
+/// Running integral of the token bonding's price over time, updated by every buy and sell.
+/// Prices are base tokens per whole target token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct PriceAccumulatorV0 {
+  pub last_price: u64,
+  pub last_unix_time: i64,
+  /// Sum of price * seconds it held. Wraps on overflow, so only differences are meaningful
+  pub cumulative_price: u128,
+}
+
+/// A native token bonding's share of reserve staking. Its staked lamports are part of its own
+/// base storage, so one bonding's stake never pays out another bonding's sells
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct ReserveStakeV0 {
+  /// This bonding's pool tokens, held in reserve staking's pool token account
+  pub pool_tokens: u64,
+  /// Lamports of base storage that are in the stake pool instead of sol storage. Never more
+  /// than base storage, since losses are burned from both. Anything the pool tokens are worth
+  /// above this is yield
+  pub staked_lamports: u64,
+  /// Yield claimed or added to reserves so far
+  pub total_yield_lamports: u64,
+  /// Slashing and stake pool fees burned from base storage so far
+  pub total_loss_lamports: u64,
+}
+
+/// Space allocated for new token bondings. Bondings created at the old 512 bytes still load and
+/// trade, since every newer field is optional or a bool. They are grown with
+/// resize_token_bonding_v0 before the newer settings are turned on
+pub const TOKEN_BONDING_SIZE: usize = 1024;
+
 #[account]
 #[derive(Default)]
 pub struct TokenBondingV0 {
@@ -137,4 +285,295 @@ pub struct TokenBondingV0 {
   /** Whether or not to ignore changes to base storage and target supply outside of the curve */
   pub ignore_external_reserve_changes: bool,
   pub ignore_external_supply_changes: bool,
+
+  /// Maximum target tokens a single wallet can purchase, tracked by a WalletPurchaseV0 per buyer
+  pub wallet_purchase_cap: Option<u64>,
+  /// If set, each wallet's purchased amount resets this many seconds after its window started
+  pub wallet_purchase_window_secs: Option<i64>,
+
+  /// Merkle root of the wallets allowed to buy while the allowlist phase is active
+  pub allowlist_root: Option<[u8; 32]>,
+  /// When the allowlist phase ends. If unset, the allowlist applies until the root is removed
+  pub allowlist_end_unix_time: Option<i64>,
+
+  /// If set, buyers must hold the gate token or NFTs from the gate collection
+  pub buy_gate: Option<BuyGateV0>,
+
+  /// If set, buy target royalties are escrowed, and each buy's royalties are released linearly
+  /// with claim_vested_royalties_v0
+  pub royalty_vesting: Option<RoyaltyVestingV0>,
+
+  /// If set, buy and sell base royalties grow with the fraction of supply traded
+  pub price_impact_fees: Option<PriceImpactFeesV0>,
+
+  /// If set, the curve can only be changed through propose_curve_update_v0, taking effect at
+  /// least this many seconds after the proposal
+  pub curve_update_delay_secs: Option<i64>,
+  pub pending_curve_update: Option<PendingCurveUpdateV0>,
+
+  /// Set permanently by lock_token_bonding_v0. Royalty percentages, accounts and price impact
+  /// fees can no longer change
+  pub royalties_locked: bool,
+  /// Set permanently by lock_token_bonding_v0. Mint, purchase and wallet purchase caps and the
+  /// wallet purchase window can no longer change. The allowlist and buy gate still can
+  pub caps_locked: bool,
+  /// Set permanently by lock_curve_v0. The curve can no longer change
+  pub curve_locked: bool,
+
+  /// If set, update_token_bonding_v0 can only raise royalties gradually. Can be tightened but
+  /// never loosened or removed
+  pub royalty_change_limits: Option<RoyaltyChangeLimitsV0>,
+  /// Only kept while royalty_change_limits is set
+  pub royalty_change_period: Option<RoyaltyChangePeriodV0>,
+
+  /// Set permanently by wind_down_token_bonding_v0. Buys stop, sells redeem a pro-rata share of
+  /// base storage, and reserve transfers and close wait until the whole supply is gone
+  pub wind_down: bool,
+
+  /// Starts with the first buy or sell
+  pub price_accumulator: Option<PriceAccumulatorV0>,
+
+  /// Set by initialize_reserve_stake_v0 on native bondings whose idle reserves are staked
+  pub reserve_stake: Option<ReserveStakeV0>,
+}
+
+pub const MAX_ROYALTY_RECIPIENTS: usize = 16;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct RoyaltyRecipientV0 {
+  /// Wallet paid in SOL, or the owner of the token account paid in the split mint
+  pub wallet: Pubkey,
+  /// Share of everything the split receives, in basis points
+  pub share_bps: u16,
+  /// Split mint tokens paid out to this recipient so far
+  pub claimed_amount: u64,
+  /// Lamports paid out to this recipient so far
+  pub native_claimed_amount: u64,
+}
+
+/// Splits royalties between several recipients. Point a token bonding's royalty accounts at the
+/// split's escrow (token royalties) or vault (native royalties) and pay recipients out of it with
+/// claim_royalty_split_v0
+#[account]
+#[derive(Default)]
+pub struct RoyaltySplitV0 {
+  pub mint: Pubkey,
+  /// Token account of `mint` owned by the vault
+  pub escrow: Pubkey,
+  /// System account holding native royalties. Also the escrow's authority
+  pub vault: Pubkey,
+  pub recipients: Vec<RoyaltyRecipientV0>,
+  pub total_claimed_amount: u64,
+  pub total_native_claimed_amount: u64,
+  pub escrow_bump_seed: u8,
+  pub vault_bump_seed: u8,
+}
+
+pub const ROYALTY_SPLIT_SIZE: usize =
+  8 + 32 + 32 + 32 + 4 + MAX_ROYALTY_RECIPIENTS * (32 + 2 + 8 + 8) + 8 + 8 + 1 + 1;
+
+pub const PRICE_ORACLE_OBSERVATIONS: usize = 64;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct PriceObservationV0 {
+  pub unix_time: i64,
+  pub cumulative_price: u128,
+}
+
+/// Ring buffer of checkpoints of a token bonding's price accumulator, for time weighted average
+/// prices. Written by buys and sells that pass it, or by record_price_observation_v0
+#[account]
+#[derive(Default)]
+pub struct PriceOracleV0 {
+  pub token_bonding: Pubkey,
+  /// Observations closer together than this are skipped, so a burst of trades cannot wipe
+  /// out the history
+  pub observation_interval_secs: i64,
+  /// Where the next observation is written once the buffer is full
+  pub next_index: u16,
+  pub observations: Vec<PriceObservationV0>,
+  pub bump_seed: u8,
+}
+
+pub const PRICE_ORACLE_SIZE: usize = 8 + 32 + 8 + 2 + 4 + PRICE_ORACLE_OBSERVATIONS * (8 + 16) + 1;
+
+#[account]
+#[derive(Default)]
+pub struct WalletPurchaseV0 {
+  pub token_bonding: Pubkey,
+  pub wallet: Pubkey,
+  pub purchased_amount: u64,
+  pub window_start_unix_time: i64,
+  pub bump_seed: u8,
+}
+
+/// Where idle native reserves are staked. There is only one, since every native token bonding
+/// shares sol storage. Each bonding keeps its own share in TokenBondingV0::reserve_stake
+#[account]
+#[derive(Default)]
+pub struct ReserveStakingV0 {
+  /// Can change the staked percentage and claim yield
+  pub authority: Pubkey,
+  pub stake_pool_program: Pubkey,
+  pub stake_pool: Pubkey,
+  /// Sol storage's account of the stake pool's mint
+  pub pool_tokens: Pubkey,
+  /// Share of each bonding's base storage, out of u32::MAX, that may be staked. At most
+  /// MAX_STAKED_PERCENTAGE, the rest stays in sol storage for sells
+  pub max_staked_percentage: u32,
+  pub bump_seed: u8,
+  pub pool_tokens_bump_seed: u8,
+}
+
+/// At least a fifth of each staked bonding's base storage stays unstaked for sells, so most
+/// sells do not have to unstake
+pub const MAX_STAKED_PERCENTAGE: u32 = u32::MAX / 5 * 4;
+
+pub const RESERVE_STAKING_SIZE: usize = 8 + 32 * 4 + 4 + 1 + 1;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use anchor_lang::Discriminator;
+
+  // TokenBondingV0 as it was before the newer settings, when bondings were allocated 512 bytes
+  #[derive(AnchorSerialize)]
+  struct BaselineTokenBondingV0 {
+    base_mint: Pubkey,
+    target_mint: Pubkey,
+    general_authority: Option<Pubkey>,
+    reserve_authority: Option<Pubkey>,
+    curve_authority: Option<Pubkey>,
+    base_storage: Pubkey,
+    buy_base_royalties: Pubkey,
+    buy_target_royalties: Pubkey,
+    sell_base_royalties: Pubkey,
+    sell_target_royalties: Pubkey,
+    buy_base_royalty_percentage: u32,
+    buy_target_royalty_percentage: u32,
+    sell_base_royalty_percentage: u32,
+    sell_target_royalty_percentage: u32,
+    curve: Pubkey,
+    mint_cap: Option<u64>,
+    purchase_cap: Option<u64>,
+    go_live_unix_time: i64,
+    freeze_buy_unix_time: Option<i64>,
+    created_at_unix_time: i64,
+    buy_frozen: bool,
+    sell_frozen: bool,
+    index: u16,
+    bump_seed: u8,
+    base_storage_bump_seed: u8,
+    target_mint_authority_bump_seed: u8,
+    base_storage_authority_bump_seed: Option<u8>,
+    reserve_balance_from_bonding: u64,
+    supply_from_bonding: u64,
+    ignore_external_reserve_changes: bool,
+    ignore_external_supply_changes: bool,
+  }
+
+  fn baseline_account() -> Vec<u8> {
+    let baseline = BaselineTokenBondingV0 {
+      base_mint: Pubkey::new_unique(),
+      target_mint: Pubkey::new_unique(),
+      general_authority: Some(Pubkey::new_unique()),
+      reserve_authority: Some(Pubkey::new_unique()),
+      curve_authority: Some(Pubkey::new_unique()),
+      base_storage: Pubkey::new_unique(),
+      buy_base_royalties: Pubkey::new_unique(),
+      buy_target_royalties: Pubkey::new_unique(),
+      sell_base_royalties: Pubkey::new_unique(),
+      sell_target_royalties: Pubkey::new_unique(),
+      buy_base_royalty_percentage: 1,
+      buy_target_royalty_percentage: 2,
+      sell_base_royalty_percentage: 3,
+      sell_target_royalty_percentage: 4,
+      curve: Pubkey::new_unique(),
+      mint_cap: Some(5),
+      purchase_cap: Some(6),
+      go_live_unix_time: 7,
+      freeze_buy_unix_time: Some(8),
+      created_at_unix_time: 9,
+      buy_frozen: false,
+      sell_frozen: true,
+      index: 10,
+      bump_seed: 11,
+      base_storage_bump_seed: 12,
+      target_mint_authority_bump_seed: 13,
+      base_storage_authority_bump_seed: Some(14),
+      reserve_balance_from_bonding: 15,
+      supply_from_bonding: 16,
+      ignore_external_reserve_changes: true,
+      ignore_external_supply_changes: true,
+    };
+    let mut data = TokenBondingV0::discriminator().to_vec();
+    data.extend(baseline.try_to_vec().unwrap());
+    data.resize(512, 0);
+    data
+  }
+
+  #[test]
+  fn test_baseline_token_bonding_deserializes() {
+    let data = baseline_account();
+    let bonding = TokenBondingV0::try_deserialize(&mut &data[..]).unwrap();
+    assert!(bonding.curve_authority.is_some());
+    assert_eq!(bonding.supply_from_bonding, 16);
+    assert!(bonding.ignore_external_supply_changes);
+    assert!(bonding.wallet_purchase_cap.is_none());
+    assert!(bonding.royalty_change_limits.is_none());
+    assert!(bonding.royalty_change_period.is_none());
+    assert!(bonding.price_accumulator.is_none());
+    assert!(bonding.reserve_stake.is_none());
+    assert!(!bonding.wind_down);
+  }
+
+  #[test]
+  fn test_baseline_token_bonding_still_fits_after_trading() {
+    let data = baseline_account();
+    let mut bonding = TokenBondingV0::try_deserialize(&mut &data[..]).unwrap();
+    bonding.price_accumulator = Some(PriceAccumulatorV0 {
+      last_price: u64::MAX,
+      last_unix_time: i64::MAX,
+      cumulative_price: u128::MAX,
+    });
+    let mut out = vec![0; 512];
+    bonding.try_serialize(&mut &mut out[..]).unwrap();
+    assert_eq!(
+      TokenBondingV0::try_deserialize(&mut &out[..])
+        .unwrap()
+        .price_accumulator
+        .unwrap()
+        .last_price,
+      u64::MAX
+    );
+  }
+
+  #[test]
+  fn test_token_bonding_with_every_setting_fits() {
+    let bonding = TokenBondingV0 {
+      general_authority: Some(Pubkey::new_unique()),
+      reserve_authority: Some(Pubkey::new_unique()),
+      curve_authority: Some(Pubkey::new_unique()),
+      mint_cap: Some(1),
+      purchase_cap: Some(1),
+      freeze_buy_unix_time: Some(1),
+      base_storage_authority_bump_seed: Some(1),
+      wallet_purchase_cap: Some(1),
+      wallet_purchase_window_secs: Some(1),
+      allowlist_root: Some([1; 32]),
+      allowlist_end_unix_time: Some(1),
+      buy_gate: Some(BuyGateV0::default()),
+      royalty_vesting: Some(RoyaltyVestingV0::default()),
+      price_impact_fees: Some(PriceImpactFeesV0::default()),
+      curve_update_delay_secs: Some(1),
+      pending_curve_update: Some(PendingCurveUpdateV0::default()),
+      royalty_change_limits: Some(RoyaltyChangeLimitsV0::default()),
+      royalty_change_period: Some(RoyaltyChangePeriodV0::default()),
+      price_accumulator: Some(PriceAccumulatorV0::default()),
+      reserve_stake: Some(ReserveStakeV0::default()),
+      ..Default::default()
+    };
+    let mut out = vec![0; TOKEN_BONDING_SIZE];
+    bonding.try_serialize(&mut &mut out[..]).unwrap();
+  }
 }
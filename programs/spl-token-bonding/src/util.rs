@@ -1,8 +1,10 @@
 use crate::error::ErrorCode;
 use crate::precise_number::{InnerUint, PreciseNumber};
-use anchor_lang::solana_program::system_program;
+use crate::state::{PriceImpactFeesV0, RoyaltyChangeLimitsV0};
+use anchor_lang::solana_program::{keccak, system_program};
 use anchor_lang::{prelude::*, solana_program};
 use anchor_spl::token::{Mint, TokenAccount};
+use mpl_token_metadata::state::Metadata;
 use std::convert::*;
 
 pub trait OrArithError<T> {
@@ -92,9 +94,13 @@ fn get_u128_pow_10(decimals: u8) -> u128 {
 }
 
 pub fn precise_supply_amt(amt: u64, mint: &Mint) -> PreciseNumber {
+  precise_decimals_amt(amt, mint.decimals)
+}
+
+pub fn precise_decimals_amt(amt: u64, decimals: u8) -> PreciseNumber {
   PreciseNumber {
     value: InnerUint::from(amt)
-      .checked_mul(InnerUint::from(get_u128_pow_10(12_u8 - mint.decimals)))
+      .checked_mul(InnerUint::from(get_u128_pow_10(12_u8 - decimals)))
       .unwrap()
       .checked_mul(InnerUint::from(1_000_000u64)) // Add 6 precision
       .unwrap(),
@@ -102,8 +108,12 @@ pub fn precise_supply_amt(amt: u64, mint: &Mint) -> PreciseNumber {
 }
 
 pub fn to_mint_amount(amt: &PreciseNumber, mint: &Mint, ceil: bool) -> u64 {
+  to_decimals_amount(amt, mint.decimals, ceil)
+}
+
+pub fn to_decimals_amount(amt: &PreciseNumber, decimals: u8, ceil: bool) -> u64 {
   // Lookup is faster than a checked_pow
-  let pow_10 = get_pow_10(mint.decimals);
+  let pow_10 = get_pow_10(decimals);
 
   let pre_round = amt.checked_mul(&pow_10).unwrap();
   let post_round = if ceil {
@@ -163,3 +173,72 @@ pub fn close_token_account<'a, 'b, 'c, 'info>(
   )
   .map_err(|e| e.into())
 }
+
+pub fn verify_price_impact_fees(price_impact_fees: &Option<PriceImpactFeesV0>) -> Result<()> {
+  if let Some(fees) = price_impact_fees {
+    if fees.min_impact_percentage >= fees.max_impact_percentage {
+      return Err(error!(ErrorCode::InvalidPriceImpactFees));
+    }
+  }
+
+  Ok(())
+}
+
+/// Royalty change limits need a positive period, and once set can only be tightened
+pub fn verify_royalty_change_limits(
+  current: &Option<RoyaltyChangeLimitsV0>,
+  new: &Option<RoyaltyChangeLimitsV0>,
+) -> Result<()> {
+  let valid = match (current, new) {
+    (_, Some(new)) if new.period_secs <= 0 => false,
+    (Some(current), Some(new)) => {
+      new.max_change_percentage <= current.max_change_percentage
+        && new.period_secs >= current.period_secs
+        && new.max_royalty_percentage <= current.max_royalty_percentage
+    }
+    (Some(_), None) => false,
+    (None, _) => true,
+  };
+  if !valid {
+    return Err(error!(ErrorCode::InvalidRoyaltyChangeLimits));
+  }
+
+  Ok(())
+}
+
+/// Verifies a keccak merkle proof where each pair of nodes is hashed in sorted order
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+  let computed = proof.iter().fold(leaf, |node, sibling| {
+    if node <= *sibling {
+      keccak::hashv(&[&node, sibling]).0
+    } else {
+      keccak::hashv(&[sibling, &node]).0
+    }
+  });
+
+  computed == root
+}
+
+/// Loads the Metaplex metadata for `mint`, checking it is owned by the metadata program and is
+/// the metadata PDA for that mint
+pub fn assert_valid_metadata(metadata_info: &AccountInfo, mint: Pubkey) -> Result<Metadata> {
+  let metadata_program = mpl_token_metadata::id();
+
+  // 1 verify the owner of the account is metaplex's metadata program
+  if metadata_info.owner != &metadata_program {
+    return Err(error!(ErrorCode::InvalidMetadata));
+  }
+
+  // 2 verify the PDA seeds match
+  let seed = &[
+    b"metadata".as_ref(),
+    metadata_program.as_ref(),
+    mint.as_ref(),
+  ];
+  let (metadata_addr, _bump) = Pubkey::find_program_address(seed, &metadata_program);
+  if metadata_addr != metadata_info.key() {
+    return Err(error!(ErrorCode::InvalidMetadata));
+  }
+
+  Ok(Metadata::from_account_info(metadata_info)?)
+}
@@ -683,3 +683,83 @@ pub struct UpdateCurveV0Wrapper<'info> {
   )]
   pub target_mint: Box<Account<'info, Mint>>,
 }
+
+#[derive(Accounts)]
+pub struct ProposeCurveUpdateV0Wrapper<'info> {
+  pub collective: Box<Account<'info, CollectiveV0>>,
+  /// CHECK: Checked via constraints
+  #[account(
+    constraint = (collective.config.is_open || authority.is_signer) && authority.key() == collective.authority.unwrap_or(Pubkey::default())
+  )]
+  pub authority: AccountInfo<'info>,
+  #[account(
+    constraint = mint_token_ref.token_bonding.ok_or(error!(ErrorCode::NoBonding))? == token_bonding.key(),
+    constraint = mint_token_ref.collective.is_none() || collective.key() == mint_token_ref.collective.unwrap() @ ErrorCode::InvalidCollective,
+    // either the collective authority is signing or the token ref authority is correct
+    constraint = authority.is_signer || token_ref_authority.key() == mint_token_ref.authority.unwrap(),
+  )]
+  pub mint_token_ref: Box<Account<'info, TokenRefV0>>,
+  #[account(
+    mut,
+    has_one = base_mint,
+    has_one = target_mint
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  // this can be an arbitrary signed address if the collective authority is signing
+  pub token_ref_authority: Signer<'info>,
+  pub curve: Box<Account<'info, CurveV0>>,
+  pub clock: Sysvar<'info, Clock>,
+
+  /// CHECK: Checked with constraint
+  #[account(address = spl_token_bonding::id())]
+  pub token_bonding_program: AccountInfo<'info>,
+
+  #[account(
+    constraint = *base_mint.to_account_info().owner == spl_token::ID
+  )]
+  pub base_mint: Box<Account<'info, Mint>>,
+  #[account(
+    constraint = target_mint.is_initialized,
+    constraint = *target_mint.to_account_info().owner == *base_mint.to_account_info().owner
+  )]
+  pub target_mint: Box<Account<'info, Mint>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelCurveUpdateV0Wrapper<'info> {
+  pub collective: Box<Account<'info, CollectiveV0>>,
+  /// CHECK: Checked via constraints
+  #[account(
+    constraint = (collective.config.is_open || authority.is_signer) && authority.key() == collective.authority.unwrap_or(Pubkey::default())
+  )]
+  pub authority: AccountInfo<'info>,
+  #[account(
+    constraint = mint_token_ref.token_bonding.ok_or(error!(ErrorCode::NoBonding))? == token_bonding.key(),
+    constraint = mint_token_ref.collective.is_none() || collective.key() == mint_token_ref.collective.unwrap() @ ErrorCode::InvalidCollective,
+    // either the collective authority is signing or the token ref authority is correct
+    constraint = authority.is_signer || token_ref_authority.key() == mint_token_ref.authority.unwrap(),
+  )]
+  pub mint_token_ref: Box<Account<'info, TokenRefV0>>,
+  #[account(
+    mut,
+    has_one = base_mint,
+    has_one = target_mint
+  )]
+  pub token_bonding: Box<Account<'info, TokenBondingV0>>,
+  // this can be an arbitrary signed address if the collective authority is signing
+  pub token_ref_authority: Signer<'info>,
+
+  /// CHECK: Checked with constraint
+  #[account(address = spl_token_bonding::id())]
+  pub token_bonding_program: AccountInfo<'info>,
+
+  #[account(
+    constraint = *base_mint.to_account_info().owner == spl_token::ID
+  )]
+  pub base_mint: Box<Account<'info, Mint>>,
+  #[account(
+    constraint = target_mint.is_initialized,
+    constraint = *target_mint.to_account_info().owner == *base_mint.to_account_info().owner
+  )]
+  pub target_mint: Box<Account<'info, Mint>>,
+}
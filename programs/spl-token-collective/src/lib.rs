@@ -8,6 +8,7 @@ use {
   anchor_lang::prelude::*,
   anchor_spl::token::{transfer, Transfer},
   borsh::{BorshDeserialize, BorshSerialize},
+  spl_token_bonding::instructions::propose_curve_update_v0::ProposeCurveUpdateV0Args,
   spl_token_bonding::instructions::update_curve_v0::UpdateCurveV0Args,
   spl_token_bonding::instructions::update_token_bonding_v0::UpdateTokenBondingV0Args,
 };
@@ -20,6 +21,8 @@ pub mod state;
 pub mod token_metadata;
 pub mod util;
 
+use spl_token_bonding::cpi::accounts::CancelCurveUpdateV0;
+use spl_token_bonding::cpi::accounts::ProposeCurveUpdateV0;
 use spl_token_bonding::cpi::accounts::UpdateCurveV0;
 use spl_token_bonding::cpi::accounts::UpdateTokenBondingV0;
 use token_metadata::UpdateMetadataAccount;
@@ -480,6 +483,52 @@ pub mod spl_token_collective {
     Ok(())
   }
 
+  pub fn propose_curve_update_v0(
+    ctx: Context<ProposeCurveUpdateV0Wrapper>,
+    args: ProposeCurveUpdateV0Args,
+  ) -> Result<()> {
+    let token_ref_seeds: &[&[&[u8]]] = &[&[
+      b"mint-token-ref",
+      ctx.accounts.target_mint.to_account_info().key.as_ref(),
+      &[ctx.accounts.mint_token_ref.bump_seed],
+    ]];
+
+    spl_token_bonding::cpi::propose_curve_update_v0(
+      CpiContext::new_with_signer(
+        ctx.accounts.token_bonding_program.clone(),
+        ProposeCurveUpdateV0 {
+          token_bonding: ctx.accounts.token_bonding.to_account_info().clone(),
+          curve_authority: ctx.accounts.mint_token_ref.to_account_info().clone(),
+          curve: ctx.accounts.curve.to_account_info().clone(),
+          clock: ctx.accounts.clock.to_account_info().clone(),
+        },
+        token_ref_seeds,
+      ),
+      args,
+    )?;
+
+    Ok(())
+  }
+
+  pub fn cancel_curve_update_v0(ctx: Context<CancelCurveUpdateV0Wrapper>) -> Result<()> {
+    let token_ref_seeds: &[&[&[u8]]] = &[&[
+      b"mint-token-ref",
+      ctx.accounts.target_mint.to_account_info().key.as_ref(),
+      &[ctx.accounts.mint_token_ref.bump_seed],
+    ]];
+
+    spl_token_bonding::cpi::cancel_curve_update_v0(CpiContext::new_with_signer(
+      ctx.accounts.token_bonding_program.clone(),
+      CancelCurveUpdateV0 {
+        token_bonding: ctx.accounts.token_bonding.to_account_info().clone(),
+        curve_authority: ctx.accounts.mint_token_ref.to_account_info().clone(),
+      },
+      token_ref_seeds,
+    ))?;
+
+    Ok(())
+  }
+
   pub fn update_token_bonding_v0(
     ctx: Context<UpdateTokenBondingV0Wrapper>,
     args: UpdateTokenBondingV0ArgsWrapper,
@@ -10,13 +10,22 @@ import {
   Token,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import { Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import {
+  Keypair,
+  PublicKey,
+  StakeProgram,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
 import { expect, use } from "chai";
 import ChaiAsPromised from "chai-as-promised";
 import {
   ExponentialCurve,
   ExponentialCurveConfig,
   ITokenBonding,
+  IUpdateTokenBondingArgs,
   SplTokenBonding,
   TimeCurveConfig,
   TimeDecayExponentialCurveConfig,
@@ -24,6 +33,7 @@ import {
 } from "../packages/spl-token-bonding/src";
 import { BondingPricing } from "../packages/spl-token-bonding/src/pricing";
 import { waitForUnixTime } from "./utils/clock";
+import { createStakePool, STAKE_POOL_PROGRAM_ID } from "./utils/stakePool";
 import { TokenUtils } from "./utils/token";
 
 use(ChaiAsPromised);
@@ -204,6 +214,148 @@ describe("spl-token-bonding", () => {
       expect(tokenBondingNow.reserveAuthority).to.equal(null);
     });
 
+    it("leaves the allowlist alone on updates that don't set it", async () => {
+      const root = Array(32).fill(1);
+      await tokenBondingProgram.updateTokenBonding({
+        tokenBonding,
+        allowlistRoot: root,
+      });
+      await tokenBondingProgram.updateTokenBonding({
+        tokenBonding,
+        buyTargetRoyaltyPercentage: 15,
+      });
+      let tokenBondingNow = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+      expect(tokenBondingNow.allowlistRoot).to.deep.equal(root);
+
+      // Existing clients still send the v0 args, which have no allowlist fields
+      const tx = new Transaction();
+      tx.add(
+        await program.instruction.updateTokenBondingV0(
+          {
+            generalAuthority: me,
+            buyBaseRoyaltyPercentage: tokenBondingNow.buyBaseRoyaltyPercentage,
+            buyTargetRoyaltyPercentage:
+              tokenBondingNow.buyTargetRoyaltyPercentage,
+            sellBaseRoyaltyPercentage: tokenBondingNow.sellBaseRoyaltyPercentage,
+            sellTargetRoyaltyPercentage:
+              tokenBondingNow.sellTargetRoyaltyPercentage,
+            buyFrozen: true,
+          },
+          {
+            accounts: {
+              tokenBonding,
+              generalAuthority: me,
+              baseMint,
+              targetMint: tokenBondingAcct.targetMint,
+              buyBaseRoyalties: tokenBondingAcct.buyBaseRoyalties,
+              buyTargetRoyalties: tokenBondingAcct.buyTargetRoyalties,
+              sellBaseRoyalties: tokenBondingAcct.sellBaseRoyalties,
+              sellTargetRoyalties: tokenBondingAcct.sellTargetRoyalties,
+            },
+          }
+        )
+      );
+      await provider.sendAndConfirm(tx);
+      tokenBondingNow = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+      expect(tokenBondingNow.buyFrozen).to.equal(true);
+      expect(tokenBondingNow.allowlistRoot).to.deep.equal(root);
+
+      await tokenBondingProgram.updateTokenBonding({
+        tokenBonding,
+        allowlistRoot: null,
+      });
+      tokenBondingNow = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+      expect(tokenBondingNow.allowlistRoot).to.equal(null);
+    });
+
+    describe("with caps locked", () => {
+      beforeEach(async () => {
+        await program.rpc.lockTokenBondingV0(
+          { lockRoyalties: false, lockCaps: true },
+          { accounts: { tokenBonding, generalAuthority: me } }
+        );
+      });
+
+      async function expectCapsLocked(
+        caps: Partial<IUpdateTokenBondingArgs>
+      ): Promise<void> {
+        try {
+          await tokenBondingProgram.updateTokenBonding({
+            tokenBonding,
+            ...caps,
+          });
+          throw "Shouldn't get here";
+        } catch (e: any) {
+          expect(e.toString()).to.contain("CapsLocked");
+        }
+      }
+
+      it("rejects changing the mint cap", async () => {
+        await expectCapsLocked({ mintCap: new BN(2000) });
+        await expectCapsLocked({ mintCap: null });
+      });
+
+      it("rejects changing the purchase cap", async () => {
+        await expectCapsLocked({ purchaseCap: new BN(10) });
+      });
+
+      it("rejects changing the wallet purchase cap", async () => {
+        await expectCapsLocked({ walletPurchaseCap: new BN(10) });
+        await expectCapsLocked({ walletPurchaseWindowSecs: new BN(60) });
+      });
+
+      it("still allows resending the same caps", async () => {
+        await tokenBondingProgram.updateTokenBonding({
+          tokenBonding,
+          mintCap: new BN(1000),
+          buyFrozen: true,
+        });
+        const tokenBondingNow = (await tokenBondingProgram.getTokenBonding(
+          tokenBonding
+        ))!;
+        expect(tokenBondingNow.buyFrozen).to.equal(true);
+        expect((tokenBondingNow.mintCap as BN).toNumber()).to.equal(1000);
+      });
+
+      it("still allows changing the allowlist and buy gate", async () => {
+        const root = Array(32).fill(1);
+        await tokenBondingProgram.updateTokenBonding({
+          tokenBonding,
+          allowlistRoot: root,
+          buyGate: {
+            gateType: { token: {} },
+            key: baseMint,
+            minimumAmount: new BN(1),
+          },
+        });
+        const tokenBondingNow = (await tokenBondingProgram.getTokenBonding(
+          tokenBonding
+        ))!;
+        expect(tokenBondingNow.allowlistRoot).to.deep.equal(root);
+        expect(tokenBondingNow.buyGate).to.not.equal(null);
+      });
+    });
+
+    it("allows updating caps before they are locked", async () => {
+      await tokenBondingProgram.updateTokenBonding({
+        tokenBonding,
+        mintCap: new BN(2000),
+        walletPurchaseCap: new BN(10),
+      });
+      const tokenBondingNow = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+      expect((tokenBondingNow.mintCap as BN).toNumber()).to.equal(2000);
+      expect((tokenBondingNow.walletPurchaseCap as BN).toNumber()).to.equal(10);
+      expect(tokenBondingNow.purchaseCap).to.equal(null);
+    });
+
     it("allows buying the bonding curve", async () => {
       await tokenBondingProgram.buy({
         tokenBonding,
@@ -215,6 +367,56 @@ describe("spl-token-bonding", () => {
       await tokenUtils.expectAtaBalance(me, tokenBondingAcct.targetMint, 0.55);
     });
 
+    it("still accepts the v0 buy args on buy_v1", async () => {
+      const destination = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        tokenBondingAcct.targetMint,
+        me
+      );
+      const source = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        baseMint,
+        me
+      );
+      // The target royalties ata doubles as the destination, so it already exists
+      const tx = new Transaction();
+      tx.add(
+        await program.instruction.buyV1(
+          {
+            buyTargetAmount: {
+              targetAmount: new BN(50),
+              maximumPrice: new BN(100),
+            },
+            buyWithBase: null,
+          },
+          {
+            accounts: {
+              common: {
+                tokenBonding,
+                curve,
+                baseMint,
+                targetMint: tokenBondingAcct.targetMint,
+                baseStorage: tokenBondingAcct.baseStorage,
+                buyBaseRoyalties: tokenBondingAcct.buyBaseRoyalties,
+                buyTargetRoyalties: tokenBondingAcct.buyTargetRoyalties,
+                destination,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+              },
+              state: (await tokenBondingProgram.getState())!.publicKey,
+              source,
+              sourceAuthority: me,
+            },
+          }
+        )
+      );
+      await provider.sendAndConfirm(tx);
+
+      await tokenUtils.expectAtaBalance(me, tokenBondingAcct.targetMint, 0.55);
+    });
+
     it("does not allow buying past the cap", async () => {
       try {
         await tokenBondingProgram.buy({
@@ -249,6 +451,55 @@ describe("spl-token-bonding", () => {
         INITIAL_BALANCE / Math.pow(10, DECIMALS)
       );
     });
+
+    it("allows selling for an exact base amount", async () => {
+      await tokenBondingProgram.buy({
+        tokenBonding,
+        desiredTargetAmount: new BN(50),
+        slippage: 0.5,
+      });
+      const baseAta = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        baseMint,
+        me
+      );
+      const before = (await provider.connection.getTokenAccountBalance(baseAta))
+        .value.uiAmount!;
+
+      await tokenBondingProgram.sell({
+        tokenBonding,
+        targetAmount: new BN(55),
+        baseAmount: new BN(5),
+        slippage: 0,
+      });
+
+      const after = (await provider.connection.getTokenAccountBalance(baseAta))
+        .value.uiAmount!;
+      expect(after).to.be.closeTo(before + 0.05, 0.001);
+    });
+
+    it("does not sell more than the maximum for an exact base amount", async () => {
+      await tokenBondingProgram.buy({
+        tokenBonding,
+        desiredTargetAmount: new BN(50),
+        slippage: 0.5,
+      });
+
+      try {
+        await tokenBondingProgram.sell({
+          tokenBonding,
+          targetAmount: new BN(1),
+          baseAmount: new BN(5),
+          slippage: 0,
+        });
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.equal(
+          "SellAmountTooHigh: Selling for that base amount would sell more than the maximum target amount. Try increasing maximum_target_amount or slippage configuration"
+        );
+      }
+    });
   });
 
   describe("buy with base vs buy with target", () => {
@@ -500,6 +751,128 @@ describe("spl-token-bonding", () => {
     });
   });
 
+  describe("curves without a closed form buy", () => {
+    const DECIMALS = 3;
+
+    // The hardest base amounts to solve for found when fuzzing these curves
+    // from zero supply. Each must fit the default compute budget.
+    async function buyWithBase(
+      primitive: any,
+      baseAmount: number
+    ): Promise<void> {
+      const baseMint = await createMint(provider, me, DECIMALS);
+      await createAtaAndMint(provider, baseMint, 1000 * Math.pow(10, DECIMALS));
+      const curve = await tokenBondingProgram.initializeCurve({
+        config: {
+          toRawConfig: () => ({
+            definition: {
+              timeV0: {
+                curves: [
+                  {
+                    offset: new BN(0),
+                    curve: primitive,
+                    buyTransitionFees: null,
+                    sellTransitionFees: null,
+                  },
+                ],
+              },
+            },
+          }),
+        },
+      });
+      const { tokenBonding } = await tokenBondingProgram.createTokenBonding({
+        curve,
+        baseMint,
+        targetMintDecimals: DECIMALS,
+        generalAuthority: me,
+        buyBaseRoyaltyPercentage: 0,
+        buyTargetRoyaltyPercentage: 0,
+        sellBaseRoyaltyPercentage: 0,
+        sellTargetRoyaltyPercentage: 0,
+      });
+      const tokenBondingAcct = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+      const destination = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        tokenBondingAcct.targetMint,
+        me
+      );
+      const source = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        baseMint,
+        me
+      );
+      // The target royalties ata doubles as the destination, so it already exists
+      const tx = new Transaction();
+      tx.add(
+        await program.instruction.buyV1(
+          {
+            buyTargetAmount: null,
+            buyWithBase: {
+              baseAmount: new BN(baseAmount),
+              minimumTargetAmount: new BN(1),
+            },
+          },
+          {
+            accounts: {
+              common: {
+                tokenBonding,
+                curve,
+                baseMint,
+                targetMint: tokenBondingAcct.targetMint,
+                baseStorage: tokenBondingAcct.baseStorage,
+                buyBaseRoyalties: tokenBondingAcct.buyBaseRoyalties,
+                buyTargetRoyalties: tokenBondingAcct.buyTargetRoyalties,
+                destination,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                clock: SYSVAR_CLOCK_PUBKEY,
+              },
+              state: (await tokenBondingProgram.getState())!.publicKey,
+              source,
+              sourceAuthority: me,
+            },
+          }
+        )
+      );
+      await provider.sendAndConfirm(tx);
+
+      await tokenUtils.expectAtaBalance(
+        me,
+        baseMint,
+        1000 - baseAmount / Math.pow(10, DECIMALS)
+      );
+    }
+
+    it("solves a logarithmic buy within the compute budget", async () => {
+      await buyWithBase(
+        {
+          logarithmicCurveV0: {
+            c: new BN("543943000000"),
+            g: new BN("691749000000"),
+            b: new BN("5071000000"),
+          },
+        },
+        17984
+      );
+    });
+
+    it("solves a sigmoid buy within the compute budget", async () => {
+      await buyWithBase(
+        {
+          sigmoidCurveV0: {
+            maxPrice: new BN("866584000000"),
+            k: new BN("498000000"),
+            midpoint: new BN("505545000000000000"),
+          },
+        },
+        219687
+      );
+    });
+  });
+
   describe("edge cases", () => {
     let baseMint: PublicKey;
     let curve: PublicKey;
@@ -1353,5 +1726,908 @@ describe("spl-token-bonding", () => {
         tokenBonding,
       });
     });
+
+    it("keeps the reserves that back the supply when ignoring external changes", async () => {
+      const baseMint = await createMint(provider, me, 2);
+      const ata = await createAtaAndMint(provider, baseMint, 100_00);
+      const curve = await tokenBondingProgram.initializeCurve({
+        config: new ExponentialCurveConfig({
+          c: 0,
+          b: 5,
+          pow: 0,
+          frac: 1,
+        }),
+      });
+      const { tokenBonding } = await tokenBondingProgram.createTokenBonding({
+        curve,
+        reserveAuthority: me,
+        baseMint,
+        targetMintDecimals: 2,
+        generalAuthority: me,
+        buyBaseRoyaltyPercentage: 0,
+        buyTargetRoyaltyPercentage: 0,
+        sellBaseRoyaltyPercentage: 0,
+        sellTargetRoyaltyPercentage: 0,
+        ignoreExternalReserveChanges: true,
+      });
+      await tokenBondingProgram.buy({
+        tokenBonding,
+        baseAmount: 10,
+        slippage: 0.05,
+      });
+      const tokenBondingAcct = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+
+      // v0 cannot see the curve or the supply, so it cannot check solvency
+      try {
+        await program.rpc.transferReservesV0(
+          { amount: new BN(100) },
+          {
+            accounts: {
+              common: {
+                tokenBonding,
+                reserveAuthority: me,
+                baseMint,
+                baseStorage: tokenBondingAcct.baseStorage,
+                tokenProgram: TOKEN_PROGRAM_ID,
+              },
+              destination: ata,
+            },
+          }
+        );
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("TransferReservesV1Only");
+      }
+
+      try {
+        await tokenBondingProgram.transferReserves({
+          tokenBonding,
+          amount: 10,
+        });
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("ReservesInsolvent");
+      }
+
+      // Once the supply is sold back, nothing is left to cover
+      await tokenBondingProgram.sell({
+        tokenBonding,
+        targetAmount: 2,
+        slippage: 0.05,
+      });
+      await tokenUtils.expectAtaBalance(me, baseMint, 100);
+    });
+  });
+
+  describe("wind down", () => {
+    it("sweeps what holders left behind when closing", async () => {
+      const baseMint = await createMint(provider, me, 2);
+      await createAtaAndMint(provider, baseMint, 100_00);
+      const curve = await tokenBondingProgram.initializeCurve({
+        config: new ExponentialCurveConfig({
+          c: 0,
+          b: 5,
+          pow: 0,
+          frac: 1,
+        }),
+      });
+      // No reserve authority, so nothing but close can take the leftovers out
+      const { tokenBonding, targetMint } =
+        await tokenBondingProgram.createTokenBonding({
+          curve,
+          baseMint,
+          targetMintDecimals: 2,
+          generalAuthority: me,
+          buyBaseRoyaltyPercentage: 0,
+          buyTargetRoyaltyPercentage: 0,
+          sellBaseRoyaltyPercentage: 0,
+          sellTargetRoyaltyPercentage: 0,
+        });
+      await tokenBondingProgram.buy({
+        tokenBonding,
+        baseAmount: 10,
+        slippage: 0.05,
+      });
+      await program.rpc.windDownTokenBondingV0({
+        accounts: {
+          tokenBonding,
+          generalAuthority: me,
+        },
+      });
+
+      // Holders are still owed the reserves
+      try {
+        await tokenBondingProgram.close({ tokenBonding });
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("ConstraintRaw");
+      }
+
+      await tokenBondingProgram.sell({
+        tokenBonding,
+        targetAmount: 1,
+        slippage: 0.05,
+      });
+      await tokenUtils.expectAtaBalance(me, baseMint, 95);
+
+      // The last holder burns their tokens instead of redeeming them
+      const targetAta = await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        targetMint,
+        me
+      );
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          Token.createBurnInstruction(
+            TOKEN_PROGRAM_ID,
+            targetMint,
+            targetAta,
+            me,
+            [],
+            100
+          )
+        )
+      );
+
+      await tokenBondingProgram.close({ tokenBonding });
+      await tokenUtils.expectAtaBalance(me, baseMint, 100);
+      expect(await provider.connection.getAccountInfo(tokenBonding)).to.be
+        .null;
+    });
+  });
+
+  describe("route swap", () => {
+    const DECIMALS = 2;
+    let baseMint: PublicKey;
+    let bondingA: ITokenBonding;
+    let bondingB: ITokenBonding;
+
+    async function createBonding(
+      mint: PublicKey = baseMint
+    ): Promise<ITokenBonding> {
+      const curve = await tokenBondingProgram.initializeCurve({
+        config: new ExponentialCurveConfig({
+          c: 1,
+          b: 0,
+          pow: 1,
+          frac: 1,
+        }),
+      });
+      const { tokenBonding } = await tokenBondingProgram.createTokenBonding({
+        curve,
+        baseMint: mint,
+        targetMintDecimals: DECIMALS,
+        buyBaseRoyaltyPercentage: 0,
+        buyTargetRoyaltyPercentage: 0,
+        sellBaseRoyaltyPercentage: 0,
+        sellTargetRoyaltyPercentage: 0,
+      });
+      return (await tokenBondingProgram.getTokenBonding(tokenBonding))!;
+    }
+
+    function ata(mint: PublicKey): Promise<PublicKey> {
+      return Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        mint,
+        me
+      );
+    }
+
+    async function balance(mint: PublicKey): Promise<number> {
+      const account = await ata(mint);
+      if (!(await provider.connection.getAccountInfo(account))) {
+        return 0;
+      }
+      return Number(
+        (await provider.connection.getTokenAccountBalance(account)).value.amount
+      );
+    }
+
+    async function hop(
+      bonding: ITokenBonding,
+      buy: boolean,
+      destinationMint: PublicKey
+    ): Promise<any[]> {
+      return [
+        bonding.publicKey,
+        bonding.curve,
+        bonding.baseMint,
+        bonding.targetMint,
+        bonding.baseStorage,
+        buy ? bonding.buyBaseRoyalties : bonding.sellBaseRoyalties,
+        buy ? bonding.buyTargetRoyalties : bonding.sellTargetRoyalties,
+        await ata(destinationMint),
+      ].map((pubkey, i) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: i != 1,
+      }));
+    }
+
+    // Base into A, A back out to base, then base into B
+    async function routeThroughBoth(minimumOutputAmount: number) {
+      const state = (await tokenBondingProgram.getState())!;
+      const mintAuthority = (
+        await SplTokenBonding.wrappedSolMintAuthorityKey(program.programId)
+      )[0];
+      await program.rpc.routeSwapV0(
+        {
+          inputMint: baseMint,
+          amount: new BN(200),
+          minimumOutputAmount: new BN(minimumOutputAmount),
+          hops: [0, 1, 2].map(() => ({ extraAccounts: 0, allowlistProof: null })),
+        },
+        {
+          accounts: {
+            state: state.publicKey,
+            wrappedSolMint: state.wrappedSolMint,
+            mintAuthority,
+            solStorage: state.solStorage,
+            source: await ata(baseMint),
+            sourceAuthority: me,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            clock: SYSVAR_CLOCK_PUBKEY,
+          },
+          remainingAccounts: [
+            ...(await hop(bondingA, true, bondingA.targetMint)),
+            ...(await hop(bondingA, false, baseMint)),
+            ...(await hop(bondingB, true, bondingB.targetMint)),
+          ],
+        }
+      );
+    }
+
+    beforeEach(async () => {
+      baseMint = await createMint(provider, me, DECIMALS);
+      await createAtaAndMint(provider, baseMint, 100000);
+      bondingA = await createBonding();
+      bondingB = await createBonding();
+      // Seed both curves so the route is not the first buy
+      for (const bonding of [bondingA, bondingB]) {
+        await tokenBondingProgram.buy({
+          tokenBonding: bonding.publicKey,
+          baseAmount: 10,
+          slippage: 0.5,
+        });
+      }
+    });
+
+    it("routes through several token bondings", async () => {
+      const baseBefore = await balance(baseMint);
+      const aBefore = await balance(bondingA.targetMint);
+      const bBefore = await balance(bondingB.targetMint);
+
+      await routeThroughBoth(0);
+
+      // Everything bought from A is sold back, so only B and the base mint change
+      expect(await balance(bondingA.targetMint)).to.equal(aBefore);
+      expect(await balance(bondingB.targetMint)).to.be.greaterThan(bBefore);
+      expect(await balance(baseMint)).to.equal(baseBefore - 200);
+    });
+
+    it("fails when the final hop outputs less than the minimum", async () => {
+      const baseBefore = await balance(baseMint);
+      const bBefore = await balance(bondingB.targetMint);
+
+      try {
+        await routeThroughBoth(1000000);
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("RouteOutputTooLow");
+      }
+
+      // No hop went through
+      expect(await balance(baseMint)).to.equal(baseBefore);
+      expect(await balance(bondingB.targetMint)).to.equal(bBefore);
+    });
+
+    describe("with native sol hops", () => {
+      const wallet = Keypair.generate();
+      let solA: ITokenBonding;
+      let solB: ITokenBonding;
+
+      async function lamports(account: PublicKey): Promise<number> {
+        return (await provider.connection.getAccountInfo(account))!.lamports;
+      }
+
+      // Sells to a wallet rather than a token account
+      async function sellToWallet(
+        bonding: ITokenBonding,
+        destination: PublicKey,
+        isSigner: boolean
+      ): Promise<any[]> {
+        const accounts = await hop(bonding, false, baseMint);
+        accounts[7] = { pubkey: destination, isSigner, isWritable: true };
+        return accounts;
+      }
+
+      // Sol into A, A back out to sol at the given wallet, then that sol into B
+      async function routeThroughBoth(
+        destination: PublicKey,
+        isSigner: boolean,
+        signers: Keypair[] = []
+      ) {
+        const state = (await tokenBondingProgram.getState())!;
+        const mintAuthority = (
+          await SplTokenBonding.wrappedSolMintAuthorityKey(program.programId)
+        )[0];
+        await program.rpc.routeSwapV0(
+          {
+            inputMint: state.wrappedSolMint,
+            amount: new BN(100000000),
+            minimumOutputAmount: new BN(0),
+            hops: [0, 1, 2].map(() => ({
+              extraAccounts: 0,
+              allowlistProof: null,
+            })),
+          },
+          {
+            accounts: {
+              state: state.publicKey,
+              wrappedSolMint: state.wrappedSolMint,
+              mintAuthority,
+              solStorage: state.solStorage,
+              source: me,
+              sourceAuthority: me,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+              clock: SYSVAR_CLOCK_PUBKEY,
+            },
+            remainingAccounts: [
+              ...(await hop(solA, true, solA.targetMint)),
+              ...(await sellToWallet(solA, destination, isSigner)),
+              ...(await hop(solB, true, solB.targetMint)),
+            ],
+            signers,
+          }
+        );
+      }
+
+      before(async () => {
+        // Keep the wallet rent exempt, it only passes sol through
+        const tx = new Transaction();
+        tx.add(
+          SystemProgram.transfer({
+            fromPubkey: me,
+            toPubkey: wallet.publicKey,
+            lamports: 10000000,
+          })
+        );
+        await provider.sendAndConfirm(tx);
+      });
+
+      beforeEach(async () => {
+        solA = await createBonding(NATIVE_MINT);
+        solB = await createBonding(NATIVE_MINT);
+        for (const bonding of [solA, solB]) {
+          await tokenBondingProgram.buy({
+            tokenBonding: bonding.publicKey,
+            baseAmount: 0.01,
+            slippage: 0.5,
+          });
+        }
+      });
+
+      it("routes sol sold mid route into the next native buy", async () => {
+        const aBefore = await balance(solA.targetMint);
+        const bBefore = await balance(solB.targetMint);
+        const meBefore = await lamports(me);
+
+        await routeThroughBoth(me, true);
+
+        expect(await balance(solA.targetMint)).to.equal(aBefore);
+        expect(await balance(solB.targetMint)).to.be.greaterThan(bBefore);
+        // About the input, the sol sold from A pays for B less rounding dust
+        expect(meBefore - (await lamports(me))).to.be.within(
+          90000000,
+          100100000
+        );
+      });
+
+      it("pays the next native buy from the wallet the sol was sold to", async () => {
+        const bBefore = await balance(solB.targetMint);
+        const meBefore = await lamports(me);
+        const walletBefore = await lamports(wallet.publicKey);
+
+        await routeThroughBoth(wallet.publicKey, true, [wallet]);
+
+        expect(await balance(solB.targetMint)).to.be.greaterThan(bBefore);
+        // The wallet spends what it was sold, keeping only rounding dust
+        expect((await lamports(wallet.publicKey)) - walletBefore).to.be.within(
+          0,
+          10000000
+        );
+        // The source authority only pays for the first hop
+        expect(meBefore - (await lamports(me))).to.be.within(
+          100000000,
+          100100000
+        );
+      });
+
+      it("requires the wallet paying a native hop to sign", async () => {
+        try {
+          await routeThroughBoth(wallet.publicKey, false);
+          throw "Shouldn't get here";
+        } catch (e: any) {
+          expect(e.toString()).to.contain("AccountNotSigner");
+        }
+      });
+    });
+  });
+
+  describe("swap", () => {
+    const DECIMALS = 2;
+    const sellRoyaltiesOwner = Keypair.generate().publicKey;
+    const buyRoyaltiesOwner = Keypair.generate().publicKey;
+    let baseMint: PublicKey;
+    let sellBonding: ITokenBonding;
+    let buyBonding: ITokenBonding;
+
+    function ata(mint: PublicKey, owner: PublicKey = me): Promise<PublicKey> {
+      return Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        mint,
+        owner
+      );
+    }
+
+    async function balance(account: PublicKey): Promise<number> {
+      if (!(await provider.connection.getAccountInfo(account))) {
+        return 0;
+      }
+      return Number(
+        (await provider.connection.getTokenAccountBalance(account)).value.amount
+      );
+    }
+
+    async function swap(targetAmount: number, minimumTargetAmount: number) {
+      const state = (await tokenBondingProgram.getState())!;
+      await program.rpc.swapV0(
+        {
+          targetAmount: new BN(targetAmount),
+          minimumTargetAmount: new BN(minimumTargetAmount),
+          allowlistProof: null,
+        },
+        {
+          accounts: {
+            sell: {
+              tokenBonding: sellBonding.publicKey,
+              curve: sellBonding.curve,
+              baseMint,
+              targetMint: sellBonding.targetMint,
+              baseStorage: sellBonding.baseStorage,
+              sellBaseRoyalties: sellBonding.sellBaseRoyalties,
+              source: await ata(sellBonding.targetMint),
+              sourceAuthority: me,
+              sellTargetRoyalties: sellBonding.sellTargetRoyalties,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              clock: SYSVAR_CLOCK_PUBKEY,
+            },
+            buy: {
+              tokenBonding: buyBonding.publicKey,
+              curve: buyBonding.curve,
+              baseMint,
+              targetMint: buyBonding.targetMint,
+              baseStorage: buyBonding.baseStorage,
+              buyBaseRoyalties: buyBonding.buyBaseRoyalties,
+              destination: await ata(buyBonding.targetMint),
+              buyTargetRoyalties: buyBonding.buyTargetRoyalties,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              clock: SYSVAR_CLOCK_PUBKEY,
+            },
+            state: state.publicKey,
+          },
+        }
+      );
+    }
+
+    beforeEach(async () => {
+      baseMint = await createMint(provider, me, DECIMALS);
+      await createAtaAndMint(provider, baseMint, 100000);
+      // One base token per target token
+      const curve = await tokenBondingProgram.initializeCurve({
+        config: new ExponentialCurveConfig({
+          c: 0,
+          b: 1,
+          pow: 0,
+          frac: 1,
+        }),
+      });
+
+      const { tokenBonding: sell } =
+        await tokenBondingProgram.createTokenBonding({
+          curve,
+          baseMint,
+          targetMintDecimals: DECIMALS,
+          buyBaseRoyaltyPercentage: 0,
+          buyTargetRoyaltyPercentage: 0,
+          sellBaseRoyaltyPercentage: 10,
+          sellTargetRoyaltyPercentage: 10,
+          sellBaseRoyaltiesOwner: sellRoyaltiesOwner,
+          sellTargetRoyaltiesOwner: sellRoyaltiesOwner,
+        });
+      sellBonding = (await tokenBondingProgram.getTokenBonding(sell))!;
+      const { tokenBonding: buy } =
+        await tokenBondingProgram.createTokenBonding({
+          curve,
+          baseMint,
+          targetMintDecimals: DECIMALS,
+          buyBaseRoyaltyPercentage: 10,
+          buyTargetRoyaltyPercentage: 10,
+          sellBaseRoyaltyPercentage: 0,
+          sellTargetRoyaltyPercentage: 0,
+          buyBaseRoyaltiesOwner: buyRoyaltiesOwner,
+          buyTargetRoyaltiesOwner: buyRoyaltiesOwner,
+        });
+      buyBonding = (await tokenBondingProgram.getTokenBonding(buy))!;
+      // A buy target account must exist to receive the swap output
+      await tokenBondingProgram.buy({
+        tokenBonding: buy,
+        baseAmount: 1,
+        slippage: 0.5,
+      });
+
+      await tokenBondingProgram.buy({
+        tokenBonding: sell,
+        baseAmount: 100,
+        slippage: 0.5,
+      });
+    });
+
+    it("buys with what the sell reclaims, paying royalties on both legs", async () => {
+      const sellSource = await ata(sellBonding.targetMint);
+      const destination = await ata(buyBonding.targetMint);
+      const before = {
+        base: await balance(await ata(baseMint)),
+        source: await balance(sellSource),
+        destination: await balance(destination),
+        sellStorage: await balance(sellBonding.baseStorage),
+        buyStorage: await balance(buyBonding.baseStorage),
+        sellBaseRoyalties: await balance(sellBonding.sellBaseRoyalties),
+        sellTargetRoyalties: await balance(sellBonding.sellTargetRoyalties),
+        buyBaseRoyalties: await balance(buyBonding.buyBaseRoyalties),
+        buyTargetRoyalties: await balance(buyBonding.buyTargetRoyalties),
+      };
+
+      // 50.00 sell tokens
+      await swap(5000, 0);
+
+      const sellTargetRoyalties =
+        (await balance(sellBonding.sellTargetRoyalties)) -
+        before.sellTargetRoyalties;
+      const sellBaseRoyalties =
+        (await balance(sellBonding.sellBaseRoyalties)) - before.sellBaseRoyalties;
+      const buyBaseRoyalties =
+        (await balance(buyBonding.buyBaseRoyalties)) - before.buyBaseRoyalties;
+      const buyTargetRoyalties =
+        (await balance(buyBonding.buyTargetRoyalties)) -
+        before.buyTargetRoyalties;
+      const reclaimed =
+        before.sellStorage - (await balance(sellBonding.baseStorage));
+      const price = (await balance(buyBonding.baseStorage)) - before.buyStorage;
+      const bought = (await balance(destination)) - before.destination;
+
+      expect(before.source - (await balance(sellSource))).to.equal(5000);
+      expect(sellTargetRoyalties).to.equal(500);
+      // Base only moves from the sell bonding to the buy bonding and the royalties, never through
+      // the swapper's wallet
+      expect(await balance(await ata(baseMint))).to.equal(before.base);
+      expect(reclaimed).to.equal(price + sellBaseRoyalties + buyBaseRoyalties);
+      expect(sellBaseRoyalties).to.be.greaterThan(0);
+      expect(buyBaseRoyalties).to.be.greaterThan(0);
+      expect(buyTargetRoyalties).to.be.greaterThan(0);
+      // Flat price of one, so the buy is its price less target royalties, up to rounding
+      expect(bought + buyTargetRoyalties).to.be.within(price - 1, price);
+    });
+
+    it("fails when the buy leg outputs less than the minimum", async () => {
+      const sellSource = await ata(sellBonding.targetMint);
+      const sourceBefore = await balance(sellSource);
+
+      try {
+        await swap(5000, 5000);
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("PriceTooHigh");
+      }
+
+      // The sell leg rolled back too
+      expect(await balance(sellSource)).to.equal(sourceBefore);
+    });
+  });
+
+  describe("reserve staking", () => {
+    const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+      "BPFLoaderUpgradeab1e11111111111111111111111"
+    );
+    let common: any;
+    let mintAuthority: PublicKey;
+
+    async function reserveStake(tokenBonding: PublicKey): Promise<any> {
+      return (await program.account.tokenBondingV0.fetch(tokenBonding))
+        .reserveStake;
+    }
+
+    async function rebalance(tokenBonding: PublicKey) {
+      const tokenBondingAcct = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+      await program.rpc.rebalanceReserveStakingV0({
+        accounts: {
+          common,
+          tokenBonding,
+          baseStorage: tokenBondingAcct.baseStorage,
+          mintAuthority,
+        },
+      });
+    }
+
+    async function setMaxStakedPercentage(maxStakedPercentage: number) {
+      await program.rpc.updateReserveStakingV0(
+        { newAuthority: me, maxStakedPercentage },
+        { accounts: { reserveStaking: common.reserveStaking, authority: me } }
+      );
+    }
+
+    // A native bonding with 10 sol of reserves that stakes them
+    async function stakedBonding(): Promise<{
+      tokenBonding: PublicKey;
+      baseStorage: PublicKey;
+    }> {
+      const curve = await tokenBondingProgram.initializeCurve({
+        config: new ExponentialCurveConfig({
+          c: 0,
+          b: 1,
+          pow: 0,
+          frac: 1,
+        }),
+      });
+      const { tokenBonding } = await tokenBondingProgram.createTokenBonding({
+        curve,
+        baseMint: NATIVE_MINT,
+        targetMintDecimals: 9,
+        generalAuthority: me,
+        buyBaseRoyaltyPercentage: 0,
+        buyTargetRoyaltyPercentage: 0,
+        sellBaseRoyaltyPercentage: 0,
+        sellTargetRoyaltyPercentage: 0,
+      });
+      await tokenBondingProgram.buy({
+        tokenBonding,
+        desiredTargetAmount: 10,
+        slippage: 0.05,
+      });
+      await program.rpc.initializeReserveStakeV0({
+        accounts: {
+          tokenBonding,
+          generalAuthority: me,
+          state: common.state,
+          reserveStaking: common.reserveStaking,
+        },
+      });
+      const tokenBondingAcct = (await tokenBondingProgram.getTokenBonding(
+        tokenBonding
+      ))!;
+
+      return { tokenBonding, baseStorage: tokenBondingAcct.baseStorage };
+    }
+
+    before(async () => {
+      const pool = await createStakePool(provider);
+      const state = (await tokenBondingProgram.getState())!;
+
+      const [reserveStakingKey] = await PublicKey.findProgramAddress(
+        [Buffer.from("reserve-staking", "utf-8")],
+        program.programId
+      );
+      const [poolTokens] = await PublicKey.findProgramAddress(
+        [Buffer.from("reserve-staking-pool-tokens", "utf-8")],
+        program.programId
+      );
+      mintAuthority = (
+        await SplTokenBonding.wrappedSolMintAuthorityKey(program.programId)
+      )[0];
+      common = {
+        state: state.publicKey,
+        reserveStaking: reserveStakingKey,
+        wrappedSolMint: state.wrappedSolMint,
+        solStorage: state.solStorage,
+        poolTokens,
+        stakePool: pool.stakePool,
+        stakePoolWithdrawAuthority: pool.withdrawAuthority,
+        reserveStake: pool.reserveStake,
+        managerFeeAccount: pool.managerFeeAccount,
+        poolMint: pool.poolMint,
+        stakePoolProgram: STAKE_POOL_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        stakeProgram: StakeProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+
+      const [programData] = await PublicKey.findProgramAddress(
+        [program.programId.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_ID
+      );
+      const initAccounts = {
+        payer: me,
+        upgradeAuthority: me,
+        programData,
+        state: state.publicKey,
+        reserveStaking: reserveStakingKey,
+        stakePool: pool.stakePool,
+        stakePoolProgram: STAKE_POOL_PROGRAM_ID,
+        poolMint: pool.poolMint,
+        solStorage: state.solStorage,
+        poolTokens,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      };
+      try {
+        await program.rpc.initializeReserveStakingV0(
+          { authority: me, maxStakedPercentage: percent(90) },
+          { accounts: initAccounts }
+        );
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("InvalidMaxStakedPercentage");
+      }
+      await program.rpc.initializeReserveStakingV0(
+        { authority: me, maxStakedPercentage: percent(50) },
+        { accounts: initAccounts }
+      );
+    });
+
+    it("stakes only the bonding's own reserves", async () => {
+      await setMaxStakedPercentage(percent(50));
+      const { tokenBonding, baseStorage } = await stakedBonding();
+      const other = await stakedBonding();
+      const poolTokensBefore = new BN(
+        (
+          await provider.connection.getTokenAccountBalance(common.poolTokens)
+        ).value.amount
+      );
+      await rebalance(tokenBonding);
+
+      const stake = await reserveStake(tokenBonding);
+      // The pool has no fees, so pool tokens are 1:1 with lamports
+      expect(stake.stakedLamports.toNumber()).to.be.closeTo(5_000_000_000, 10);
+      expect(stake.poolTokens.toString()).to.equal(
+        stake.stakedLamports.toString()
+      );
+      const poolTokensAfter = new BN(
+        (
+          await provider.connection.getTokenAccountBalance(common.poolTokens)
+        ).value.amount
+      );
+      expect(poolTokensAfter.sub(poolTokensBefore).toString()).to.equal(
+        stake.poolTokens.toString()
+      );
+      // Staking never touches base storage
+      await tokenUtils.expectBalanceWithin(baseStorage, 10, 0.000001);
+      expect(
+        (await reserveStake(other.tokenBonding)).stakedLamports.toNumber()
+      ).to.equal(0);
+
+      try {
+        await program.rpc.claimReserveYieldV0({
+          accounts: { common, authority: me, tokenBonding, destination: me },
+        });
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("NoReserveYield");
+      }
+    });
+
+    it("unstakes when a sell pays out more than is unstaked", async () => {
+      await setMaxStakedPercentage(percent(80));
+      const { tokenBonding, baseStorage } = await stakedBonding();
+      await rebalance(tokenBonding);
+      const staked = (await reserveStake(tokenBonding)).stakedLamports;
+      expect(staked.toNumber()).to.be.closeTo(8_000_000_000, 10);
+
+      // Without the reserve staking accounts, the sell cannot reach the staked lamports
+      const { instructions } = await tokenBondingProgram.sellInstructions({
+        tokenBonding,
+        targetAmount: 5,
+        slippage: 0.05,
+      });
+      const sell = instructions[instructions.length - 1];
+      sell.keys = sell.keys.slice(0, sell.keys.length - 16);
+      try {
+        await provider.sendAndConfirm(new Transaction().add(...instructions));
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("ReservesNotLiquid");
+      }
+
+      const lamportsBefore = await provider.connection.getBalance(me);
+      await tokenBondingProgram.sell({
+        tokenBonding,
+        targetAmount: 5,
+        slippage: 0.05,
+      });
+      expect(
+        (await provider.connection.getBalance(me)) - lamportsBefore
+      ).to.be.closeTo(5_000_000_000, 10_000);
+      await tokenUtils.expectBalanceWithin(baseStorage, 5, 0.000001);
+      // Only what the sell needed beyond the 2 unstaked sol came out of the pool
+      const stake = await reserveStake(tokenBonding);
+      expect(staked.sub(stake.stakedLamports).toNumber()).to.be.closeTo(
+        3_000_000_000,
+        10
+      );
+      expect(stake.totalLossLamports.toNumber()).to.equal(0);
+    });
+
+    it("unstakes everything once wound down, so the bonding can close", async () => {
+      await setMaxStakedPercentage(percent(50));
+      const { tokenBonding } = await stakedBonding();
+      await rebalance(tokenBonding);
+      await program.rpc.windDownTokenBondingV0({
+        accounts: {
+          tokenBonding,
+          generalAuthority: me,
+        },
+      });
+      await rebalance(tokenBonding);
+
+      const stake = await reserveStake(tokenBonding);
+      expect(stake.stakedLamports.toNumber()).to.equal(0);
+      expect(stake.poolTokens.toNumber()).to.equal(0);
+
+      await tokenBondingProgram.sell({
+        tokenBonding,
+        targetAmount: 10,
+        slippage: 0.05,
+      });
+      await tokenBondingProgram.close({ tokenBonding });
+      expect(await provider.connection.getAccountInfo(tokenBonding)).to.be
+        .null;
+    });
+
+    it("only adds yield to a bonding that stakes", async () => {
+      const curve = await tokenBondingProgram.initializeCurve({
+        config: new ExponentialCurveConfig({
+          c: 1,
+          b: 0,
+          pow: 1,
+          frac: 1,
+        }),
+      });
+      const { tokenBonding, baseStorage } =
+        await tokenBondingProgram.createTokenBonding({
+          curve,
+          baseMint: NATIVE_MINT,
+          targetMintDecimals: 2,
+          buyBaseRoyaltyPercentage: 0,
+          buyTargetRoyaltyPercentage: 0,
+          sellBaseRoyaltyPercentage: 0,
+          sellTargetRoyaltyPercentage: 0,
+        });
+
+      try {
+        await program.rpc.addReserveYieldV0({
+          accounts: {
+            common,
+            authority: me,
+            mintAuthority,
+            tokenBonding,
+            baseStorage,
+          },
+        });
+        throw "Shouldn't get here";
+      } catch (e: any) {
+        expect(e.toString()).to.contain("ReserveStakeNotInitialized");
+      }
+    });
   });
 });
@@ -0,0 +1,166 @@
+import { AnchorProvider } from "@project-serum/anchor";
+import {
+  Authorized,
+  Keypair,
+  Lockup,
+  PublicKey,
+  StakeProgram,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+
+export const STAKE_POOL_PROGRAM_ID = new PublicKey(
+  "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"
+);
+
+// Room for StakePool across stake pool releases, the program ignores trailing bytes
+const STAKE_POOL_SIZE = 1024;
+const MAX_VALIDATORS = 1;
+// ValidatorList header plus one 73 byte entry per validator
+const VALIDATOR_LIST_SIZE = 9 + 73 * MAX_VALIDATORS;
+
+export interface StakePoolAccounts {
+  stakePool: PublicKey;
+  withdrawAuthority: PublicKey;
+  reserveStake: PublicKey;
+  managerFeeAccount: PublicKey;
+  poolMint: PublicKey;
+}
+
+function fee(numerator: number, denominator: number): Buffer {
+  const buf = Buffer.alloc(16);
+  buf.writeBigUInt64LE(BigInt(denominator), 0);
+  buf.writeBigUInt64LE(BigInt(numerator), 8);
+  return buf;
+}
+
+/**
+ * Create a fee-less stake pool with no validators, so sol deposits and withdrawals only move
+ * lamports in and out of the reserve stake
+ */
+export async function createStakePool(
+  provider: AnchorProvider
+): Promise<StakePoolAccounts> {
+  const me = provider.wallet.publicKey;
+  const connection = provider.connection;
+  const stakePool = Keypair.generate();
+  const validatorList = Keypair.generate();
+  const reserveStake = Keypair.generate();
+  const poolMint = Keypair.generate();
+  const withdrawAuthority = (
+    await PublicKey.findProgramAddress(
+      [stakePool.publicKey.toBuffer(), Buffer.from("withdraw", "utf-8")],
+      STAKE_POOL_PROGRAM_ID
+    )
+  )[0];
+
+  const managerFeeAccount = await Token.getAssociatedTokenAddress(
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    poolMint.publicKey,
+    me
+  );
+
+  const setup = new Transaction();
+  setup.add(
+    SystemProgram.createAccount({
+      fromPubkey: me,
+      newAccountPubkey: stakePool.publicKey,
+      lamports: await connection.getMinimumBalanceForRentExemption(
+        STAKE_POOL_SIZE
+      ),
+      space: STAKE_POOL_SIZE,
+      programId: STAKE_POOL_PROGRAM_ID,
+    }),
+    SystemProgram.createAccount({
+      fromPubkey: me,
+      newAccountPubkey: validatorList.publicKey,
+      lamports: await connection.getMinimumBalanceForRentExemption(
+        VALIDATOR_LIST_SIZE
+      ),
+      space: VALIDATOR_LIST_SIZE,
+      programId: STAKE_POOL_PROGRAM_ID,
+    }),
+    StakeProgram.createAccount({
+      fromPubkey: me,
+      stakePubkey: reserveStake.publicKey,
+      authorized: new Authorized(withdrawAuthority, withdrawAuthority),
+      lockup: new Lockup(0, 0, PublicKey.default),
+      lamports:
+        (await connection.getMinimumBalanceForRentExemption(
+          StakeProgram.space
+        )) + 1,
+    }),
+    SystemProgram.createAccount({
+      fromPubkey: me,
+      newAccountPubkey: poolMint.publicKey,
+      lamports: await Token.getMinBalanceRentForExemptMint(connection),
+      space: 82,
+      programId: TOKEN_PROGRAM_ID,
+    }),
+    Token.createInitMintInstruction(
+      TOKEN_PROGRAM_ID,
+      poolMint.publicKey,
+      9,
+      withdrawAuthority,
+      null
+    ),
+    Token.createAssociatedTokenAccountInstruction(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      poolMint.publicKey,
+      managerFeeAccount,
+      me,
+      me
+    )
+  );
+  await provider.sendAndConfirm(setup, [
+    stakePool,
+    validatorList,
+    reserveStake,
+    poolMint,
+  ]);
+
+  const maxValidators = Buffer.alloc(4);
+  maxValidators.writeUInt32LE(MAX_VALIDATORS, 0);
+  const initialize = new Transaction();
+  initialize.add(
+    new TransactionInstruction({
+      programId: STAKE_POOL_PROGRAM_ID,
+      keys: [
+        { pubkey: stakePool.publicKey, isSigner: false, isWritable: true },
+        { pubkey: me, isSigner: true, isWritable: false },
+        { pubkey: me, isSigner: false, isWritable: false },
+        { pubkey: withdrawAuthority, isSigner: false, isWritable: false },
+        { pubkey: validatorList.publicKey, isSigner: false, isWritable: true },
+        { pubkey: reserveStake.publicKey, isSigner: false, isWritable: false },
+        { pubkey: poolMint.publicKey, isSigner: false, isWritable: true },
+        { pubkey: managerFeeAccount, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([
+        Buffer.from([0]), // Initialize
+        fee(0, 100),
+        fee(0, 100),
+        fee(0, 100),
+        Buffer.from([0]), // referral fee
+        maxValidators,
+      ]),
+    })
+  );
+  await provider.sendAndConfirm(initialize);
+
+  return {
+    stakePool: stakePool.publicKey,
+    withdrawAuthority,
+    reserveStake: reserveStake.publicKey,
+    managerFeeAccount,
+    poolMint: poolMint.publicKey,
+  };
+}